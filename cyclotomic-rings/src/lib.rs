@@ -10,6 +10,7 @@
 extern crate ark_std;
 
 pub mod challenge_set;
+pub mod norm;
 pub mod rings;
 pub mod rotation;
 
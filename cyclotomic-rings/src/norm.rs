@@ -0,0 +1,107 @@
+//!
+//! Norms of ring elements in the coefficient form.
+//!
+//! All norms are computed on the centered representatives of the coefficients,
+//! i.e. a coefficient $x \in \mathbb{Z}_p$ is identified with the unique integer
+//! in $[-\lfloor p/2 \rfloor, \lfloor p/2 \rfloor]$ congruent to $x$.
+//!
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use stark_rings::PolyRing;
+
+/// Returns the absolute value of the centered representative of `x`.
+pub fn centered_abs<F: PrimeField>(x: &F) -> BigUint {
+    let x: BigUint = x.into_bigint().into();
+    let modulus: BigUint = F::MODULUS.into();
+
+    if x > &modulus >> 1 {
+        modulus - x
+    } else {
+        x
+    }
+}
+
+/// Norms of a ring element in the coefficient form,
+/// such as [`SuitableRing::CoefficientRepresentation`](crate::rings::SuitableRing::CoefficientRepresentation).
+pub trait CoeffNorm {
+    /// Returns the infinity norm, i.e. the largest absolute value of a coefficient.
+    fn norm_inf(&self) -> BigUint;
+
+    /// Returns the square of the $\ell_2$-norm, i.e. the sum of the squares of the coefficients.
+    fn norm_l2_squared(&self) -> BigUint;
+}
+
+impl<R: PolyRing> CoeffNorm for R
+where
+    R::BaseRing: PrimeField,
+{
+    fn norm_inf(&self) -> BigUint {
+        self.coeffs()
+            .iter()
+            .map(centered_abs)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn norm_l2_squared(&self) -> BigUint {
+        self.coeffs()
+            .iter()
+            .map(|x| {
+                let x = centered_abs(x);
+                &x * &x
+            })
+            .sum()
+    }
+}
+
+/// Returns the infinity norm of a vector of ring elements in the coefficient form.
+pub fn vec_norm_inf<R: CoeffNorm>(v: &[R]) -> BigUint {
+    v.iter().map(R::norm_inf).max().unwrap_or_default()
+}
+
+/// Returns the square of the $\ell_2$-norm of a vector of ring elements in the coefficient form.
+pub fn vec_norm_l2_squared<R: CoeffNorm>(v: &[R]) -> BigUint {
+    v.iter().map(R::norm_l2_squared).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, Zero};
+    use stark_rings::cyclotomic_ring::models::goldilocks::Fq;
+
+    use super::*;
+    use crate::{ark_base::*, rings::GoldilocksRingPoly};
+
+    fn poly(coeffs: &[i64]) -> GoldilocksRingPoly {
+        let mut coeffs: Vec<Fq> = coeffs.iter().map(|&x| Fq::from(x)).collect();
+        coeffs.resize(GoldilocksRingPoly::dimension(), Fq::zero());
+
+        GoldilocksRingPoly::from(coeffs)
+    }
+
+    #[test]
+    fn test_centered_abs() {
+        assert_eq!(centered_abs(&Fq::from(5u64)), BigUint::from(5u64));
+        assert_eq!(centered_abs(&-Fq::from(5u64)), BigUint::from(5u64));
+        assert_eq!(centered_abs(&Fq::zero()), BigUint::zero());
+
+        let half = Fq::from(2u64).inverse().unwrap();
+        let modulus: BigUint = Fq::MODULUS.into();
+        let expected = (modulus - BigUint::from(1u64)) >> 1;
+        assert_eq!(centered_abs(&half), expected);
+    }
+
+    #[test]
+    fn test_norms() {
+        let a = poly(&[1, -3, 2]);
+        let b = poly(&[-4]);
+
+        assert_eq!(a.norm_inf(), BigUint::from(3u64));
+        assert_eq!(a.norm_l2_squared(), BigUint::from(14u64));
+
+        assert_eq!(vec_norm_inf(&[a, b]), BigUint::from(4u64));
+        assert_eq!(vec_norm_l2_squared(&[a, b]), BigUint::from(30u64));
+        assert_eq!(vec_norm_inf::<GoldilocksRingPoly>(&[]), BigUint::zero());
+    }
+}
@@ -5,6 +5,7 @@
 use ark_crypto_primitives::sponge::{poseidon::PoseidonConfig, Absorb};
 use ark_ff::{Field, PrimeField};
 use ark_std::ops::MulAssign;
+use poseidon2::Poseidon2Config;
 use stark_rings::{
     balanced_decomposition::Decompose,
     cyclotomic_ring::{CRT, ICRT},
@@ -15,7 +16,8 @@ use stark_rings::{
 mod babybear;
 mod frog;
 mod goldilocks;
-mod poseidon;
+pub mod poseidon;
+pub mod poseidon2;
 mod stark;
 
 pub use babybear::*;
@@ -51,7 +53,7 @@ pub use stark::*;
 ///   * `R` and `R::CoefficientRepresentation` should be convertible into each other.
 ///   * `R::CoefficientRepresentation` is radix-$B$ decomposable and exhibits cyclotomic structure (`R::CoefficientRepresentation: Decompose + Cyclotomic`).
 ///
-/// In addition to the data above a suitable ring has to provide Poseidon hash parameters for its base prime field (i.e. $\mathbb{Z}\_p$).
+/// In addition to the data above a suitable ring has to provide Poseidon and Poseidon2 hash parameters for its base prime field (i.e. $\mathbb{Z}\_p$).
 pub trait SuitableRing:
     OverField
     + ICRT<ICRTForm = Self::CoefficientRepresentation>
@@ -69,6 +71,11 @@ where
 
     /// Poseidon sponge parameters for the base prime field.
     type PoseidonParams: GetPoseidonParams<<<Self as PolyRing>::BaseRing as Field>::BasePrimeField>;
+
+    /// Poseidon2 sponge parameters for the base prime field.
+    type Poseidon2Params: GetPoseidon2Params<
+        <<Self as PolyRing>::BaseRing as Field>::BasePrimeField,
+    >;
 }
 
 /// A trait for types with an associated Poseidon sponge configuration.
@@ -76,3 +83,9 @@ pub trait GetPoseidonParams<Fq: PrimeField> {
     /// Returns the associated Poseidon sponge configuration.
     fn get_poseidon_config() -> PoseidonConfig<Fq>;
 }
+
+/// A trait for types with an associated Poseidon2 sponge configuration.
+pub trait GetPoseidon2Params<Fq: PrimeField> {
+    /// Returns the associated Poseidon2 sponge configuration.
+    fn get_poseidon2_config() -> Poseidon2Config<Fq>;
+}
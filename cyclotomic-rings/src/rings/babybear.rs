@@ -22,6 +22,7 @@ pub type BabyBearRingPoly = RqPoly;
 impl SuitableRing for BabyBearRingNTT {
     type CoefficientRepresentation = RqPoly;
     type PoseidonParams = BabyBearPoseidonConfig;
+    type Poseidon2Params = BabyBearPoseidonConfig;
 }
 
 pub struct BabyBearPoseidonConfig;
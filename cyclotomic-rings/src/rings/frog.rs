@@ -22,6 +22,7 @@ pub type FrogRingPoly = RqPoly;
 impl SuitableRing for FrogRingNTT {
     type CoefficientRepresentation = RqPoly;
     type PoseidonParams = FrogPoseidonConfig;
+    type Poseidon2Params = FrogPoseidonConfig;
 }
 
 pub struct FrogPoseidonConfig;
@@ -22,6 +22,7 @@ pub type GoldilocksRingPoly = RqPoly;
 impl SuitableRing for GoldilocksRingNTT {
     type CoefficientRepresentation = RqPoly;
     type PoseidonParams = GoldilocksPoseidonConfig;
+    type Poseidon2Params = GoldilocksPoseidonConfig;
 }
 
 pub struct GoldilocksPoseidonConfig;
@@ -1,4 +1,84 @@
-mod babybear;
-mod frog;
-mod goldilocks;
+//!
+//! Poseidon parameters of the rings and their generation.
+//!
+//! The parameters can be derived for any prime field with [`PoseidonSpec::with_security`] and [`poseidon_config`],
+//! following the reference implementation of Poseidon. The configurations of the available rings are obtained as follows:
+//!   * Goldilocks: [`poseidon_config`] for $t = 24$, $\alpha = 7$, $R_F = 8$ and $R_P = 22$,
+//!     i.e. the round numbers of [`PoseidonSpec::with_security`] at 128 bits,
+//!   * BabyBear and Frog: the Goldilocks constants reduced modulo their primes,
+//!     i.e. [`PoseidonConstants::generate`] for the Goldilocks modulus followed by [`PoseidonConstants::to_config`],
+//!   * Starknet prime: the constants of the Starknet Poseidon hash, which are not Grain LFSR outputs
+//!     but SHA-256 outputs of `Hades{i}` reduced modulo $p$, with the fixed MDS matrix of the Starknet hash.
+//!     Its round numbers agree with [`PoseidonSpec::with_security`] at 128 bits.
+//!
+//! The Goldilocks, BabyBear and Frog configurations are generated on first use.
+//! With the `std` feature they are cached for the lifetime of the process.
+//!
+
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::PrimeField;
+use stark_rings::cyclotomic_ring::models::{babybear, frog_ring, goldilocks};
+
+use crate::rings::{
+    BabyBearPoseidonConfig, FrogPoseidonConfig, GetPoseidonParams, GoldilocksPoseidonConfig,
+};
+
+mod generator;
 mod stark;
+
+pub use generator::*;
+pub(crate) use generator::{check_alpha, GrainLfsr};
+
+/// The spec of the Goldilocks, BabyBear and Frog configurations.
+pub(crate) const SPEC_64: PoseidonSpec = PoseidonSpec {
+    rate: 20,
+    capacity: 4,
+    alpha: 7,
+    full_rounds: 8,
+    partial_rounds: 22,
+};
+
+/// Evaluates `$init` once per process and returns clones of the result.
+#[cfg(feature = "std")]
+macro_rules! lazy_config {
+    ($field:ty, $init:expr) => {{
+        static CONFIG: std::sync::OnceLock<PoseidonConfig<$field>> = std::sync::OnceLock::new();
+        CONFIG.get_or_init(|| $init).clone()
+    }};
+}
+
+/// Without `std` there is no synchronized cell to cache in, so `$init` is evaluated on every call.
+#[cfg(not(feature = "std"))]
+macro_rules! lazy_config {
+    ($field:ty, $init:expr) => {
+        $init
+    };
+}
+
+/// Returns the Goldilocks constants of [`SPEC_64`] reduced into `F`.
+fn goldilocks_constants<F: PrimeField>() -> PoseidonConfig<F> {
+    PoseidonConstants::generate(&SPEC_64, &goldilocks::Fq::MODULUS.into())
+        .expect("SPEC_64 is a valid spec for Goldilocks")
+        .to_config(&SPEC_64)
+}
+
+impl GetPoseidonParams<goldilocks::Fq> for GoldilocksPoseidonConfig {
+    fn get_poseidon_config() -> PoseidonConfig<goldilocks::Fq> {
+        lazy_config!(
+            goldilocks::Fq,
+            poseidon_config(&SPEC_64).expect("SPEC_64 is a valid spec for Goldilocks")
+        )
+    }
+}
+
+impl GetPoseidonParams<babybear::Fq> for BabyBearPoseidonConfig {
+    fn get_poseidon_config() -> PoseidonConfig<babybear::Fq> {
+        lazy_config!(babybear::Fq, goldilocks_constants())
+    }
+}
+
+impl GetPoseidonParams<frog_ring::Fq> for FrogPoseidonConfig {
+    fn get_poseidon_config() -> PoseidonConfig<frog_ring::Fq> {
+        lazy_config!(frog_ring::Fq, goldilocks_constants())
+    }
+}
@@ -1,1425 +0,0 @@
-use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
-use ark_std::convert::From;
-use stark_rings::cyclotomic_ring::models::babybear::Fq;
-
-use crate::rings::{BabyBearPoseidonConfig, GetPoseidonParams};
-
-impl GetPoseidonParams<Fq> for BabyBearPoseidonConfig {
-    fn get_poseidon_config() -> PoseidonConfig<Fq> {
-        let full_rounds = 8;
-        let partial_rounds = 22;
-        let alpha = 7;
-
-        let ark = vec![
-            vec![
-                Fq::from(0xb872395376c3f222_i128),
-                Fq::from(0x7d7c6c8d6dda795a_i128),
-                Fq::from(0xc1a1e1450d7d048e_i128),
-                Fq::from(0x37eb0d1b1db5377a_i128),
-                Fq::from(0xc4bcae9b18a59675_i128),
-                Fq::from(0x71b53bb0a698e945_i128),
-                Fq::from(0x2bf969e10958afce_i128),
-                Fq::from(0x7d547ff97be746ed_i128),
-                Fq::from(0x039f63f1e29db315_i128),
-                Fq::from(0x3c1ff85fc2faa32b_i128),
-                Fq::from(0x90726208dcb17d1c_i128),
-                Fq::from(0x9c90cd53e23b6011_i128),
-                Fq::from(0xc58c9dad698777e0_i128),
-                Fq::from(0x141ec36e6fea61b2_i128),
-                Fq::from(0x07c39986c2451f69_i128),
-                Fq::from(0xf993966c72c1a7eb_i128),
-                Fq::from(0xe3938b0f5ee41384_i128),
-                Fq::from(0x83ca82679ec4b5f6_i128),
-                Fq::from(0xcb64cc3dca7fa41e_i128),
-                Fq::from(0x8840e8205e53d58f_i128),
-                Fq::from(0x1f3f078825625b52_i128),
-                Fq::from(0xa72bf89dfd2c2f87_i128),
-                Fq::from(0xc9e870ff497c838a_i128),
-                Fq::from(0x426a6f37e7ae0634_i128),
-            ],
-            vec![
-                Fq::from(0x4314cfdcd95b0740_i128),
-                Fq::from(0x92a7f6d3314f8a50_i128),
-                Fq::from(0x156758fbdcf9c1ee_i128),
-                Fq::from(0x8378d076901e56be_i128),
-                Fq::from(0xe1d97b6e463812a7_i128),
-                Fq::from(0x8d46d2652bca6b40_i128),
-                Fq::from(0x1ea01b3bf7e5cfe3_i128),
-                Fq::from(0x906170850a1cffff_i128),
-                Fq::from(0x9643d41e81398b4f_i128),
-                Fq::from(0x301a20019c460b34_i128),
-                Fq::from(0x6ce25ea766317b4e_i128),
-                Fq::from(0x1c261cd0a6080260_i128),
-                Fq::from(0xe11c4fdff98687eb_i128),
-                Fq::from(0xf3b4ed6258851726_i128),
-                Fq::from(0x2a77f334bf614629_i128),
-                Fq::from(0x567d7604f6f3c921_i128),
-                Fq::from(0x2e1bf953f8665f03_i128),
-                Fq::from(0x93c80c0e670b8953_i128),
-                Fq::from(0x58b039468b4f6ee3_i128),
-                Fq::from(0xe36a89b1ffc54d63_i128),
-                Fq::from(0xb30fc735a5d99435_i128),
-                Fq::from(0x1d919c819c473bdf_i128),
-                Fq::from(0x6daa31ea18a7bc11_i128),
-                Fq::from(0x16471ed8a5579f77_i128),
-            ],
-            vec![
-                Fq::from(0x0cdd3af8ddada773_i128),
-                Fq::from(0xd078e5fb0ecf9625_i128),
-                Fq::from(0x4b975e2da42d49f9_i128),
-                Fq::from(0x742cf192dbd445d5_i128),
-                Fq::from(0x57e9f5d56874544f_i128),
-                Fq::from(0x6a8da54d5288a300_i128),
-                Fq::from(0x225dbda097bd252a_i128),
-                Fq::from(0xbe9eb51ec094d9a1_i128),
-                Fq::from(0x1ddb4046d4c7c116_i128),
-                Fq::from(0xcbc7e7b40bbea879_i128),
-                Fq::from(0x93166b2f6414b901_i128),
-                Fq::from(0xcb11dfec6fbb8e52_i128),
-                Fq::from(0x2ecbf1f80014e579_i128),
-                Fq::from(0x15060341ca9d22f5_i128),
-                Fq::from(0x1fa9b7a194723117_i128),
-                Fq::from(0x3c2c3f71dc560bf4_i128),
-                Fq::from(0x5f97a5f4043339a3_i128),
-                Fq::from(0x4392b244d1d5c960_i128),
-                Fq::from(0xa801965518f6c110_i128),
-                Fq::from(0x054d6ec710d1d8d2_i128),
-                Fq::from(0xd65f11ec2f9c9265_i128),
-                Fq::from(0x0f47bbb086b1a190_i128),
-                Fq::from(0xda31dfbafc6a76cd_i128),
-                Fq::from(0xe7ff26ac0f400d2c_i128),
-            ],
-            vec![
-                Fq::from(0x89f43015ee3f11d5_i128),
-                Fq::from(0x8362e3a83b5c87a7_i128),
-                Fq::from(0xe43d40b6fe4806ed_i128),
-                Fq::from(0x3de4666954796d0a_i128),
-                Fq::from(0x81efba88a5a5bd0c_i128),
-                Fq::from(0x14962857ad16b327_i128),
-                Fq::from(0xbdd58805de1bbe5b_i128),
-                Fq::from(0x182747b173500cb3_i128),
-                Fq::from(0xdac897a37c8d3673_i128),
-                Fq::from(0xcf3423a4f52d256e_i128),
-                Fq::from(0x61af815c01ad11f1_i128),
-                Fq::from(0x5572af14b3a045dd_i128),
-                Fq::from(0xe604d80d81deec38_i128),
-                Fq::from(0x031a31f537d56d21_i128),
-                Fq::from(0x2a1ac61a3d836c1e_i128),
-                Fq::from(0xe2e5d6750882a1fa_i128),
-                Fq::from(0x9456bd7eada108b2_i128),
-                Fq::from(0xc3fd14f704898765_i128),
-                Fq::from(0x6aad6c94f578da05_i128),
-                Fq::from(0x6e622f104488d85c_i128),
-                Fq::from(0xc8945dbd1b0b3c67_i128),
-                Fq::from(0x4e555653569e8fe2_i128),
-                Fq::from(0x6e6fb4afb9147379_i128),
-                Fq::from(0xa099b0ca9f55bfec_i128),
-            ],
-            vec![
-                Fq::from(0x859bff4cfb815f66_i128),
-                Fq::from(0x6725eecc958f9066_i128),
-                Fq::from(0xb8b999428093aae5_i128),
-                Fq::from(0xf507d3a28abd9528_i128),
-                Fq::from(0xc1cbd266d76a07b9_i128),
-                Fq::from(0x8f870dee1a225f50_i128),
-                Fq::from(0xf9ec5d885f91c224_i128),
-                Fq::from(0xa31fc4e1f06777f5_i128),
-                Fq::from(0xc2a722c32ddf0699_i128),
-                Fq::from(0xa581b9da188309b4_i128),
-                Fq::from(0x998aad025ad11ba3_i128),
-                Fq::from(0x443de5cad68a0c83_i128),
-                Fq::from(0x5dd46af323630c9f_i128),
-                Fq::from(0x92cc7b2191aa18b7_i128),
-                Fq::from(0xf27884b16af142d6_i128),
-                Fq::from(0xeda382312a75bcd2_i128),
-                Fq::from(0x6d1c398af14aac0a_i128),
-                Fq::from(0xf56d4baef333ebae_i128),
-                Fq::from(0x0110cf87d458dc79_i128),
-                Fq::from(0xc2a6d1e83ab3b7a5_i128),
-                Fq::from(0xb246c0bde623c7c5_i128),
-                Fq::from(0xf4d8e7384bc7ce9f_i128),
-                Fq::from(0xffc81193376c8d6f_i128),
-                Fq::from(0x65068cb1af18b3b2_i128),
-            ],
-            vec![
-                Fq::from(0x2ad75ff59419ff2a_i128),
-                Fq::from(0x8d8e0ffa5dc273a4_i128),
-                Fq::from(0x6cf77dae86ee2d35_i128),
-                Fq::from(0x89c7df66cd9807c4_i128),
-                Fq::from(0xf6454818244090d0_i128),
-                Fq::from(0xef6104b209b0b1d3_i128),
-                Fq::from(0x8e9cf4285d7071d8_i128),
-                Fq::from(0x9318266cbec0c0a9_i128),
-                Fq::from(0xcba6b13786123742_i128),
-                Fq::from(0x9ecd446df78805f6_i128),
-                Fq::from(0x9990981422f24ba7_i128),
-                Fq::from(0x27ea278397f82898_i128),
-                Fq::from(0x9c7939759cee3520_i128),
-                Fq::from(0x43265790682ebeaa_i128),
-                Fq::from(0xf09fe17c2b20e5a7_i128),
-                Fq::from(0xd0c44de757df8ca8_i128),
-                Fq::from(0x78467f5491ba8979_i128),
-                Fq::from(0xf26c4954e8816f8e_i128),
-                Fq::from(0x061904592de1f069_i128),
-                Fq::from(0xc5ef15a2b2b4b596_i128),
-                Fq::from(0x8d340e0d7f29d3e9_i128),
-                Fq::from(0xb8574407bdefb1c8_i128),
-                Fq::from(0x5bead7b674257ef5_i128),
-                Fq::from(0xfd13d3db5fdd2dc8_i128),
-            ],
-            vec![
-                Fq::from(0x7e882a45092af135_i128),
-                Fq::from(0x202b3667250e7767_i128),
-                Fq::from(0x1813b1a84a915eb8_i128),
-                Fq::from(0x8d6e2342a5d2bfa3_i128),
-                Fq::from(0xea586b679c23ade6_i128),
-                Fq::from(0x79b8573bb3eadb91_i128),
-                Fq::from(0xccec2454db884ccf_i128),
-                Fq::from(0xf78ce820fed76822_i128),
-                Fq::from(0x44a5aeb6eb5749e4_i128),
-                Fq::from(0xb21c684f44119678_i128),
-                Fq::from(0x36e69d844defa80a_i128),
-                Fq::from(0xc57d8acf6e0f7ba9_i128),
-                Fq::from(0xa2d10cd73b240141_i128),
-                Fq::from(0x84672d34689ded5c_i128),
-                Fq::from(0x18313f2619cc4708_i128),
-                Fq::from(0xca8ed13987b100c9_i128),
-                Fq::from(0x5e2a3d35773bb9d0_i128),
-                Fq::from(0x997580bbddec1762_i128),
-                Fq::from(0xd77717409a19fd54_i128),
-                Fq::from(0x86d8b7f2a0ea4a1f_i128),
-                Fq::from(0x2b47aa3dffb33a7d_i128),
-                Fq::from(0xe33d89e53511c6f2_i128),
-                Fq::from(0xebffcaeef0a009dc_i128),
-                Fq::from(0x9dd655f164ba3f57_i128),
-            ],
-            vec![
-                Fq::from(0xca429d053c15bbf0_i128),
-                Fq::from(0x4209895ffef00bfc_i128),
-                Fq::from(0x2bf56e4e8b9628e3_i128),
-                Fq::from(0x977eeca95d919f44_i128),
-                Fq::from(0x5d308377fb7d5a49_i128),
-                Fq::from(0xc05f3158cc3a1c98_i128),
-                Fq::from(0xc3161ef16a5df467_i128),
-                Fq::from(0x7a4b9c1c6ca931c5_i128),
-                Fq::from(0x9c0b8968d427bca1_i128),
-                Fq::from(0x01ccd501e8e1eb46_i128),
-                Fq::from(0xa442344e50b458de_i128),
-                Fq::from(0x0d4b66d8d4b59213_i128),
-                Fq::from(0x9c34acbaac050c48_i128),
-                Fq::from(0xed456d99ad122ee9_i128),
-                Fq::from(0x2480e218a0a79547_i128),
-                Fq::from(0x391531ec020eddb6_i128),
-                Fq::from(0x9d88b6a526385791_i128),
-                Fq::from(0xe83ea68627ead11d_i128),
-                Fq::from(0x0596f7aaab1bd3c4_i128),
-                Fq::from(0x1b54bf08f680cc3a_i128),
-                Fq::from(0x1e0adfe5a7691a37_i128),
-                Fq::from(0x5835b5c5bd242eef_i128),
-                Fq::from(0x6b4166a1ac848543_i128),
-                Fq::from(0x157050c069a612e1_i128),
-            ],
-            vec![
-                Fq::from(0x03cad0b28d0dc405_i128),
-                Fq::from(0x86a1c6ffc30b5366_i128),
-                Fq::from(0xe5c4c673028d9eb7_i128),
-                Fq::from(0x2aa0959e65bf3d88_i128),
-                Fq::from(0x9689b17dea4ffab4_i128),
-                Fq::from(0x9f33b26c0c29d748_i128),
-                Fq::from(0x92dba3d9a7c6cc2b_i128),
-                Fq::from(0x64b1ffde84e0be8e_i128),
-                Fq::from(0x497e2fefcd9c123b_i128),
-                Fq::from(0x038941e2c91de4a6_i128),
-                Fq::from(0x46bace39adf392ae_i128),
-                Fq::from(0xc6cdb4f961fe5d40_i128),
-                Fq::from(0xe78d7eca4614b50f_i128),
-                Fq::from(0x080921c1dfac1f1a_i128),
-                Fq::from(0xd02e64dbe2a9e171_i128),
-                Fq::from(0xdfd692b97654269b_i128),
-                Fq::from(0xb5e89b1ae4d73426_i128),
-                Fq::from(0xabb2af48fc4e6386_i128),
-                Fq::from(0x90c7614f5262f6d0_i128),
-                Fq::from(0x762ac2506830bc09_i128),
-                Fq::from(0xb9610ada762a35ac_i128),
-                Fq::from(0x720617d63864a661_i128),
-                Fq::from(0xaa316a39e4af856b_i128),
-                Fq::from(0xfecc9ce2722514ca_i128),
-            ],
-            vec![
-                Fq::from(0x0985cd086e8d3c9f_i128),
-                Fq::from(0x4b333e3a97120587_i128),
-                Fq::from(0xd73b5f3d5b69e171_i128),
-                Fq::from(0x6958f7ced05f7420_i128),
-                Fq::from(0x917ad2cb2566bd9a_i128),
-                Fq::from(0x156ce2452fc1c2f7_i128),
-                Fq::from(0x4f6bdbf3e80e6c61_i128),
-                Fq::from(0x346d3df42d8cab9d_i128),
-                Fq::from(0x0b383d1fcbb108b6_i128),
-                Fq::from(0x1cf04fa09e669dda_i128),
-                Fq::from(0x9973f2cdd358ef1c_i128),
-                Fq::from(0x5952d54bc5cdc896_i128),
-                Fq::from(0x6857f66f269792b5_i128),
-                Fq::from(0xbf7fb7117323b534_i128),
-                Fq::from(0x7e04a971d1eda87c_i128),
-                Fq::from(0x36ccdc0dc562d53f_i128),
-                Fq::from(0x6c646da7a5a4bee2_i128),
-                Fq::from(0x4920ccda161dd87a_i128),
-                Fq::from(0xe940e971b3ad886c_i128),
-                Fq::from(0x1f06991e14ef0e8e_i128),
-                Fq::from(0x48fc9ef05e756671_i128),
-                Fq::from(0xd9acea3f9219fb50_i128),
-                Fq::from(0x5223c72945837fc1_i128),
-                Fq::from(0x73360c1d1f35b239_i128),
-            ],
-            vec![
-                Fq::from(0xfaaf0ea3af6eda6a_i128),
-                Fq::from(0x219d1bb3cf2b7d65_i128),
-                Fq::from(0x7d100c0d1ac81888_i128),
-                Fq::from(0x8ec49a2bdb3d7701_i128),
-                Fq::from(0x700c2e8a490d6e53_i128),
-                Fq::from(0x9b253f521b80d25d_i128),
-                Fq::from(0x54172097a3beafc0_i128),
-                Fq::from(0x196c19d164bc2eef_i128),
-                Fq::from(0xe15f769fbeb5a97d_i128),
-                Fq::from(0x8a1f03d3c8f225a2_i128),
-                Fq::from(0x8ffb9ad395827440_i128),
-                Fq::from(0x79cdf18345e1e98b_i128),
-                Fq::from(0x2c1ed43b7a27953d_i128),
-                Fq::from(0x86697cd2e0cb32b3_i128),
-                Fq::from(0x368b77b774dde8b7_i128),
-                Fq::from(0xc54531516139b664_i128),
-                Fq::from(0x5b6c016af832bb1f_i128),
-                Fq::from(0x1591e66fdcf2f518_i128),
-                Fq::from(0xd86fc89ea6287119_i128),
-                Fq::from(0x6910f65ca3205303_i128),
-                Fq::from(0x82548e013c7b5f49_i128),
-                Fq::from(0x3a95f1a396bcaf1c_i128),
-                Fq::from(0x9b5deaa06eae9a09_i128),
-                Fq::from(0x40ff21cb3a5631c0_i128),
-            ],
-            vec![
-                Fq::from(0xded0dda2fd169b31_i128),
-                Fq::from(0x7fc791d354693d76_i128),
-                Fq::from(0xd46e1ac4c65313ed_i128),
-                Fq::from(0x9c3eebe8b5fc0527_i128),
-                Fq::from(0x6d30d5ead668b552_i128),
-                Fq::from(0xa20378a271dd2124_i128),
-                Fq::from(0x6d9511897ed8422b_i128),
-                Fq::from(0x31869f44c2d6828f_i128),
-                Fq::from(0x4df3c36c3f7a141d_i128),
-                Fq::from(0x2f460cb7131a3eb1_i128),
-                Fq::from(0xd95068001988ce49_i128),
-                Fq::from(0x1c422a9b38b2ba33_i128),
-                Fq::from(0x818b7cf735ef9e87_i128),
-                Fq::from(0x40d05780bbd29808_i128),
-                Fq::from(0x54364cdd750e5977_i128),
-                Fq::from(0xb4df3ec5a5334ed7_i128),
-                Fq::from(0x6e1771fbf5572c8c_i128),
-                Fq::from(0xbcdee03578f71987_i128),
-                Fq::from(0x5fe8319e20ef8267_i128),
-                Fq::from(0x67ee3ba6cff5ba7c_i128),
-                Fq::from(0xd15c93bdd5468e37_i128),
-                Fq::from(0xe5509707f63dee0d_i128),
-                Fq::from(0xe07c6e4e5ad5217c_i128),
-                Fq::from(0x3f233d9f27dd32d4_i128),
-            ],
-            vec![
-                Fq::from(0x8b30f33bd6b85c9f_i128),
-                Fq::from(0xd1d23fce7622ffdb_i128),
-                Fq::from(0x19fb4fd353b93720_i128),
-                Fq::from(0x0b10a7fa35d8ec2f_i128),
-                Fq::from(0x3aa84d8501781423_i128),
-                Fq::from(0x22b7f53339cb7513_i128),
-                Fq::from(0xaeacf0bc09034211_i128),
-                Fq::from(0x17462ccb38556a39_i128),
-                Fq::from(0xbabc459b985c611e_i128),
-                Fq::from(0x97cb229412cf4fd7_i128),
-                Fq::from(0x60c41e807f5288b2_i128),
-                Fq::from(0x601463e4b0fcfc28_i128),
-                Fq::from(0xa15eb67b20979f19_i128),
-                Fq::from(0xb59e96953cfe10e2_i128),
-                Fq::from(0xeade0fb7d322984b_i128),
-                Fq::from(0x8c822813528b44da_i128),
-                Fq::from(0x6e1031b5982b9904_i128),
-                Fq::from(0x83b8b54c5e0de780_i128),
-                Fq::from(0x870cf1efea560234_i128),
-                Fq::from(0xa600a53c11e9eb80_i128),
-                Fq::from(0x3d2882836770f783_i128),
-                Fq::from(0x3ac7b9c3d1b01d23_i128),
-                Fq::from(0x55c70ee1a7d4b3ba_i128),
-                Fq::from(0x30638435e6b1f039_i128),
-            ],
-            vec![
-                Fq::from(0x70e1fe292d854119_i128),
-                Fq::from(0xdc4784dce330f8d9_i128),
-                Fq::from(0xa8ae0b239b820a65_i128),
-                Fq::from(0xac98204b001da3dd_i128),
-                Fq::from(0x9c8864a6beea1ee7_i128),
-                Fq::from(0x13baebb85737b0d3_i128),
-                Fq::from(0xe4e2da60e5400cc4_i128),
-                Fq::from(0xd0e6c79e013bfe8c_i128),
-                Fq::from(0x041a6548aecfd520_i128),
-                Fq::from(0xedd73ee58a647f8a_i128),
-                Fq::from(0x61d51ec95237546f_i128),
-                Fq::from(0x542cefa223d2b1c7_i128),
-                Fq::from(0x716d89f3612e8225_i128),
-                Fq::from(0x5935696457764161_i128),
-                Fq::from(0x74fe91eca39af8c4_i128),
-                Fq::from(0x91d81c57127c7f0a_i128),
-                Fq::from(0xa0e5b704ca60be49_i128),
-                Fq::from(0x4e58a4a5a23960c7_i128),
-                Fq::from(0x7f792fb5dc43c091_i128),
-                Fq::from(0x9c17778c1845a141_i128),
-                Fq::from(0x1fbd05e2f8991e74_i128),
-                Fq::from(0x21cf531acbf42624_i128),
-                Fq::from(0xe89091c68618db99_i128),
-                Fq::from(0x063b2b63b355e67a_i128),
-            ],
-            vec![
-                Fq::from(0x495c708f428c5407_i128),
-                Fq::from(0x41a0bda5cb74f93a_i128),
-                Fq::from(0x439fcb560a6fc868_i128),
-                Fq::from(0x77b597ab8ae8ab10_i128),
-                Fq::from(0xc65676ccd03046d5_i128),
-                Fq::from(0x3b13a96b6e31e3e2_i128),
-                Fq::from(0x7292eca069830219_i128),
-                Fq::from(0x80b0dc886fb738d8_i128),
-                Fq::from(0x43898baf80328b3d_i128),
-                Fq::from(0xf7bc32c8a25df378_i128),
-                Fq::from(0x91eed6d925dbc3f9_i128),
-                Fq::from(0x0dbbc585b6dad5ef_i128),
-                Fq::from(0x2e0ef4e8d2f023f3_i128),
-                Fq::from(0x32830b8485c30573_i128),
-                Fq::from(0xa968dd50b3d2b2b0_i128),
-                Fq::from(0xee7a7b3492c123b1_i128),
-                Fq::from(0x97b5a5b3f790a7c6_i128),
-                Fq::from(0x0e82f741e12ab8a6_i128),
-                Fq::from(0x0a32bb7e61903e70_i128),
-                Fq::from(0x544a084d84d11b1c_i128),
-                Fq::from(0x7700a13108a6532b_i128),
-                Fq::from(0xcc089a88ab80249e_i128),
-                Fq::from(0x16411f9a6065413c_i128),
-                Fq::from(0x165668d331d4d540_i128),
-            ],
-            vec![
-                Fq::from(0x5ed6d89fe6924a43_i128),
-                Fq::from(0xcbca1c3c36584b1b_i128),
-                Fq::from(0xaae1f3f5ce373378_i128),
-                Fq::from(0x64cac3b6c5782377_i128),
-                Fq::from(0xd3768367f9105aa4_i128),
-                Fq::from(0x4414d1db2f902dde_i128),
-                Fq::from(0xbf1f3218d082865e_i128),
-                Fq::from(0x98d423a76d7cfa9f_i128),
-                Fq::from(0x6bfab27171138269_i128),
-                Fq::from(0xcc832d29f9453be3_i128),
-                Fq::from(0xedce62132ddd9004_i128),
-                Fq::from(0xd42095f5a81307dc_i128),
-                Fq::from(0xb365c562a15d4cbd_i128),
-                Fq::from(0x46db7026067e9d79_i128),
-                Fq::from(0x8e652c470807079c_i128),
-                Fq::from(0x0b8317e6cb0fb519_i128),
-                Fq::from(0x1e6d710a96a0661d_i128),
-                Fq::from(0xa2832149e4e33c56_i128),
-                Fq::from(0x7aa5bf9079a56b2b_i128),
-                Fq::from(0x43fbee5d52a865ca_i128),
-                Fq::from(0x1f2649751d684f0c_i128),
-                Fq::from(0x3cbd3e30663c8aab_i128),
-                Fq::from(0x2503d3565c6b1271_i128),
-                Fq::from(0xd1b6c6431a5c0816_i128),
-            ],
-            vec![
-                Fq::from(0x45a182276f6a0986_i128),
-                Fq::from(0xe0f721966bdc89eb_i128),
-                Fq::from(0xa39e5a6f1ddb33ec_i128),
-                Fq::from(0xf2d6c2e8c5206fe0_i128),
-                Fq::from(0x79b990aaaf02d905_i128),
-                Fq::from(0xb52859771f63dab6_i128),
-                Fq::from(0xfb193201ed0a3a93_i128),
-                Fq::from(0x2474334700abe4d6_i128),
-                Fq::from(0x589507a4a4ffebe4_i128),
-                Fq::from(0x60fc7a0ac9ee5488_i128),
-                Fq::from(0x2220732050404a8e_i128),
-                Fq::from(0x005d0d2202c45b53_i128),
-                Fq::from(0x65429dc2cc25f163_i128),
-                Fq::from(0x3e97114b63b799cc_i128),
-                Fq::from(0xbd942fc56751fd57_i128),
-                Fq::from(0x456b22d8a37c2464_i128),
-                Fq::from(0x8ff3bb0e3e1d0ee7_i128),
-                Fq::from(0x3955689712515ece_i128),
-                Fq::from(0xb316b0791f6ccfb9_i128),
-                Fq::from(0x26c2dba328b61ed9_i128),
-                Fq::from(0x938570a663d8adc8_i128),
-                Fq::from(0xb7218124d033141a_i128),
-                Fq::from(0xb1ff3c436b6afd75_i128),
-                Fq::from(0x72604031a5a7283d_i128),
-            ],
-            vec![
-                Fq::from(0x8ed3b68a991c4c2a_i128),
-                Fq::from(0xddd1e0c78f469137_i128),
-                Fq::from(0x6c5376cd5b7fcc10_i128),
-                Fq::from(0x6f8ee3b053eee9d3_i128),
-                Fq::from(0x9dd7bafd228c49d0_i128),
-                Fq::from(0x5ad67f1a71dbb664_i128),
-                Fq::from(0x3f7985ea12dd1706_i128),
-                Fq::from(0x29d51b7931de2ede_i128),
-                Fq::from(0x930f8bd81d72da91_i128),
-                Fq::from(0xe0674af4d1ec6d85_i128),
-                Fq::from(0x71f37c1a8c0805f7_i128),
-                Fq::from(0x07b7b001b8dd00fe_i128),
-                Fq::from(0xaf576ecf1c0f411b_i128),
-                Fq::from(0xf57002e63c22d74f_i128),
-                Fq::from(0x2580a3de9a3a5abe_i128),
-                Fq::from(0xf8a060907a191899_i128),
-                Fq::from(0xcd2af931e55596c4_i128),
-                Fq::from(0x16dfaf4b40a9c4fa_i128),
-                Fq::from(0x2e6ee13f07983235_i128),
-                Fq::from(0xe749ecef64d63dfe_i128),
-                Fq::from(0x53f221e4b65e07cc_i128),
-                Fq::from(0x45b059bdfe11bcd0_i128),
-                Fq::from(0x1393fe7ad53c1cb9_i128),
-                Fq::from(0xab3424570e63fa76_i128),
-            ],
-            vec![
-                Fq::from(0xc5ae17e406d14f1c_i128),
-                Fq::from(0xb0f91d8b154ce8a9_i128),
-                Fq::from(0xb4a5056782cb39f8_i128),
-                Fq::from(0x16774735c2b1a754_i128),
-                Fq::from(0x85a1d9db678e01da_i128),
-                Fq::from(0xb81a935f9760153d_i128),
-                Fq::from(0xcc46cdb3f6fa159a_i128),
-                Fq::from(0xdf76416cf26d24f6_i128),
-                Fq::from(0xba3d97c6b3bd8507_i128),
-                Fq::from(0x8a0947d397eff0e5_i128),
-                Fq::from(0xe2945b0f461e1bac_i128),
-                Fq::from(0xd493d63ac6497473_i128),
-                Fq::from(0x588daa834230a59b_i128),
-                Fq::from(0x20cb06ecaed4184b_i128),
-                Fq::from(0x7ac9e1b0ae5d87a3_i128),
-                Fq::from(0x84726227c5549094_i128),
-                Fq::from(0xf24176a7a874c684_i128),
-                Fq::from(0xe5af08a57af306af_i128),
-                Fq::from(0x915fd3aa481dc75b_i128),
-                Fq::from(0xd38c8c779462b667_i128),
-                Fq::from(0xc53e6e415de04194_i128),
-                Fq::from(0xd4b58b6c9901102a_i128),
-                Fq::from(0xe9ee1badc53c3be4_i128),
-                Fq::from(0x20de77729749e5ad_i128),
-            ],
-            vec![
-                Fq::from(0x416a09b99d5aa758_i128),
-                Fq::from(0x473ece84c6331c0c_i128),
-                Fq::from(0x5086b279ce5a889a_i128),
-                Fq::from(0xcdc7731deee8a46e_i128),
-                Fq::from(0x9696568d8313bcea_i128),
-                Fq::from(0x4f3911ba864e6b82_i128),
-                Fq::from(0xa6b9435aadf0edfd_i128),
-                Fq::from(0x10846051bb86ced1_i128),
-                Fq::from(0xfb61505ed90b0a91_i128),
-                Fq::from(0x437b8d32f843ca06_i128),
-                Fq::from(0xcc9cbe26e2d29db1_i128),
-                Fq::from(0x7855757e39712e9a_i128),
-                Fq::from(0x3f321f61419459fd_i128),
-                Fq::from(0x7232248f5bbcffbd_i128),
-                Fq::from(0xdd79bae4544819bb_i128),
-                Fq::from(0x11ce0e4d13b4931b_i128),
-                Fq::from(0x6233ea9f12966a9f_i128),
-                Fq::from(0x68d47e9d914106e1_i128),
-                Fq::from(0x3435c9e0351948b2_i128),
-                Fq::from(0xafd14d9ad2c01b12_i128),
-                Fq::from(0xef90c93fc110c23d_i128),
-                Fq::from(0x32a825d645ae478a_i128),
-                Fq::from(0x9b3ab6811e6c13f1_i128),
-                Fq::from(0x94b0125d178aac9d_i128),
-            ],
-            vec![
-                Fq::from(0x886a1e92d5a92df8_i128),
-                Fq::from(0x97b334c9b8cd2deb_i128),
-                Fq::from(0x1d041125dc72ddcc_i128),
-                Fq::from(0xf732f2a4fefcc3c0_i128),
-                Fq::from(0xfaa05dfebd06148a_i128),
-                Fq::from(0x390bea785cacb3b1_i128),
-                Fq::from(0xb8238931b7780ce7_i128),
-                Fq::from(0x583b217aba5e5ead_i128),
-                Fq::from(0x00dd33813e7c9a14_i128),
-                Fq::from(0x018a415e29ba69e2_i128),
-                Fq::from(0x87f0118840634a6e_i128),
-                Fq::from(0x4211aad8b9c675ff_i128),
-                Fq::from(0x056595bdc862a03a_i128),
-                Fq::from(0xa815b33d2f2b16ec_i128),
-                Fq::from(0xb09e804a48678697_i128),
-                Fq::from(0xac81f7a133a531f7_i128),
-                Fq::from(0xae5a90952bae7626_i128),
-                Fq::from(0xb14edc1c86ad3028_i128),
-                Fq::from(0xb72861857aaf1b97_i128),
-                Fq::from(0xa5293f8608044c0b_i128),
-                Fq::from(0x9670bf8e2b8aebaf_i128),
-                Fq::from(0x88c5072fa96f5900_i128),
-                Fq::from(0xaf236e2127c17e30_i128),
-                Fq::from(0x1bccbfd05c24069f_i128),
-            ],
-            vec![
-                Fq::from(0x376ac12932dfd6a3_i128),
-                Fq::from(0x002e44ff66f7142e_i128),
-                Fq::from(0x445fa9db5939e32f_i128),
-                Fq::from(0x1e7ef52b37a6a4ed_i128),
-                Fq::from(0x6114bcb1073e316d_i128),
-                Fq::from(0x6be21eb68d0400be_i128),
-                Fq::from(0x723beaa709da00fc_i128),
-                Fq::from(0x3d2b09dbade93f96_i128),
-                Fq::from(0xab0a0487bf9c78a1_i128),
-                Fq::from(0xce0f0c5a2b459b3f_i128),
-                Fq::from(0xd4c581b8b6b6af78_i128),
-                Fq::from(0xe0f5cd2c48764fd0_i128),
-                Fq::from(0xf59500e60ea86bb4_i128),
-                Fq::from(0x7ebb01fd446d15f2_i128),
-                Fq::from(0x85d8ac7f1342dab0_i128),
-                Fq::from(0xe015b612c72c2bd9_i128),
-                Fq::from(0xd7f43986b8f29dce_i128),
-                Fq::from(0xaf0b0d647183a9b9_i128),
-                Fq::from(0xad6163ca06c6cc96_i128),
-                Fq::from(0x55e4155c23d5df82_i128),
-                Fq::from(0x7e5e3397db582d35_i128),
-                Fq::from(0x200c61224abd503a_i128),
-                Fq::from(0xeee2c631e8ead78d_i128),
-                Fq::from(0x77256c45be60b3f7_i128),
-            ],
-            vec![
-                Fq::from(0x18135e1b8ea31bdc_i128),
-                Fq::from(0x87809c7ac25060d7_i128),
-                Fq::from(0x6849076791034cd2_i128),
-                Fq::from(0x11052c2f2b632fe0_i128),
-                Fq::from(0x7a55f4cc2539c1c8_i128),
-                Fq::from(0x93eabf9ef17771ca_i128),
-                Fq::from(0x0749e7e01c3de8b6_i128),
-                Fq::from(0x4a34d515312fc31b_i128),
-                Fq::from(0x05ca352f4f7073d1_i128),
-                Fq::from(0xf652d29e7b9e2a31_i128),
-                Fq::from(0x0b23ae3540e998ec_i128),
-                Fq::from(0x2f2ccd8c85c64b8d_i128),
-                Fq::from(0x06e9902f75273139_i128),
-                Fq::from(0xf94dd66a64dbc7fd_i128),
-                Fq::from(0xff75cc7e6a7ec0f1_i128),
-                Fq::from(0x1c3cb98a40a43131_i128),
-                Fq::from(0xe48d582f32480f37_i128),
-                Fq::from(0x4a3eef0b12f2524d_i128),
-                Fq::from(0x99761629ffa8c8c9_i128),
-                Fq::from(0xb0093e7c3419aaca_i128),
-                Fq::from(0xbcb2f73bf39ba594_i128),
-                Fq::from(0x88000f314f8c96ca_i128),
-                Fq::from(0xe34057b156536f00_i128),
-                Fq::from(0xbd7833560e71615d_i128),
-            ],
-            vec![
-                Fq::from(0x32a946eeb6fec92a_i128),
-                Fq::from(0x0a6a85748461606e_i128),
-                Fq::from(0x5cba2346dcd7849d_i128),
-                Fq::from(0x13e9c0842a69cfc4_i128),
-                Fq::from(0x682228b4b5c94ff1_i128),
-                Fq::from(0xcb1316389a14427d_i128),
-                Fq::from(0xa2b33f775c13f863_i128),
-                Fq::from(0x5b2480fb74eaf23d_i128),
-                Fq::from(0xfdb24724a0211e95_i128),
-                Fq::from(0x90d74814aeb23167_i128),
-                Fq::from(0xa259daef26d4ccd1_i128),
-                Fq::from(0x35c8e4936d1c0f67_i128),
-                Fq::from(0xe50aaf79ab598b9a_i128),
-                Fq::from(0xd2f43b60a359ecc5_i128),
-                Fq::from(0x07c7b03b854841e5_i128),
-                Fq::from(0x0ecd0b915ee0db18_i128),
-                Fq::from(0x8d5f6a8bd73c16f6_i128),
-                Fq::from(0xf22d9e2d37727eaa_i128),
-                Fq::from(0x0d2883de508466fc_i128),
-                Fq::from(0x36abc80dc37612d8_i128),
-                Fq::from(0xa66af1d3c75d703c_i128),
-                Fq::from(0xefd052c8e54f9dd1_i128),
-                Fq::from(0x7e2884fd0cf4187f_i128),
-                Fq::from(0x1bade50d9d6d8e20_i128),
-            ],
-            vec![
-                Fq::from(0x596781cb0acf4c56_i128),
-                Fq::from(0xf5f2ff9734b83cea_i128),
-                Fq::from(0x898197bb713c7cc7_i128),
-                Fq::from(0x12b85d1eada79d56_i128),
-                Fq::from(0xc791272ab0f94a19_i128),
-                Fq::from(0x0473748941def820_i128),
-                Fq::from(0x1843333e86bc8baf_i128),
-                Fq::from(0xff98b703d9353e29_i128),
-                Fq::from(0x7d4c303335357bc0_i128),
-                Fq::from(0xf6d876d6985a74c6_i128),
-                Fq::from(0xe5954076df382a27_i128),
-                Fq::from(0x23c3807ffbb639e3_i128),
-                Fq::from(0x973dc7352ca546bd_i128),
-                Fq::from(0x6cba07ee4d5f18a9_i128),
-                Fq::from(0x3787f7e5abb24e99_i128),
-                Fq::from(0x488762017e0e81c2_i128),
-                Fq::from(0xb57715b42aa89e67_i128),
-                Fq::from(0xc209a364b0df2f9d_i128),
-                Fq::from(0x072814fd44cbf2da_i128),
-                Fq::from(0x6232ac5518a8a559_i128),
-                Fq::from(0x99619e884510dcae_i128),
-                Fq::from(0x07386a13a5f9662d_i128),
-                Fq::from(0x691ccfb6ca88c452_i128),
-                Fq::from(0x5fcf5321e22b5e76_i128),
-            ],
-            vec![
-                Fq::from(0x5d64102e8a84e8a4_i128),
-                Fq::from(0xcee6bf1078be6967_i128),
-                Fq::from(0x25e2ed5f1a68247f_i128),
-                Fq::from(0x10d311efd67eea96_i128),
-                Fq::from(0x1a745d030aebbc40_i128),
-                Fq::from(0x030ab4dd90371772_i128),
-                Fq::from(0xc1b8ec7f6b543e45_i128),
-                Fq::from(0x50e8a3e6333b32b9_i128),
-                Fq::from(0x8a0d8b1c7621daa8_i128),
-                Fq::from(0x759e936ca6b91938_i128),
-                Fq::from(0xbf0bbaf82fc4c0b0_i128),
-                Fq::from(0x4eb75bb703b4d6e3_i128),
-                Fq::from(0x66aed9b20ace46f6_i128),
-                Fq::from(0xeee7f984ff048a96_i128),
-                Fq::from(0x66e71086327815d4_i128),
-                Fq::from(0xeb85bf106dc96f92_i128),
-                Fq::from(0x707b5d55622f7ecf_i128),
-                Fq::from(0x38089e4627c11cdb_i128),
-                Fq::from(0x476e90101299f0df_i128),
-                Fq::from(0x03c7fec5d73597ee_i128),
-                Fq::from(0x738c430db7fb71d9_i128),
-                Fq::from(0x55d6181dcd09c7b4_i128),
-                Fq::from(0xced74df130317e25_i128),
-                Fq::from(0x5cf82d6027f37041_i128),
-            ],
-            vec![
-                Fq::from(0xcf48783625ff7226_i128),
-                Fq::from(0xd41290514f23ba7d_i128),
-                Fq::from(0xfa2f3ae78faf9f84_i128),
-                Fq::from(0xafb5d4a2945e6ee9_i128),
-                Fq::from(0x964e608185c6b8fa_i128),
-                Fq::from(0xdc2e37ea7d2635c1_i128),
-                Fq::from(0x7bc52396daa20476_i128),
-                Fq::from(0x1deb31f73737e5af_i128),
-                Fq::from(0x106d8f1b8cc17f99_i128),
-                Fq::from(0xaff7e7e7abda6bab_i128),
-                Fq::from(0x57a38cab2998db16_i128),
-                Fq::from(0x50160e8b08a1f89f_i128),
-                Fq::from(0xa37026c95d8416d6_i128),
-                Fq::from(0xa5754bd74b99066b_i128),
-                Fq::from(0xfe7da192126d0cc5_i128),
-                Fq::from(0xdd2331eaf30a5c38_i128),
-                Fq::from(0x2109532b685015c6_i128),
-                Fq::from(0x1697fda3b1cece3a_i128),
-                Fq::from(0x364f1a76012ef83c_i128),
-                Fq::from(0xfc5ffdfce8ebbf00_i128),
-                Fq::from(0x2e0e9d8b0a1576ce_i128),
-                Fq::from(0xbc8fdd2652908029_i128),
-                Fq::from(0x7207fd7852aa795f_i128),
-                Fq::from(0xd3f3eaa8a4d55c4e_i128),
-            ],
-            vec![
-                Fq::from(0xa9f5e19d363aced5_i128),
-                Fq::from(0x5931b202ded2cfcb_i128),
-                Fq::from(0xc5a1f208d96fcf9c_i128),
-                Fq::from(0x61e4dbdf78e9b17a_i128),
-                Fq::from(0xace26406aff7cef6_i128),
-                Fq::from(0xd7df38236c7eb91d_i128),
-                Fq::from(0x8addbc9fdf33a257_i128),
-                Fq::from(0x6c0b3f627ac201fd_i128),
-                Fq::from(0xaf0b780e48a21459_i128),
-                Fq::from(0xe91f3a721e62e77a_i128),
-                Fq::from(0x868aace51ad5e2b9_i128),
-                Fq::from(0x62b3ee5a9e6f1e5f_i128),
-                Fq::from(0x57474919a2b1e6e1_i128),
-                Fq::from(0xc44722eec99dfe59_i128),
-                Fq::from(0xfe289e6fbc4cdd4a_i128),
-                Fq::from(0x2002dba4868a5811_i128),
-                Fq::from(0x60d344f23b88f1d8_i128),
-                Fq::from(0x5f6910c461be8f13_i128),
-                Fq::from(0x602cc672047d168e_i128),
-                Fq::from(0xa0a3de26bd160050_i128),
-                Fq::from(0x4570d62788372c05_i128),
-                Fq::from(0x142b1867aaa5fe9b_i128),
-                Fq::from(0x9b5dc028259fb27f_i128),
-                Fq::from(0xc87f615b8ebb5537_i128),
-            ],
-            vec![
-                Fq::from(0xb218f13b6fc66834_i128),
-                Fq::from(0xdd8195ca8957762a_i128),
-                Fq::from(0x61bc85c3132ef924_i128),
-                Fq::from(0x67cfd5d6685bcae2_i128),
-                Fq::from(0x92c0f570fbc255c9_i128),
-                Fq::from(0xe6220d6e4a251a25_i128),
-                Fq::from(0x02ce088f874d8496_i128),
-                Fq::from(0x5aa92f8101235fe2_i128),
-                Fq::from(0xfb5aee665ecb6c64_i128),
-                Fq::from(0x3f9d8ba60234bb22_i128),
-                Fq::from(0x05b60220c5fb3a0e_i128),
-                Fq::from(0x6193b67e9d9e67ee_i128),
-                Fq::from(0xf15f27e8b68970e7_i128),
-                Fq::from(0x6ba3e1113a5f4411_i128),
-                Fq::from(0xd0bc3cfcb076ca52_i128),
-                Fq::from(0x2dfdb1f597460643_i128),
-                Fq::from(0x956d1aa388dbab05_i128),
-                Fq::from(0xeebafa432b0702da_i128),
-                Fq::from(0x3220aa43c6e4a032_i128),
-                Fq::from(0x765c6ec86da3ce54_i128),
-                Fq::from(0xf3dd2bbe9d1fce50_i128),
-                Fq::from(0x8ca72fe5205221a7_i128),
-                Fq::from(0x1a0e275b49df9be0_i128),
-                Fq::from(0xf50f8a27c838eeac_i128),
-            ],
-            vec![
-                Fq::from(0x914099e876e8d230_i128),
-                Fq::from(0x6997aec228ac2252_i128),
-                Fq::from(0x2f5c933466e9e37b_i128),
-                Fq::from(0x195f4174e05827d2_i128),
-                Fq::from(0x3174b8f68abae376_i128),
-                Fq::from(0x7083281ab357b661_i128),
-                Fq::from(0x7de0ce8656047365_i128),
-                Fq::from(0xddfe36d6b0e93518_i128),
-                Fq::from(0xf4f0c61082fc67d6_i128),
-                Fq::from(0x2bf8744c5e854620_i128),
-                Fq::from(0x202664d1a1bfc63b_i128),
-                Fq::from(0x4cbbb78c359b2233_i128),
-                Fq::from(0x7aa39816977e2237_i128),
-                Fq::from(0xe958dc803bf22275_i128),
-                Fq::from(0x8a6ebad07ab8c641_i128),
-                Fq::from(0xf4413b71e4100b81_i128),
-                Fq::from(0x5cbdfffdb4474a3f_i128),
-                Fq::from(0x7d32aecef2dc595b_i128),
-                Fq::from(0x224f1350a09c9415_i128),
-                Fq::from(0x2ce590758688eaea_i128),
-                Fq::from(0x9aad856874e55da8_i128),
-                Fq::from(0x7c66bdbe3dffcfcb_i128),
-                Fq::from(0x988671ca3d6db9ce_i128),
-                Fq::from(0xc272205fa72dd055_i128),
-            ],
-        ];
-
-        let mds = vec![
-            vec![
-                Fq::from(0xabd0a077d40eb130_i128),
-                Fq::from(0x5037701d962818bd_i128),
-                Fq::from(0xa9cc9c1b2a840efb_i128),
-                Fq::from(0xccee74e2cb3ea4cb_i128),
-                Fq::from(0x3c078cb0f8c150ee_i128),
-                Fq::from(0xa980586ab26b3193_i128),
-                Fq::from(0x6aa4b27c9a427876_i128),
-                Fq::from(0xb20a7c9cc4d64f31_i128),
-                Fq::from(0x0b791c321ef3fbe7_i128),
-                Fq::from(0x91199170af33655f_i128),
-                Fq::from(0x05e6cc9564f02f10_i128),
-                Fq::from(0x1b36f9f30ddadd23_i128),
-                Fq::from(0x78763e0620055dfb_i128),
-                Fq::from(0xa4c3043ceafae0e1_i128),
-                Fq::from(0x251b1bd462b187c4_i128),
-                Fq::from(0x63c2fa0ce7b2b615_i128),
-                Fq::from(0x8f7eb7a918f8c8b8_i128),
-                Fq::from(0x39b43e85fc0eb593_i128),
-                Fq::from(0x9dbf75e36f76bb26_i128),
-                Fq::from(0xd99234ce8fa2a0b6_i128),
-                Fq::from(0xb1cfc278173c4a29_i128),
-                Fq::from(0xb049246072c07b64_i128),
-                Fq::from(0x510613dc7ee25b2f_i128),
-                Fq::from(0xc09de95cb358f842_i128),
-            ],
-            vec![
-                Fq::from(0x3437b3455b6dd333_i128),
-                Fq::from(0x341d4cb0fd854739_i128),
-                Fq::from(0x71f363f934ea6649_i128),
-                Fq::from(0x2e24a044bbaddf1b_i128),
-                Fq::from(0xdc65efd2426abc34_i128),
-                Fq::from(0xb31b79a3208c0b7a_i128),
-                Fq::from(0x389a14a3b17f29a6_i128),
-                Fq::from(0x9cc844d8333d966b_i128),
-                Fq::from(0xcc6095ed80972090_i128),
-                Fq::from(0x42659b17e5ec1a63_i128),
-                Fq::from(0xc163f50be585f830_i128),
-                Fq::from(0x1c2cd2ea704f77fc_i128),
-                Fq::from(0xb0b13a8ad8d97c08_i128),
-                Fq::from(0x423d3e37e8ec491e_i128),
-                Fq::from(0xe7583736b1c9fc5b_i128),
-                Fq::from(0x3fab89b459456992_i128),
-                Fq::from(0xae3d4411935a0874_i128),
-                Fq::from(0x3c5bb29e2fec9308_i128),
-                Fq::from(0xc9a5f0c11d766fe7_i128),
-                Fq::from(0x5b954d9d83a60285_i128),
-                Fq::from(0x5e7cee8a0cf2605a_i128),
-                Fq::from(0x27b63a4075c22f57_i128),
-                Fq::from(0x196d89b0afa46bce_i128),
-                Fq::from(0x2123777db2e15b8f_i128),
-            ],
-            vec![
-                Fq::from(0x34c96e79640e0539_i128),
-                Fq::from(0x66004b55224b7deb_i128),
-                Fq::from(0xf826c1855f3f20da_i128),
-                Fq::from(0xd77955ecdbc1163f_i128),
-                Fq::from(0x3d86a61427dd3918_i128),
-                Fq::from(0x337dad083aebfd9f_i128),
-                Fq::from(0x83577934130f407f_i128),
-                Fq::from(0x4a6af6fa8cc25e64_i128),
-                Fq::from(0x33803c4f592630db_i128),
-                Fq::from(0xaa948b32f60a0b99_i128),
-                Fq::from(0x9c33a5bd0aace338_i128),
-                Fq::from(0x862997f377759eae_i128),
-                Fq::from(0x36547d587b5bc2b3_i128),
-                Fq::from(0x288a32a127c0e8c9_i128),
-                Fq::from(0xfdc3e6ec4063ab66_i128),
-                Fq::from(0x544d4b23ee9d6ac6_i128),
-                Fq::from(0x4dac9b969091e4e1_i128),
-                Fq::from(0x27d92d34f7cf293f_i128),
-                Fq::from(0x5eb37849ae454026_i128),
-                Fq::from(0xdf74ab06ae1fdb5f_i128),
-                Fq::from(0x86f85b48b2bc2bf0_i128),
-                Fq::from(0xc8640b9199196471_i128),
-                Fq::from(0x7ef43c87e578c9a6_i128),
-                Fq::from(0x262062cc7004bc4f_i128),
-            ],
-            vec![
-                Fq::from(0xce2869d4643d4304_i128),
-                Fq::from(0x0738ff64d4ee8f31_i128),
-                Fq::from(0x570cf3f26df6d3dd_i128),
-                Fq::from(0x25b42ae34a6670cc_i128),
-                Fq::from(0xcf73c9c82c884afc_i128),
-                Fq::from(0x223e5cdecb2ef2bb_i128),
-                Fq::from(0x26ebc55e27ab97d2_i128),
-                Fq::from(0x6f81e38cfdd3e13c_i128),
-                Fq::from(0x589b7633dd5051e1_i128),
-                Fq::from(0xb32da2a7858ff35e_i128),
-                Fq::from(0x1862fc82229cd3af_i128),
-                Fq::from(0x58e9519508cc571f_i128),
-                Fq::from(0xf189b53fc8290e32_i128),
-                Fq::from(0xebfb03723842d649_i128),
-                Fq::from(0x1966a81ea83a0caa_i128),
-                Fq::from(0x077178e6f0b9f694_i128),
-                Fq::from(0x9ad4d6baaea63bb8_i128),
-                Fq::from(0x9267be45f3fa9952_i128),
-                Fq::from(0xc06151c511fdb9d4_i128),
-                Fq::from(0xa359f4e4b318b4e5_i128),
-                Fq::from(0x0fa506dbf143938f_i128),
-                Fq::from(0x6697db5ffb9b3b2b_i128),
-                Fq::from(0xe73acda5944ec76a_i128),
-                Fq::from(0xd2d67794aa5e1b64_i128),
-            ],
-            vec![
-                Fq::from(0x16fae1b2abae0241_i128),
-                Fq::from(0xe00e191e8ea8e4ed_i128),
-                Fq::from(0xccc2fa8f3621a893_i128),
-                Fq::from(0x2ec0f7a48566435b_i128),
-                Fq::from(0x5f9afcbcf5590d38_i128),
-                Fq::from(0x95d576e2628eb3cd_i128),
-                Fq::from(0xa7ab0cb783af35d4_i128),
-                Fq::from(0x1f2bc8282874cc85_i128),
-                Fq::from(0xd65d0662dfcf9e9b_i128),
-                Fq::from(0x9e4dd80b10dcd8c6_i128),
-                Fq::from(0x504ec0a8821a2559_i128),
-                Fq::from(0x11e9b993be1b7f19_i128),
-                Fq::from(0xd2ee20bcc1bec226_i128),
-                Fq::from(0xf45f747201e2478f_i128),
-                Fq::from(0x4c09ad982464e748_i128),
-                Fq::from(0x9e8c7cdf686192e1_i128),
-                Fq::from(0x9b815f8277ff1372_i128),
-                Fq::from(0xb01fc436f79399f5_i128),
-                Fq::from(0x2dfbd8dc10cdb537_i128),
-                Fq::from(0x636a7465f0e2247a_i128),
-                Fq::from(0xd37eb97026918307_i128),
-                Fq::from(0x6b0c87b5b167b0a4_i128),
-                Fq::from(0x2c247890275aa171_i128),
-                Fq::from(0x291d841e535a3219_i128),
-            ],
-            vec![
-                Fq::from(0x71676f9fafabf0da_i128),
-                Fq::from(0x14eb63b11f1c82d0_i128),
-                Fq::from(0xa7c387d41b4aae9d_i128),
-                Fq::from(0xcdff0c0b64b42b6f_i128),
-                Fq::from(0x23bc31e056c323e0_i128),
-                Fq::from(0x3bfe78b4d090f0de_i128),
-                Fq::from(0x8fcf1913195660e8_i128),
-                Fq::from(0xcbb10a69f08cb5f3_i128),
-                Fq::from(0x05bf632df898791d_i128),
-                Fq::from(0xc1a82976d63fe521_i128),
-                Fq::from(0x513e878f24b7014e_i128),
-                Fq::from(0x8931bcd680940bb5_i128),
-                Fq::from(0x1485249c806d637d_i128),
-                Fq::from(0x11ffe7d8ceb9a47f_i128),
-                Fq::from(0x39133c2dbb69d75c_i128),
-                Fq::from(0x6947d2934ff3cf16_i128),
-                Fq::from(0xb2af3a9e0b487436_i128),
-                Fq::from(0x542cf4d292777bec_i128),
-                Fq::from(0x1d3575b7f466c953_i128),
-                Fq::from(0x57cbf9010020ba23_i128),
-                Fq::from(0x6e652d7d662ef677_i128),
-                Fq::from(0xa408f6e599d5e953_i128),
-                Fq::from(0xf29d7469ce34e02d_i128),
-                Fq::from(0xdb7d489c12f76551_i128),
-            ],
-            vec![
-                Fq::from(0x26e176f2718af7e8_i128),
-                Fq::from(0x5f5b2b2fd936e0d9_i128),
-                Fq::from(0x2100ea5ccbbcf831_i128),
-                Fq::from(0x8027bad387f4b2e6_i128),
-                Fq::from(0xae2aac1c7dc1c23a_i128),
-                Fq::from(0x933a73476d565138_i128),
-                Fq::from(0x49e57c5154d236a6_i128),
-                Fq::from(0xcdefaba0db03005b_i128),
-                Fq::from(0x63beb63a7c6c9b5c_i128),
-                Fq::from(0xa5a224160bbc3f2a_i128),
-                Fq::from(0x889009359ea256fb_i128),
-                Fq::from(0x2b3d724e957eb86e_i128),
-                Fq::from(0x887da66fcdd77480_i128),
-                Fq::from(0x3645282b86688333_i128),
-                Fq::from(0x460f5c66a4595c80_i128),
-                Fq::from(0x881def07e3b286f3_i128),
-                Fq::from(0x65f16cff94c958de_i128),
-                Fq::from(0x6f49bc42d2b63aa1_i128),
-                Fq::from(0x456235cd87323476_i128),
-                Fq::from(0x2d7e73de1e5eb585_i128),
-                Fq::from(0xdf16c396678fe8ee_i128),
-                Fq::from(0x49be9de07e4dc05d_i128),
-                Fq::from(0x555f1d686e9aca18_i128),
-                Fq::from(0xd0f8586d1445c5f4_i128),
-            ],
-            vec![
-                Fq::from(0xaff4a16dfa41f5f1_i128),
-                Fq::from(0x819c72c13e6e5763_i128),
-                Fq::from(0x0760beb12c8ca9ba_i128),
-                Fq::from(0xe510bac7d446918c_i128),
-                Fq::from(0xf0c00e8bc4ab5d2e_i128),
-                Fq::from(0x15e6b95b325b399b_i128),
-                Fq::from(0xf7d6d6a5caca676c_i128),
-                Fq::from(0x40dc281be3ed891a_i128),
-                Fq::from(0x408408a77732385a_i128),
-                Fq::from(0x0dd7c22773ceb947_i128),
-                Fq::from(0xca2fcef358e9b3ca_i128),
-                Fq::from(0x8e4522738cc8ae6a_i128),
-                Fq::from(0x661f4ea7ae82df95_i128),
-                Fq::from(0x7dad9b2ef1e80113_i128),
-                Fq::from(0xc1beedef68c9a79d_i128),
-                Fq::from(0xb43705d04c15d05b_i128),
-                Fq::from(0x234ed9b3f32da74a_i128),
-                Fq::from(0x967179641c3265bd_i128),
-                Fq::from(0x974e55fb0e10dbae_i128),
-                Fq::from(0x01d50f8bd849a8c0_i128),
-                Fq::from(0xe8caff9be30c8fa7_i128),
-                Fq::from(0x0cd2f75112e7e0cf_i128),
-                Fq::from(0x6a62e0d0385d818f_i128),
-                Fq::from(0xd492e6dd84286ece_i128),
-            ],
-            vec![
-                Fq::from(0x98a509da9d1b816c_i128),
-                Fq::from(0x267c539765e9dabc_i128),
-                Fq::from(0xaec7e17641c2e0f3_i128),
-                Fq::from(0x37bc359f9bfe7f06_i128),
-                Fq::from(0xe2667226e058a3d3_i128),
-                Fq::from(0xc495a8fb435586b5_i128),
-                Fq::from(0x14b8f8b71f2fcf0a_i128),
-                Fq::from(0x90c168ab57e747ea_i128),
-                Fq::from(0x48ba02b7f7fcb596_i128),
-                Fq::from(0x8750cd2ffe3e066c_i128),
-                Fq::from(0xdc8848e2b290321f_i128),
-                Fq::from(0x7dca1d5df7434030_i128),
-                Fq::from(0x8c6dc7bffa106788_i128),
-                Fq::from(0x2548da39c45773fc_i128),
-                Fq::from(0x14eb7899a38d3a32_i128),
-                Fq::from(0xff41809308ebd16c_i128),
-                Fq::from(0xf0b8feb07bc4354c_i128),
-                Fq::from(0x87024a3dd2ba54d3_i128),
-                Fq::from(0x9dd320459daa7735_i128),
-                Fq::from(0x043788947dc50309_i128),
-                Fq::from(0xfeca47841194489f_i128),
-                Fq::from(0x7fbc62bd62470005_i128),
-                Fq::from(0xccbb260e4dd0d209_i128),
-                Fq::from(0x76625f36a17c1a8d_i128),
-            ],
-            vec![
-                Fq::from(0x9457b7968cfa52e3_i128),
-                Fq::from(0x0f03816bf0fef01d_i128),
-                Fq::from(0x199b579d455e7d0b_i128),
-                Fq::from(0x54d56296ae710918_i128),
-                Fq::from(0x1cc4f884ea9c8d82_i128),
-                Fq::from(0x622beb302371c75d_i128),
-                Fq::from(0x5585d4adb825810e_i128),
-                Fq::from(0x1107fc6458f86b58_i128),
-                Fq::from(0xba9b129821253271_i128),
-                Fq::from(0x3e01dfdd55b40acd_i128),
-                Fq::from(0x1851d14dcdb2c058_i128),
-                Fq::from(0x3b86de5f4593837d_i128),
-                Fq::from(0x8762e3cd14ecb4cd_i128),
-                Fq::from(0x23a93a42f9f2105b_i128),
-                Fq::from(0x6be908065e670265_i128),
-                Fq::from(0x6b83e722de5079f6_i128),
-                Fq::from(0x293e9dbb69df3620_i128),
-                Fq::from(0xaa553faefd68eb78_i128),
-                Fq::from(0xa398b6c1651d264e_i128),
-                Fq::from(0x1b3b1414770ebb2a_i128),
-                Fq::from(0xbc647ee55b0e3efb_i128),
-                Fq::from(0x23759545da9aed96_i128),
-                Fq::from(0x0234593a91d50ae9_i128),
-                Fq::from(0x2ac60b39297c6e4a_i128),
-            ],
-            vec![
-                Fq::from(0xe470a09b5165f82c_i128),
-                Fq::from(0xb82d705040e73031_i128),
-                Fq::from(0x3351bc83b0244d64_i128),
-                Fq::from(0x2ae0af4b4c81eb63_i128),
-                Fq::from(0xaec51b96584644f7_i128),
-                Fq::from(0x68aef3e5b6b284b5_i128),
-                Fq::from(0xc2d3dca003b81301_i128),
-                Fq::from(0xa1446e4fab306e8a_i128),
-                Fq::from(0x634b14dacc026906_i128),
-                Fq::from(0x5fc396e3a78b6b1a_i128),
-                Fq::from(0xb39f70f998de3903_i128),
-                Fq::from(0x151d43b412ada162_i128),
-                Fq::from(0x6ce4b3a909286380_i128),
-                Fq::from(0x7ca0e6ec75557ae3_i128),
-                Fq::from(0x00449d78190ca1a8_i128),
-                Fq::from(0xd1f7542f0b283e03_i128),
-                Fq::from(0x689211ce41cc4a7c_i128),
-                Fq::from(0x088c2f4927ce6122_i128),
-                Fq::from(0x197ae67b82ca9800_i128),
-                Fq::from(0xcd5ee08c509b2c38_i128),
-                Fq::from(0xe696efaa3e57b6e0_i128),
-                Fq::from(0x8de9fc8b3b82570f_i128),
-                Fq::from(0x9e965d44f967db34_i128),
-                Fq::from(0xa87dda20853ab81b_i128),
-            ],
-            vec![
-                Fq::from(0x1a05546cd7ff5dc3_i128),
-                Fq::from(0xfeb37e3b66d6439b_i128),
-                Fq::from(0x36c3b58a82bfd67a_i128),
-                Fq::from(0x8afeee5cc17595da_i128),
-                Fq::from(0xc2058c6c52cdd905_i128),
-                Fq::from(0x80390a27176f7345_i128),
-                Fq::from(0x9c77827b953296d3_i128),
-                Fq::from(0x3f83bdca2c860999_i128),
-                Fq::from(0x7208576056c8aa3f_i128),
-                Fq::from(0xa4c31cc9bb665b49_i128),
-                Fq::from(0xc668d6898c93ed8d_i128),
-                Fq::from(0x5a836ea5e85b71b9_i128),
-                Fq::from(0xa12dcb523bda3d51_i128),
-                Fq::from(0x220fed161f5b0498_i128),
-                Fq::from(0x70fa235fe69a0593_i128),
-                Fq::from(0x8ea7097b6a23e839_i128),
-                Fq::from(0x73fda9ddcd8f3b56_i128),
-                Fq::from(0x9b55fed64c19b193_i128),
-                Fq::from(0xadd612d80adccfaf_i128),
-                Fq::from(0x2ba5f3e1132c8a8b_i128),
-                Fq::from(0x9515fd4136ca0f59_i128),
-                Fq::from(0x6092a2e8560031e1_i128),
-                Fq::from(0xdebca51d9e7e489d_i128),
-                Fq::from(0x74a55f846e0c8d7d_i128),
-            ],
-            vec![
-                Fq::from(0x9159c384f4de58e5_i128),
-                Fq::from(0xeaf299209fcd1518_i128),
-                Fq::from(0x6c4364cbb46cfc13_i128),
-                Fq::from(0xe7cc82667e18838a_i128),
-                Fq::from(0x2a6a3dedca39b211_i128),
-                Fq::from(0x05022ef5cc9d93ec_i128),
-                Fq::from(0x4eef5d9865b8bd01_i128),
-                Fq::from(0x7be3e9c64080760d_i128),
-                Fq::from(0x0c156b42f2629136_i128),
-                Fq::from(0xa3f052e53d4ebda5_i128),
-                Fq::from(0x875b8455789fa496_i128),
-                Fq::from(0xf936f6638b9df330_i128),
-                Fq::from(0xd5e81e3ec8bbcea2_i128),
-                Fq::from(0x2471eebd84ceaf99_i128),
-                Fq::from(0x4849ac0e3735481a_i128),
-                Fq::from(0xe125b50f31e4f133_i128),
-                Fq::from(0xeaba7f3bbe51ce7e_i128),
-                Fq::from(0x3c6fa7b6d1b75541_i128),
-                Fq::from(0x7e39d4be8317dc17_i128),
-                Fq::from(0xc9b2c9c22c116b94_i128),
-                Fq::from(0x16653e026bd1a2c3_i128),
-                Fq::from(0x21edc93786158789_i128),
-                Fq::from(0x3a28bbef607ae6a9_i128),
-                Fq::from(0x721bc9b50e18320e_i128),
-            ],
-            vec![
-                Fq::from(0x8a003172ed31075b_i128),
-                Fq::from(0x2b543ff33a481e8a_i128),
-                Fq::from(0xeb71b400267c5d33_i128),
-                Fq::from(0x71e6f7daab216e68_i128),
-                Fq::from(0xe9ee1a6d478aea47_i128),
-                Fq::from(0x3f03ea3932de08b3_i128),
-                Fq::from(0x5fe91e49e67408e7_i128),
-                Fq::from(0x079aa05156d2004c_i128),
-                Fq::from(0x4b3da743548f74f8_i128),
-                Fq::from(0x85ce771dbdccb2a2_i128),
-                Fq::from(0x2bd1e46d78af567c_i128),
-                Fq::from(0xf394ecb66d84c04d_i128),
-                Fq::from(0xdad1a6f49d0aebdf_i128),
-                Fq::from(0xfdda8f614c7e7094_i128),
-                Fq::from(0x70836a8489576908_i128),
-                Fq::from(0x82bed35c5c37c0a8_i128),
-                Fq::from(0xebe0ffb46a42d647_i128),
-                Fq::from(0xa7b572335b8a8411_i128),
-                Fq::from(0x9fd7c1516be6e8f0_i128),
-                Fq::from(0x3701adf0e1d4505a_i128),
-                Fq::from(0x6116337205db745c_i128),
-                Fq::from(0x49b6771fb70c87bf_i128),
-                Fq::from(0xa735887761aa2679_i128),
-                Fq::from(0xe7558080dfd4507e_i128),
-            ],
-            vec![
-                Fq::from(0x84bda650396c7240_i128),
-                Fq::from(0x504dd1d952051a1d_i128),
-                Fq::from(0x961daea972d9e317_i128),
-                Fq::from(0xff513958c6054dab_i128),
-                Fq::from(0xb02dd02d9791d2fa_i128),
-                Fq::from(0x6347aac85f00f6ef_i128),
-                Fq::from(0x7682e5b0522ca812_i128),
-                Fq::from(0xe8214811fc8bf168_i128),
-                Fq::from(0x941b1db929f0640d_i128),
-                Fq::from(0x1bf8a67d43870bef_i128),
-                Fq::from(0x8c90a3ae0fe8e810_i128),
-                Fq::from(0x354a0fb68a238698_i128),
-                Fq::from(0x176f0d4eacefad2d_i128),
-                Fq::from(0x5fcba813b2b51f47_i128),
-                Fq::from(0xc101c62458ad4b2a_i128),
-                Fq::from(0x7187f693398c1dec_i128),
-                Fq::from(0x11e760e7a29597e1_i128),
-                Fq::from(0x738be0b5c8c3fbcc_i128),
-                Fq::from(0xb78da909ee6d4b73_i128),
-                Fq::from(0x3c5a711b585476e5_i128),
-                Fq::from(0x99a938d867bb79c6_i128),
-                Fq::from(0xb8c4cc5cfe7d19c0_i128),
-                Fq::from(0x746de9b754f00380_i128),
-                Fq::from(0x5f66cfb3fb8f9629_i128),
-            ],
-            vec![
-                Fq::from(0x79def1f52c64974b_i128),
-                Fq::from(0x96616a3f197a43df_i128),
-                Fq::from(0x9e1d47accba483de_i128),
-                Fq::from(0xff0d452165c0cf28_i128),
-                Fq::from(0xaea40f91c6801990_i128),
-                Fq::from(0xfa9a08297458b1e5_i128),
-                Fq::from(0x998e38405197b425_i128),
-                Fq::from(0xfbe49b3ed512735c_i128),
-                Fq::from(0xad79764fa64b4194_i128),
-                Fq::from(0x425d9768b92535a1_i128),
-                Fq::from(0x91f93ed07a483f14_i128),
-                Fq::from(0x1e16c761f2c8d3f6_i128),
-                Fq::from(0xe4438296f09bba42_i128),
-                Fq::from(0x50ee9b59e7d7ed04_i128),
-                Fq::from(0x29288a6c1f2efb7b_i128),
-                Fq::from(0xefafd17084eb4e84_i128),
-                Fq::from(0x64ffd1773cfdae31_i128),
-                Fq::from(0x9085a743da96aeb4_i128),
-                Fq::from(0xa75682c645f7510b_i128),
-                Fq::from(0x60d8d6c56847b087_i128),
-                Fq::from(0xfab3cf69d1f1d90d_i128),
-                Fq::from(0xfb3f8ba8eea85501_i128),
-                Fq::from(0xcf4364d29de3ec50_i128),
-                Fq::from(0xdf01277ef261061d_i128),
-            ],
-            vec![
-                Fq::from(0x1eb48109d6091d64_i128),
-                Fq::from(0x1ef756c07a521f5c_i128),
-                Fq::from(0x3971f08f8b9f205d_i128),
-                Fq::from(0xdc758708ceefae54_i128),
-                Fq::from(0xda331a04cfb2bcb0_i128),
-                Fq::from(0xdcf33c8627c3e537_i128),
-                Fq::from(0x7c89aff82cf39d40_i128),
-                Fq::from(0xed049c0148a6ea60_i128),
-                Fq::from(0x466cfe619e4f56e2_i128),
-                Fq::from(0x17b36fe00215b615_i128),
-                Fq::from(0x0e83a0fe53498209_i128),
-                Fq::from(0xdad84911f7234194_i128),
-                Fq::from(0xfe34bc57fa30f955_i128),
-                Fq::from(0x0a3ec20c3cc0bbe6_i128),
-                Fq::from(0x24a0a742c8c80197_i128),
-                Fq::from(0xddfc6877763f4886_i128),
-                Fq::from(0x18ad64d27c9c6145_i128),
-                Fq::from(0xddd20e9bceea3a3c_i128),
-                Fq::from(0xee42b7a223853a3e_i128),
-                Fq::from(0xefa4b520dc22f615_i128),
-                Fq::from(0x758a560c4a193111_i128),
-                Fq::from(0x5184c3ecf20323e9_i128),
-                Fq::from(0x105b1057045cf152_i128),
-                Fq::from(0x859f11ed218b0349_i128),
-            ],
-            vec![
-                Fq::from(0x8d91da397d3fbfe0_i128),
-                Fq::from(0xbbdcf72e0f0f6d1b_i128),
-                Fq::from(0x0485bbcc74cc28fd_i128),
-                Fq::from(0xf9ece4895ff18aeb_i128),
-                Fq::from(0xb3741b77f4dd20e8_i128),
-                Fq::from(0x2f6f7c8da2917b74_i128),
-                Fq::from(0x4c4467287d348bb7_i128),
-                Fq::from(0xcd8c0d0ad7eeeeae_i128),
-                Fq::from(0xbee11c9f8f3c08cf_i128),
-                Fq::from(0x31c0ced527f4fdab_i128),
-                Fq::from(0x4c58c0360dd3ae9a_i128),
-                Fq::from(0x57d3d2dd1542b321_i128),
-                Fq::from(0x585c5bed525c6013_i128),
-                Fq::from(0x9838d8e0a2a50f6f_i128),
-                Fq::from(0x5fbcf5c6630d3465_i128),
-                Fq::from(0x9e457ff0afd055ea_i128),
-                Fq::from(0xa53b2d3acdf27a35_i128),
-                Fq::from(0x05094e16b98e324d_i128),
-                Fq::from(0x27930629177ada58_i128),
-                Fq::from(0x082acfde855af052_i128),
-                Fq::from(0xfb96d0dc5d4ac20c_i128),
-                Fq::from(0xa414f249b0a2ba43_i128),
-                Fq::from(0xaf4b0a90638309bc_i128),
-                Fq::from(0x01f59d3ced27d58c_i128),
-            ],
-            vec![
-                Fq::from(0xc12f620eb3a6f462_i128),
-                Fq::from(0x36da68b5e012f93d_i128),
-                Fq::from(0xdb3dd64b800bc603_i128),
-                Fq::from(0xbc783c1541368473_i128),
-                Fq::from(0x6e3fb5a3cab07c5d_i128),
-                Fq::from(0x4b1cf67bdbd38ff4_i128),
-                Fq::from(0x9effb0f79db8f7a6_i128),
-                Fq::from(0xbcd110556622de90_i128),
-                Fq::from(0xf28de535a52954bb_i128),
-                Fq::from(0x49d66d89b94ea5f3_i128),
-                Fq::from(0x7825b6dff4860add_i128),
-                Fq::from(0xd245b59855b5af38_i128),
-                Fq::from(0xa2079c2a324a6fab_i128),
-                Fq::from(0xcfa747315e4a4a38_i128),
-                Fq::from(0x977fb7d2606142a9_i128),
-                Fq::from(0x98f430207246e4a9_i128),
-                Fq::from(0x7070801ecc629b9a_i128),
-                Fq::from(0x290cc7028448a7fd_i128),
-                Fq::from(0xe838d939186ee030_i128),
-                Fq::from(0xfd40d2dfaaebcfce_i128),
-                Fq::from(0x1cfca1ac0b136b59_i128),
-                Fq::from(0x4181083fab7d95db_i128),
-                Fq::from(0x4d76f5982deab6e2_i128),
-                Fq::from(0xb3f6fc4c99374128_i128),
-            ],
-            vec![
-                Fq::from(0x7a4141eaf5c51c7e_i128),
-                Fq::from(0x2826632381ed1f9f_i128),
-                Fq::from(0x241f02dfefb67d7f_i128),
-                Fq::from(0xebfc4e69ad7b180e_i128),
-                Fq::from(0x238c27a5b2f13f7b_i128),
-                Fq::from(0x02bfd512bfc4bf1c_i128),
-                Fq::from(0xedcb3d4875f53d71_i128),
-                Fq::from(0x07b79a5c34615310_i128),
-                Fq::from(0x68e2c9928d97f1ce_i128),
-                Fq::from(0x333ac28123180366_i128),
-                Fq::from(0xaee0d5a14bf8fc4e_i128),
-                Fq::from(0x78a7ceb1e392b69e_i128),
-                Fq::from(0xadb83d3bdf7db893_i128),
-                Fq::from(0x5470836d2164761d_i128),
-                Fq::from(0x35f933bb6d8915fa_i128),
-                Fq::from(0xebf14c43af523c96_i128),
-                Fq::from(0x14cd027c7936cb89_i128),
-                Fq::from(0x0854207ffa999052_i128),
-                Fq::from(0xad2d80f31905fd55_i128),
-                Fq::from(0xd33362877a565a8c_i128),
-                Fq::from(0x7ad984f676fd7371_i128),
-                Fq::from(0x13f332ab1f0b6058_i128),
-                Fq::from(0xb7bf38ca8f22bebf_i128),
-                Fq::from(0xe4001f998cd776aa_i128),
-            ],
-            vec![
-                Fq::from(0x0774dffba585ed97_i128),
-                Fq::from(0x93ea95382c972724_i128),
-                Fq::from(0x76738c72c992a2b0_i128),
-                Fq::from(0x632063cecd2c0bec_i128),
-                Fq::from(0xe94da3c71cf58492_i128),
-                Fq::from(0xe2e7c3c27f7e6651_i128),
-                Fq::from(0xad8c08808179f220_i128),
-                Fq::from(0x219e16904ee24240_i128),
-                Fq::from(0x558ff10562373a39_i128),
-                Fq::from(0x820011dca44282d4_i128),
-                Fq::from(0xc1a72d7135797fa0_i128),
-                Fq::from(0x392a205036550f9e_i128),
-                Fq::from(0xe7543b287d77b04e_i128),
-                Fq::from(0x767758fce1228507_i128),
-                Fq::from(0x2ca666fa31d86282_i128),
-                Fq::from(0x842332303153cc1d_i128),
-                Fq::from(0xe2b440a172184b97_i128),
-                Fq::from(0xc1a6a8377007ddaf_i128),
-                Fq::from(0xc20730caae5d292b_i128),
-                Fq::from(0xb015fbe7f6706436_i128),
-                Fq::from(0x4183b9884ff44869_i128),
-                Fq::from(0xd7624bb1d77053ba_i128),
-                Fq::from(0x0c2f13ff3a7ee34e_i128),
-                Fq::from(0x75d3758a031ef550_i128),
-            ],
-            vec![
-                Fq::from(0x3e8a6a77daad4405_i128),
-                Fq::from(0xe9393d3c1ba7314d_i128),
-                Fq::from(0x6ee99019ce2d77b6_i128),
-                Fq::from(0xe40b3e9372407798_i128),
-                Fq::from(0x86332369863c43bd_i128),
-                Fq::from(0x7f2452d9761c1e97_i128),
-                Fq::from(0x961795c0b4fd8870_i128),
-                Fq::from(0x6aa5d6f0399dc0d6_i128),
-                Fq::from(0x90f3a8cd1062a4ae_i128),
-                Fq::from(0xcb30fb6da6bfdd13_i128),
-                Fq::from(0xe61b009b014a6600_i128),
-                Fq::from(0x4e5a16e60089fe00_i128),
-                Fq::from(0xc6ea8046681fbf8a_i128),
-                Fq::from(0x0ac7a3f2bc49e1e1_i128),
-                Fq::from(0xb26f3b435fb8e214_i128),
-                Fq::from(0x12a85c5924d2e68e_i128),
-                Fq::from(0x4882cdf2d7ea8d39_i128),
-                Fq::from(0xec36f85a962c1cfb_i128),
-                Fq::from(0x9acae0126b50f270_i128),
-                Fq::from(0x929eee51f35edb15_i128),
-                Fq::from(0x4dc3c130352d7dec_i128),
-                Fq::from(0xd313ef94994eb130_i128),
-                Fq::from(0x51cbf1e1afa65785_i128),
-                Fq::from(0x3e286758dea37884_i128),
-            ],
-            vec![
-                Fq::from(0xdb7a99f28622ec94_i128),
-                Fq::from(0x32f2b1dd76cab76d_i128),
-                Fq::from(0x16c3a308605efbc9_i128),
-                Fq::from(0x136d21f9564495af_i128),
-                Fq::from(0x9e4df892cf57ada6_i128),
-                Fq::from(0x7fb4f4a2bc91d33e_i128),
-                Fq::from(0x369d1a58ed795027_i128),
-                Fq::from(0x50367191920df3de_i128),
-                Fq::from(0x050243f7aba277d1_i128),
-                Fq::from(0x1443d6a088651896_i128),
-                Fq::from(0x86d37e91913682bf_i128),
-                Fq::from(0x819a34c068289faf_i128),
-                Fq::from(0x46161c875b36df71_i128),
-                Fq::from(0xf0350f7b9e550240_i128),
-                Fq::from(0xc7857a164d346685_i128),
-                Fq::from(0xf84ca04cec984354_i128),
-                Fq::from(0xde0618a5c3fb4642_i128),
-                Fq::from(0x96ab14e10e1f76a0_i128),
-                Fq::from(0xa6e5616958b5e64a_i128),
-                Fq::from(0xe923e50e5cd1b6c6_i128),
-                Fq::from(0x40833af01785e494_i128),
-                Fq::from(0x3bec17e1504b5c48_i128),
-                Fq::from(0x956cab0212475387_i128),
-                Fq::from(0xb9aa04d5832fe129_i128),
-            ],
-            vec![
-                Fq::from(0xd42d672cf1fc07da_i128),
-                Fq::from(0x0061aa458f99db9b_i128),
-                Fq::from(0x6e5bcf2ba9de46ef_i128),
-                Fq::from(0x3173d383888c00c9_i128),
-                Fq::from(0x2401844fda7df11a_i128),
-                Fq::from(0x0957679843a552a7_i128),
-                Fq::from(0x9fde574e8540fc9a_i128),
-                Fq::from(0x831f144f95348a2d_i128),
-                Fq::from(0xa3c1ca3f0395cb6a_i128),
-                Fq::from(0xb1d01a12bf96ea22_i128),
-                Fq::from(0x6b71bc98f1a8b5b8_i128),
-                Fq::from(0x0e0984668385e189_i128),
-                Fq::from(0x5f2605f4bb6b5e2e_i128),
-                Fq::from(0x6e9690afdcb2fdf5_i128),
-                Fq::from(0x9f2b546fadd9e364_i128),
-                Fq::from(0xbfcf301c3124e3ce_i128),
-                Fq::from(0xd6d7663bc73004ef_i128),
-                Fq::from(0x46545a07dd39508d_i128),
-                Fq::from(0xc25e13694c3d81f0_i128),
-                Fq::from(0x5313b19405f75440_i128),
-                Fq::from(0xd80bdc78e88d1ca6_i128),
-                Fq::from(0xbf6faf1a66bd1155_i128),
-                Fq::from(0x28bda7bd9134fb48_i128),
-                Fq::from(0x66f2fdda42f45b55_i128),
-            ],
-        ];
-
-        PoseidonConfig::<Fq>::new(full_rounds, partial_rounds, alpha, mds, ark, 20, 4)
-    }
-}
@@ -0,0 +1,699 @@
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::PrimeField;
+use ark_std::{format, string::String};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+use crate::ark_base::*;
+
+/// The length of the state of the Grain LFSR.
+const GRAIN_STATE_LEN: usize = 80;
+
+/// The number of initial Grain LFSR outputs that are discarded.
+const GRAIN_WARM_UP: usize = 160;
+
+/// Errors that can occur while generating Poseidon parameters.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PoseidonParamsError {
+    /// The S-box $x^\alpha$ has to be a permutation of the field, i.e. $\alpha \geq 3$ and $\gcd(\alpha, p - 1) = 1$.
+    #[error("x^{0} is not a permutation of the field")]
+    InvalidAlpha(u64),
+    /// The state has to consist of at least two elements.
+    #[error("the state width {0} is too small")]
+    WidthTooSmall(usize),
+    /// A parameter does not fit into its field of the Grain LFSR initial state.
+    #[error("{name} = {value} does not fit into the Grain LFSR initial state")]
+    ParameterOutOfRange { name: &'static str, value: u64 },
+    /// No round numbers in the search range reach the security level.
+    #[error("no round numbers reach {0} bits of security")]
+    NoRoundNumbers(u32),
+    /// The MDS matrix is not a square matrix of the state width or it is singular.
+    #[error("the MDS matrix is not an invertible {0}x{0} matrix")]
+    InvalidMds(usize),
+    /// Poseidon2 is only defined for the state widths 2, 3 and multiples of 4.
+    #[error("Poseidon2 does not support the state width {0}")]
+    UnsupportedWidth(usize),
+}
+
+/// The shape of a Poseidon instance with the S-box $x^\alpha$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoseidonSpec {
+    /// The rate of the sponge.
+    pub rate: usize,
+    /// The capacity of the sponge.
+    pub capacity: usize,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// The number of full rounds $R_F$.
+    pub full_rounds: usize,
+    /// The number of partial rounds $R_P$.
+    pub partial_rounds: usize,
+}
+
+impl PoseidonSpec {
+    /// Returns the width $t$ of the state.
+    pub fn width(&self) -> usize {
+        self.rate + self.capacity
+    }
+
+    /// Returns the spec with the cheapest round numbers achieving `security_bits` bits of security over `F`,
+    /// see [`round_numbers`].
+    #[cfg(feature = "std")]
+    pub fn with_security<F: PrimeField>(
+        rate: usize,
+        capacity: usize,
+        alpha: u64,
+        security_bits: u32,
+    ) -> Result<Self, PoseidonParamsError> {
+        let modulus: BigUint = F::MODULUS.into();
+        check_alpha(&modulus, alpha)?;
+
+        let (full_rounds, partial_rounds) =
+            round_numbers(&modulus, rate + capacity, alpha, security_bits)
+                .ok_or(PoseidonParamsError::NoRoundNumbers(security_bits))?;
+
+        Ok(Self {
+            rate,
+            capacity,
+            alpha,
+            full_rounds,
+            partial_rounds,
+        })
+    }
+}
+
+/// The round constants and the MDS matrix of a Poseidon instance as integers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonConstants {
+    /// The round constants, one row of $t$ constants per round.
+    pub ark: Vec<Vec<BigUint>>,
+    /// The $t \times t$ MDS matrix.
+    pub mds: Vec<Vec<BigUint>>,
+}
+
+impl PoseidonConstants {
+    /// Generates the constants of `spec` for the prime `modulus` as the reference implementation of Poseidon does.
+    ///
+    /// The Grain LFSR is seeded with the field type, the S-box type, the bit length $n$ of the modulus,
+    /// $t$, $R_F$ and $R_P$. The round constants are sampled as $n$-bit integers, rejecting those not smaller than the modulus.
+    /// The MDS matrix is the Cauchy matrix $\left((x_i + y_j)^{-1}\right)\_{i,j}$ for $2t$ further $n$-bit integers
+    /// $x_1, \ldots, x_t, y_1, \ldots, y_t$ reduced modulo the modulus, which are resampled until they are pairwise distinct
+    /// and $x_i + y_j \neq 0$. Such a matrix is MDS.
+    pub fn generate(spec: &PoseidonSpec, modulus: &BigUint) -> Result<Self, PoseidonParamsError> {
+        let width = spec.width();
+        if width < 2 {
+            return Err(PoseidonParamsError::WidthTooSmall(width));
+        }
+        check_alpha(modulus, spec.alpha)?;
+
+        let bits = modulus.bits();
+        let mut grain = GrainLfsr::new(
+            bits,
+            width as u64,
+            spec.full_rounds as u64,
+            spec.partial_rounds as u64,
+        )?;
+
+        let ark = (0..spec.full_rounds + spec.partial_rounds)
+            .map(|_| {
+                (0..width)
+                    .map(|_| loop {
+                        let c = grain.next_uint(bits);
+                        if &c < modulus {
+                            break c;
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let (xs, ys) = loop {
+            let mut points: Vec<BigUint> = (0..2 * width)
+                .map(|_| grain.next_uint(bits) % modulus)
+                .collect();
+            let ys = points.split_off(width);
+
+            if is_cauchy(&points, &ys, modulus) {
+                break (points, ys);
+            }
+        };
+
+        let exponent = modulus - 2u32;
+        let mds = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| ((x + y) % modulus).modpow(&exponent, modulus))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self { ark, mds })
+    }
+
+    /// Returns the configuration of `spec` with the constants reduced into `F`.
+    ///
+    /// If the constants were generated for another modulus, the MDS property is not guaranteed in `F`.
+    pub fn to_config<F: PrimeField>(&self, spec: &PoseidonSpec) -> PoseidonConfig<F> {
+        PoseidonConfig::new(
+            spec.full_rounds,
+            spec.partial_rounds,
+            spec.alpha,
+            to_field_matrix(&self.mds),
+            to_field_matrix(&self.ark),
+            spec.rate,
+            spec.capacity,
+        )
+    }
+
+    /// Returns a Rust expression constructing the configuration of `spec`,
+    /// e.g. for a build script generating a [`GetPoseidonParams`](crate::rings::GetPoseidonParams) implementation.
+    ///
+    /// The expression refers to the field as `Fq`.
+    pub fn to_rust(&self, spec: &PoseidonSpec) -> String {
+        let matrix = |m: &[Vec<BigUint>]| {
+            let rows: Vec<String> = m
+                .iter()
+                .map(|row| {
+                    let row: Vec<String> = row
+                        .iter()
+                        .map(|c| format!("ark_ff::MontFp!(\"{c}\")"))
+                        .collect();
+                    format!("ark_std::vec![{}]", row.join(", "))
+                })
+                .collect();
+            format!("ark_std::vec![{}]", rows.join(", "))
+        };
+
+        format!(
+            "ark_crypto_primitives::sponge::poseidon::PoseidonConfig::<Fq>::new({}, {}, {}, {}, {}, {}, {})",
+            spec.full_rounds,
+            spec.partial_rounds,
+            spec.alpha,
+            matrix(&self.mds),
+            matrix(&self.ark),
+            spec.rate,
+            spec.capacity,
+        )
+    }
+}
+
+/// Generates the Poseidon configuration of `spec` over `F`, see [`PoseidonConstants::generate`].
+pub fn poseidon_config<F: PrimeField>(
+    spec: &PoseidonSpec,
+) -> Result<PoseidonConfig<F>, PoseidonParamsError> {
+    let config = PoseidonConstants::generate(spec, &F::MODULUS.into())?.to_config(spec);
+    check_mds(&config.mds, spec.width())?;
+
+    Ok(config)
+}
+
+/// Same as [`poseidon_config`], but every configuration is generated only once per field and spec
+/// and cached for the lifetime of the process.
+#[cfg(feature = "std")]
+pub fn cached_poseidon_config<F: PrimeField>(
+    spec: &PoseidonSpec,
+) -> Result<PoseidonConfig<F>, PoseidonParamsError> {
+    use std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        sync::{Mutex, OnceLock, PoisonError},
+    };
+
+    type Cache = Mutex<HashMap<(TypeId, PoseidonSpec), Box<dyn Any + Send + Sync>>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    let key = (TypeId::of::<F>(), *spec);
+
+    if let Some(config) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&key)
+    {
+        return Ok(config
+            .downcast_ref::<PoseidonConfig<F>>()
+            .expect("the cache is keyed by the field type")
+            .clone());
+    }
+
+    let config = poseidon_config::<F>(spec)?;
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(key, Box::new(config.clone()));
+
+    Ok(config)
+}
+
+/// Checks that `mds` is an invertible `width`x`width` matrix over `F`.
+pub fn check_mds<F: PrimeField>(mds: &[Vec<F>], width: usize) -> Result<(), PoseidonParamsError> {
+    if mds.len() != width || mds.iter().any(|row| row.len() != width) {
+        return Err(PoseidonParamsError::InvalidMds(width));
+    }
+
+    let mut m = mds.to_vec();
+    for col in 0..width {
+        let pivot = (col..width)
+            .find(|&row| !m[row][col].is_zero())
+            .ok_or(PoseidonParamsError::InvalidMds(width))?;
+        m.swap(col, pivot);
+
+        let inv = m[col][col].inverse().expect("the pivot is non-zero");
+        for row in col + 1..width {
+            let factor = m[row][col] * inv;
+            for j in col..width {
+                let sub = factor * m[col][j];
+                m[row][j] -= sub;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the cheapest round numbers $(R_F, R_P)$ of a Poseidon instance of width `width`
+/// with the S-box $x^\alpha$ over the prime field of modulus `modulus`
+/// achieving `security_bits` bits of security.
+///
+/// Follows `calc_round_numbers.py` of the reference implementation: the smallest round numbers
+/// resisting the statistical, interpolation and Gröbner basis attacks of the Poseidon paper
+/// and the attack of <https://eprint.iacr.org/2023/537> are searched for $R_P < 500$ and even $R_F < 100$,
+/// then the security margin of two full rounds and $7.5\\%$ partial rounds is added.
+/// The result minimizes the number of S-boxes $t R_F + R_P$, preferring fewer full rounds.
+#[cfg(feature = "std")]
+pub fn round_numbers(
+    modulus: &BigUint,
+    width: usize,
+    alpha: u64,
+    security_bits: u32,
+) -> Option<(usize, usize)> {
+    let log2_p = log2(modulus);
+    let bits = modulus.bits() as f64;
+    let mut best: Option<(usize, usize, usize)> = None;
+
+    for partial_rounds in 1..500 {
+        // For fixed R_P the cost grows with R_F, so only the smallest secure R_F matters.
+        let Some(full_rounds) = (4..100).step_by(2).find(|&full_rounds| {
+            is_secure(
+                log2_p,
+                bits,
+                width,
+                full_rounds,
+                partial_rounds,
+                alpha,
+                security_bits as f64,
+            )
+        }) else {
+            continue;
+        };
+
+        let full_rounds = full_rounds + 2;
+        let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+        let cost = width * full_rounds + partial_rounds;
+
+        if best.map_or(true, |(best_cost, best_full_rounds, _)| {
+            cost < best_cost || (cost == best_cost && full_rounds < best_full_rounds)
+        }) {
+            best = Some((cost, full_rounds, partial_rounds));
+        }
+    }
+
+    best.map(|(_, full_rounds, partial_rounds)| (full_rounds, partial_rounds))
+}
+
+#[cfg(feature = "std")]
+fn is_secure(
+    log2_p: f64,
+    bits: f64,
+    width: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    security_bits: f64,
+) -> bool {
+    let (t, r_f, r_p, a, m) = (
+        width as f64,
+        full_rounds as f64,
+        partial_rounds as f64,
+        alpha as f64,
+        security_bits,
+    );
+    let log_alpha = |x: f64| x.ln() / a.ln();
+
+    let statistical = if m <= (log2_p - (a - 1.0) / 2.0).floor() * (t + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+    let interpolation = 1.0 + (log_alpha(2.0) * m.min(bits)).ceil() + log_alpha(t).ceil() - r_p;
+    let groebner_1 = log_alpha(2.0) * m.min(log2_p) - r_p;
+    let groebner_2 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(log2_p / 2.0) - r_p;
+    let groebner_3 = (t - 2.0 + m / (2.0 * (a.ln() / 2f64.ln())) - r_p) / (t - 1.0);
+
+    let min_full_rounds = [
+        statistical,
+        interpolation,
+        groebner_1,
+        groebner_2,
+        groebner_3,
+    ]
+    .into_iter()
+    .map(f64::ceil)
+    .fold(f64::MIN, f64::max);
+    if r_f < min_full_rounds {
+        return false;
+    }
+
+    let r = (width / 3) as f64;
+    let over = (r_f - 1.0) * t + 2.0 * r_p + r + r * r_f / 2.0 + a;
+    let under = r * r_f / 2.0 + r_p + a;
+
+    (2.0 * log2_binomial(over as u64, under as u64)).ceil() >= m
+}
+
+#[cfg(feature = "std")]
+fn log2_binomial(n: u64, k: u64) -> f64 {
+    (1..=k)
+        .map(|i| ((n - k + i) as f64).log2() - (i as f64).log2())
+        .sum()
+}
+
+#[cfg(feature = "std")]
+fn log2(x: &BigUint) -> f64 {
+    let shift = x.bits().saturating_sub(64);
+    let top = (x >> shift).iter_u64_digits().next().unwrap_or_default();
+
+    (top as f64).log2() + shift as f64
+}
+
+/// Checks that $x^\alpha$ is a permutation of the prime field of modulus `modulus`.
+pub(crate) fn check_alpha(modulus: &BigUint, alpha: u64) -> Result<(), PoseidonParamsError> {
+    let r = (modulus - 1u32) % alpha;
+    let r = r.iter_u64_digits().next().unwrap_or_default();
+
+    if alpha < 3 || gcd(alpha, r) != 1 {
+        return Err(PoseidonParamsError::InvalidAlpha(alpha));
+    }
+
+    Ok(())
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Checks that the points are pairwise distinct and $x_i + y_j \neq 0$.
+fn is_cauchy(xs: &[BigUint], ys: &[BigUint], modulus: &BigUint) -> bool {
+    let points: Vec<&BigUint> = xs.iter().chain(ys).collect();
+    let distinct = points
+        .iter()
+        .enumerate()
+        .all(|(i, p)| points[i + 1..].iter().all(|q| p != q));
+
+    distinct
+        && xs
+            .iter()
+            .all(|x| ys.iter().all(|y| ((x + y) % modulus).bits() != 0))
+}
+
+fn to_field_matrix<F: PrimeField>(m: &[Vec<BigUint>]) -> Vec<Vec<F>> {
+    m.iter()
+        .map(|row| {
+            row.iter()
+                .map(|c| F::from_le_bytes_mod_order(&c.to_bytes_le()))
+                .collect()
+        })
+        .collect()
+}
+
+/// The Grain LFSR of the reference implementation of Poseidon.
+pub(crate) struct GrainLfsr {
+    state: [bool; GRAIN_STATE_LEN],
+    head: usize,
+}
+
+impl GrainLfsr {
+    pub(crate) fn new(
+        bits: u64,
+        width: u64,
+        full_rounds: u64,
+        partial_rounds: u64,
+    ) -> Result<Self, PoseidonParamsError> {
+        // Prime field, S-box x^alpha, then the parameters; the remaining bits are set.
+        let fields = [
+            ("field", 1, 2),
+            ("sbox", 0, 4),
+            ("n", bits, 12),
+            ("t", width, 12),
+            ("R_F", full_rounds, 10),
+            ("R_P", partial_rounds, 10),
+        ];
+
+        let mut state = [true; GRAIN_STATE_LEN];
+        let mut i = 0;
+        for (name, value, len) in fields {
+            if value >> len != 0 {
+                return Err(PoseidonParamsError::ParameterOutOfRange { name, value });
+            }
+            for j in (0..len).rev() {
+                state[i] = (value >> j) & 1 == 1;
+                i += 1;
+            }
+        }
+
+        let mut grain = Self { state, head: 0 };
+        for _ in 0..GRAIN_WARM_UP {
+            grain.next_bit();
+        }
+
+        Ok(grain)
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let s = |i: usize| self.state[(self.head + i) % GRAIN_STATE_LEN];
+        let bit = s(62) ^ s(51) ^ s(38) ^ s(23) ^ s(13) ^ s(0);
+
+        self.state[self.head] = bit;
+        self.head = (self.head + 1) % GRAIN_STATE_LEN;
+
+        bit
+    }
+
+    /// Outputs the second bit of every pair of bits whose first bit is set.
+    fn next_filtered_bit(&mut self) -> bool {
+        loop {
+            let keep = self.next_bit();
+            let bit = self.next_bit();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Returns an integer of `bits` bits, the most significant bit first.
+    pub(crate) fn next_uint(&mut self, bits: u64) -> BigUint {
+        let digits: Vec<u8> = (0..bits).map(|_| self.next_filtered_bit() as u8).collect();
+
+        BigUint::from_radix_be(&digits, 2).expect("binary digits")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::Field;
+    use sha2::{Digest, Sha256};
+    use stark_rings::cyclotomic_ring::models::{babybear, goldilocks, stark_prime};
+
+    use super::*;
+    use crate::rings::{
+        poseidon::SPEC_64, BabyBearPoseidonConfig, FrogPoseidonConfig, GetPoseidonParams,
+        GoldilocksPoseidonConfig, StarkPoseidonConfig,
+    };
+
+    fn assert_config_eq<F: PrimeField>(a: &PoseidonConfig<F>, b: &PoseidonConfig<F>) {
+        assert_eq!(a.full_rounds, b.full_rounds);
+        assert_eq!(a.partial_rounds, b.partial_rounds);
+        assert_eq!(a.alpha, b.alpha);
+        assert_eq!(a.rate, b.rate);
+        assert_eq!(a.capacity, b.capacity);
+        assert_eq!(a.ark, b.ark);
+        assert_eq!(a.mds, b.mds);
+    }
+
+    /// Known answers of the Goldilocks instance of the reference implementation,
+    /// which BabyBear and Frog reduce modulo their primes.
+    #[test]
+    fn test_known_constants() {
+        fn check<F: PrimeField>(config: &PoseidonConfig<F>) {
+            assert_eq!(
+                (config.full_rounds, config.partial_rounds, config.alpha),
+                (8, 22, 7)
+            );
+            assert_eq!((config.rate, config.capacity), (20, 4));
+            assert_eq!(config.ark.len(), 30);
+
+            assert_eq!(config.ark[0][0], F::from(0xb872395376c3f222_u64));
+            assert_eq!(config.ark[0][1], F::from(0x7d7c6c8d6dda795a_u64));
+            assert_eq!(config.ark[29][23], F::from(0xc272205fa72dd055_u64));
+            assert_eq!(config.mds[0][0], F::from(0xabd0a077d40eb130_u64));
+            assert_eq!(config.mds[23][23], F::from(0x66f2fdda42f45b55_u64));
+        }
+
+        check(&GoldilocksPoseidonConfig::get_poseidon_config());
+        check(&BabyBearPoseidonConfig::get_poseidon_config());
+        check(&FrogPoseidonConfig::get_poseidon_config());
+    }
+
+    #[test]
+    fn test_goldilocks_config() {
+        for _ in 0..2 {
+            assert_config_eq(
+                &GoldilocksPoseidonConfig::get_poseidon_config(),
+                &poseidon_config::<goldilocks::Fq>(&SPEC_64).unwrap(),
+            );
+        }
+    }
+
+    /// The Starknet constants follow `poseidon_utils.py` of cairo-lang: the `i`-th round constant
+    /// is SHA-256 of `Hades{i}` reduced modulo $p$, and the MDS matrix is fixed.
+    #[test]
+    fn test_reproduces_stark_table() {
+        let spec = PoseidonSpec::with_security::<stark_prime::Fq>(2, 1, 3, 128).unwrap();
+        let modulus: BigUint = stark_prime::Fq::MODULUS.into();
+        let width = spec.width();
+
+        let ark = (0..spec.full_rounds + spec.partial_rounds)
+            .map(|round| {
+                (0..width)
+                    .map(|j| {
+                        let digest = Sha256::digest(format!("Hades{}", round * width + j));
+                        BigUint::from_bytes_be(&digest) % &modulus
+                    })
+                    .collect()
+            })
+            .collect();
+        let one = BigUint::from(1u32);
+        let mds = vec![
+            vec![BigUint::from(3u32), one.clone(), one.clone()],
+            vec![one.clone(), &modulus - 1u32, one.clone()],
+            vec![one.clone(), one, &modulus - 2u32],
+        ];
+
+        assert_config_eq(
+            &PoseidonConstants { ark, mds }.to_config::<stark_prime::Fq>(&spec),
+            &StarkPoseidonConfig::get_poseidon_config(),
+        );
+    }
+
+    #[test]
+    fn test_round_numbers() {
+        assert_eq!(
+            PoseidonSpec::with_security::<goldilocks::Fq>(20, 4, 7, 128),
+            Ok(SPEC_64)
+        );
+
+        let stark = StarkPoseidonConfig::get_poseidon_config();
+        assert_eq!(
+            PoseidonSpec::with_security::<stark_prime::Fq>(2, 1, 3, 128),
+            Ok(PoseidonSpec {
+                rate: 2,
+                capacity: 1,
+                alpha: 3,
+                full_rounds: stark.full_rounds,
+                partial_rounds: stark.partial_rounds,
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_mds() {
+        let stark = StarkPoseidonConfig::get_poseidon_config();
+        assert_eq!(check_mds(&stark.mds, 3), Ok(()));
+        assert_eq!(
+            check_mds(&stark.mds, 4),
+            Err(PoseidonParamsError::InvalidMds(4))
+        );
+
+        let one = stark_prime::Fq::ONE;
+        let singular = vec![vec![one, one], vec![one, one]];
+        assert_eq!(
+            check_mds(&singular, 2),
+            Err(PoseidonParamsError::InvalidMds(2))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        // 3 divides p - 1 for Goldilocks.
+        let spec = PoseidonSpec {
+            alpha: 3,
+            ..SPEC_64
+        };
+        assert!(matches!(
+            poseidon_config::<goldilocks::Fq>(&spec),
+            Err(PoseidonParamsError::InvalidAlpha(3))
+        ));
+
+        let spec = PoseidonSpec {
+            partial_rounds: 1024,
+            ..SPEC_64
+        };
+        assert!(matches!(
+            poseidon_config::<goldilocks::Fq>(&spec),
+            Err(PoseidonParamsError::ParameterOutOfRange { name: "R_P", .. })
+        ));
+
+        let spec = PoseidonSpec {
+            rate: 1,
+            capacity: 0,
+            ..SPEC_64
+        };
+        assert!(matches!(
+            poseidon_config::<goldilocks::Fq>(&spec),
+            Err(PoseidonParamsError::WidthTooSmall(1))
+        ));
+    }
+
+    #[test]
+    fn test_cached_config() {
+        let spec = PoseidonSpec::with_security::<babybear::Fq>(2, 1, 7, 128).unwrap();
+        let config = poseidon_config::<babybear::Fq>(&spec).unwrap();
+
+        for _ in 0..2 {
+            assert_config_eq(
+                &cached_poseidon_config::<babybear::Fq>(&spec).unwrap(),
+                &config,
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_rust() {
+        let spec = PoseidonSpec {
+            rate: 1,
+            capacity: 1,
+            alpha: 7,
+            full_rounds: 1,
+            partial_rounds: 0,
+        };
+        let constants = PoseidonConstants {
+            ark: vec![vec![BigUint::from(1u32), BigUint::from(2u32)]],
+            mds: vec![
+                vec![BigUint::from(3u32), BigUint::from(4u32)],
+                vec![BigUint::from(5u32), BigUint::from(6u32)],
+            ],
+        };
+
+        assert_eq!(
+            constants.to_rust(&spec),
+            "ark_crypto_primitives::sponge::poseidon::PoseidonConfig::<Fq>::new(1, 0, 7, \
+             ark_std::vec![ark_std::vec![ark_ff::MontFp!(\"3\"), ark_ff::MontFp!(\"4\")], \
+             ark_std::vec![ark_ff::MontFp!(\"5\"), ark_ff::MontFp!(\"6\")]], \
+             ark_std::vec![ark_std::vec![ark_ff::MontFp!(\"1\"), ark_ff::MontFp!(\"2\")]], 1, 1)"
+        );
+    }
+}
@@ -0,0 +1,576 @@
+//!
+//! Poseidon2 parameters of the rings, their generation and the Poseidon2 permutation.
+//!
+//! Poseidon2 (<https://eprint.iacr.org/2023/323>) replaces the dense MDS matrix of Poseidon by a cheap external matrix $M_E$
+//! in the full rounds and by $M_I = J + \mathrm{diag}(d)$, where $J$ is the all-ones matrix, in the partial rounds.
+//! A partial round only adds a round constant to the first element of the state.
+//!
+//! The parameters of the rings are the output of [`Poseidon2Config::generate`]:
+//!   * Goldilocks and Frog: $t = 12$ (rate 8, capacity 4), $\alpha = 7$, $R_F = 8$ and $R_P = 22$,
+//!   * BabyBear: $t = 16$ (rate 8, capacity 8), $\alpha = 7$, $R_F = 8$ and $R_P = 13$,
+//!   * Starknet prime: $t = 3$ (rate 2, capacity 1), $\alpha = 3$, $R_F = 8$ and $R_P = 83$,
+//!
+//! with the round numbers of [`PoseidonSpec::with_security`] at 128 bits.
+//!
+
+use ark_ff::{BitIteratorBE, PrimeField};
+use ark_std::array;
+use num_bigint::BigUint;
+
+use super::poseidon::{check_alpha, GrainLfsr, PoseidonParamsError, PoseidonSpec};
+use crate::ark_base::*;
+
+mod babybear;
+mod frog;
+mod goldilocks;
+mod stark;
+
+/// The $4 \times 4$ block of the external matrix for widths divisible by 4.
+const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+/// A Poseidon2 configuration with the S-box $x^\alpha$.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poseidon2Config<F: PrimeField> {
+    /// The number of full rounds $R_F$.
+    pub full_rounds: usize,
+    /// The number of partial rounds $R_P$.
+    pub partial_rounds: usize,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// The round constants of the full rounds, one row of $t$ constants per round.
+    pub external_rc: Vec<Vec<F>>,
+    /// The round constants of the partial rounds.
+    pub internal_rc: Vec<F>,
+    /// The diagonal $d$ of $M_I = J + \mathrm{diag}(d)$.
+    pub internal_diag: Vec<F>,
+    /// The rate of the sponge.
+    pub rate: usize,
+    /// The capacity of the sponge.
+    pub capacity: usize,
+}
+
+impl<F: PrimeField> Poseidon2Config<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        full_rounds: usize,
+        partial_rounds: usize,
+        alpha: u64,
+        external_rc: Vec<Vec<F>>,
+        internal_rc: Vec<F>,
+        internal_diag: Vec<F>,
+        rate: usize,
+        capacity: usize,
+    ) -> Self {
+        let width = rate + capacity;
+        assert!(width == 2 || width == 3 || (width > 0 && width % 4 == 0));
+        assert_eq!(external_rc.len(), full_rounds);
+        assert!(external_rc.iter().all(|rc| rc.len() == width));
+        assert_eq!(internal_rc.len(), partial_rounds);
+        assert_eq!(internal_diag.len(), width);
+
+        Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            external_rc,
+            internal_rc,
+            internal_diag,
+            rate,
+            capacity,
+        }
+    }
+
+    /// Returns the width $t$ of the state.
+    pub fn width(&self) -> usize {
+        self.rate + self.capacity
+    }
+
+    /// Generates the configuration of `spec` over `F` with the Grain LFSR of [`PoseidonConstants::generate`](super::poseidon::PoseidonConstants::generate).
+    ///
+    /// The LFSR is seeded as for Poseidon. The $R_F t + R_P$ round constants are sampled in the order of the rounds,
+    /// i.e. $t$ constants per full round and one per partial round.
+    /// For $t \geq 4$ the diagonal $d$ is then sampled as $t$ further $n$-bit integers reduced modulo $p$,
+    /// which are resampled until the minimal polynomials of $M_I^k$ are irreducible of degree $t$ for $k = 1, \ldots, 2t$,
+    /// as in the reference implementation. Then $M_I$ is invertible and no power of $M_I$ up to $2t$
+    /// has a non-trivial invariant subspace, which rules out infinitely long subspace trails.
+    /// For $t = 2$ and $t = 3$ the fixed matrices of the Poseidon2 paper are used.
+    pub fn generate(spec: &PoseidonSpec) -> Result<Self, PoseidonParamsError> {
+        let width = spec.width();
+        if width < 2 {
+            return Err(PoseidonParamsError::WidthTooSmall(width));
+        }
+        if width > 3 && width % 4 != 0 {
+            return Err(PoseidonParamsError::UnsupportedWidth(width));
+        }
+
+        let modulus: BigUint = F::MODULUS.into();
+        check_alpha(&modulus, spec.alpha)?;
+
+        let bits = modulus.bits();
+        let mut grain = GrainLfsr::new(
+            bits,
+            width as u64,
+            spec.full_rounds as u64,
+            spec.partial_rounds as u64,
+        )?;
+        let mut next_rc = || loop {
+            let c = grain.next_uint(bits);
+            if c < modulus {
+                break F::from_le_bytes_mod_order(&c.to_bytes_le());
+            }
+        };
+
+        let half = spec.full_rounds / 2;
+        let mut external_rc: Vec<Vec<F>> = (0..half)
+            .map(|_| (0..width).map(|_| next_rc()).collect())
+            .collect();
+        let internal_rc = (0..spec.partial_rounds).map(|_| next_rc()).collect();
+        external_rc
+            .extend((half..spec.full_rounds).map(|_| (0..width).map(|_| next_rc()).collect()));
+
+        let internal_diag = match width {
+            2 => vec![F::one(), F::from(2u64)],
+            3 => vec![F::one(), F::one(), F::from(2u64)],
+            _ => loop {
+                let diag: Vec<F> = (0..width)
+                    .map(|_| F::from_le_bytes_mod_order(&grain.next_uint(bits).to_bytes_le()))
+                    .collect();
+                let charpoly = internal_charpoly(&diag);
+                if is_irreducible(&charpoly) && has_irreducible_powers(&charpoly) {
+                    break diag;
+                }
+            },
+        };
+
+        Ok(Self::new(
+            spec.full_rounds,
+            spec.partial_rounds,
+            spec.alpha,
+            external_rc,
+            internal_rc,
+            internal_diag,
+            spec.rate,
+            spec.capacity,
+        ))
+    }
+
+    /// Applies the Poseidon2 permutation to `state`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `state` is not the width of the configuration.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), self.width());
+
+        let half = self.full_rounds / 2;
+
+        external_layer(state);
+        for rc in &self.external_rc[..half] {
+            self.full_round(state, rc);
+        }
+        for rc in &self.internal_rc {
+            state[0] = (state[0] + rc).pow([self.alpha]);
+            self.internal_layer(state);
+        }
+        for rc in &self.external_rc[half..] {
+            self.full_round(state, rc);
+        }
+    }
+
+    fn full_round(&self, state: &mut [F], rc: &[F]) {
+        for (x, c) in state.iter_mut().zip(rc) {
+            *x = (*x + c).pow([self.alpha]);
+        }
+        external_layer(state);
+    }
+
+    /// Multiplies `state` by $M_I = J + \mathrm{diag}(d)$.
+    fn internal_layer(&self, state: &mut [F]) {
+        let sum: F = state.iter().sum();
+        for (x, d) in state.iter_mut().zip(&self.internal_diag) {
+            *x = *x * d + sum;
+        }
+    }
+}
+
+/// Multiplies `state` by $M_E$, i.e. by $\mathrm{circ}(2, 1)$ or $\mathrm{circ}(2, 1, 1)$ for $t \leq 3$
+/// and by the block matrix with $2 M_4$ on the diagonal and $M_4$ elsewhere for $t = 4k$.
+fn external_layer<F: PrimeField>(state: &mut [F]) {
+    if state.len() <= 3 {
+        let sum: F = state.iter().sum();
+        state.iter_mut().for_each(|x| *x += sum);
+        return;
+    }
+
+    for chunk in state.chunks_exact_mut(4) {
+        let product: [F; 4] = array::from_fn(|i| {
+            M4[i]
+                .iter()
+                .zip(chunk.iter())
+                .map(|(&m, x)| F::from(m) * x)
+                .sum()
+        });
+        chunk.copy_from_slice(&product);
+    }
+
+    let mut sums = [F::zero(); 4];
+    for chunk in state.chunks_exact(4) {
+        for (sum, x) in sums.iter_mut().zip(chunk) {
+            *sum += x;
+        }
+    }
+    for chunk in state.chunks_exact_mut(4) {
+        for (x, sum) in chunk.iter_mut().zip(&sums) {
+            *x += sum;
+        }
+    }
+}
+
+/// Returns the characteristic polynomial $\det(X I - J - \mathrm{diag}(d))
+/// = g(X) - \sum_i g(X) / (X - d_i)$ for $g(X) = \prod_i (X - d_i)$.
+///
+/// Polynomials are vectors of coefficients in the ascending order without trailing zeros.
+fn internal_charpoly<F: PrimeField>(diag: &[F]) -> Vec<F> {
+    let g = diag
+        .iter()
+        .fold(vec![F::one()], |g, d| mul(&g, &[-*d, F::one()]));
+
+    let mut charpoly = g.clone();
+    for d in diag {
+        // Synthetic division of g by X - d.
+        let mut quotient = vec![F::zero(); g.len() - 1];
+        let mut carry = F::zero();
+        for i in (1..g.len()).rev() {
+            carry = g[i] + carry * d;
+            quotient[i - 1] = carry;
+        }
+        charpoly = sub(&charpoly, &quotient);
+    }
+
+    charpoly
+}
+
+/// Rabin's test: a monic polynomial $f$ of degree $t$ is irreducible iff $f \mid X^{p^t} - X$
+/// and $\gcd(f, X^{p^{t/q}} - X) = 1$ for every prime $q \mid t$.
+fn is_irreducible<F: PrimeField>(f: &[F]) -> bool {
+    let degree = f.len() - 1;
+    let x = vec![F::zero(), F::one()];
+
+    // frobenius[k] = X^{p^{k + 1}} mod f
+    let mut frobenius: Vec<Vec<F>> = Vec::with_capacity(degree);
+    let mut h = x.clone();
+    for _ in 0..degree {
+        h = pow_mod(&h, F::characteristic(), f);
+        frobenius.push(h.clone());
+    }
+
+    if !sub(&frobenius[degree - 1], &x).is_empty() {
+        return false;
+    }
+
+    prime_factors(degree)
+        .into_iter()
+        .all(|q| gcd(f, &sub(&frobenius[degree / q - 1], &x)).len() == 1)
+}
+
+/// Checks that the minimal polynomials of $M^k$ for $k = 1, \ldots, 2t$ are irreducible of degree $t$,
+/// given the irreducible characteristic polynomial `charpoly` of degree $t$ of $M$.
+///
+/// The minimal polynomial of $M^k$ is the one of $\lambda^k$ for a root $\lambda \in \mathbb{F}_{p^t}$ of `charpoly`.
+/// It has degree $t$ iff $\lambda^k$ lies in no proper subfield, i.e. iff $(\lambda^k)^{p^{t/q}} \neq \lambda^k$
+/// for every prime $q \mid t$, computed in $\mathbb{F}_p[X] / (\mathrm{charpoly})$ with $\lambda = X$.
+fn has_irreducible_powers<F: PrimeField>(charpoly: &[F]) -> bool {
+    let degree = charpoly.len() - 1;
+    let x = vec![F::zero(), F::one()];
+    let factors = prime_factors(degree);
+
+    let mut lambda_k = vec![F::one()];
+    (1..=2 * degree).all(|_| {
+        lambda_k = rem(&mul(&lambda_k, &x), charpoly);
+        factors.iter().all(|&q| {
+            let conjugate = (0..degree / q).fold(lambda_k.clone(), |y, _| {
+                pow_mod(&y, F::characteristic(), charpoly)
+            });
+            conjugate != lambda_k
+        })
+    })
+}
+
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n % q == 0 {
+            factors.push(q);
+            while n % q == 0 {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+fn trim<F: PrimeField>(mut a: Vec<F>) -> Vec<F> {
+    while a.last().is_some_and(|c| c.is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+fn sub<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut c = a.to_vec();
+    c.resize(a.len().max(b.len()), F::zero());
+    for (c, b) in c.iter_mut().zip(b) {
+        *c -= b;
+    }
+    trim(c)
+}
+
+fn mul<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut c = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            c[i + j] += *a * b;
+        }
+    }
+    trim(c)
+}
+
+/// Returns `a` modulo the non-zero polynomial `f`.
+fn rem<F: PrimeField>(a: &[F], f: &[F]) -> Vec<F> {
+    let degree = f.len() - 1;
+    let lead_inv = f[degree].inverse().expect("f is non-zero");
+
+    let mut a = a.to_vec();
+    while a.len() > degree {
+        let top = a.len() - 1;
+        let c = a[top] * lead_inv;
+        for (i, f) in f.iter().enumerate() {
+            a[top - degree + i] -= c * f;
+        }
+        a = trim(a);
+    }
+    a
+}
+
+fn pow_mod<F: PrimeField>(a: &[F], exp: &[u64], f: &[F]) -> Vec<F> {
+    let mut result = vec![F::one()];
+    for bit in BitIteratorBE::without_leading_zeros(exp) {
+        result = rem(&mul(&result, &result), f);
+        if bit {
+            result = rem(&mul(&result, a), f);
+        }
+    }
+    result
+}
+
+fn gcd<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    while !b.is_empty() {
+        let r = rem(&a, &b);
+        (a, b) = (b, r);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::MontFp;
+    use stark_rings::cyclotomic_ring::models::{babybear, frog_ring, goldilocks, stark_prime};
+
+    use super::*;
+    use crate::rings::{
+        BabyBearPoseidonConfig, FrogPoseidonConfig, GetPoseidon2Params, GoldilocksPoseidonConfig,
+        StarkPoseidonConfig,
+    };
+
+    fn spec<F: PrimeField>(rate: usize, capacity: usize, alpha: u64) -> PoseidonSpec {
+        PoseidonSpec::with_security::<F>(rate, capacity, alpha, 128).unwrap()
+    }
+
+    fn permute<F: PrimeField>(config: &Poseidon2Config<F>) -> Vec<F> {
+        let mut state: Vec<F> = (0..config.width() as u64).map(F::from).collect();
+        config.permute(&mut state);
+        state
+    }
+
+    #[test]
+    fn test_reproduces_tables() {
+        assert_eq!(
+            Poseidon2Config::<goldilocks::Fq>::generate(&spec::<goldilocks::Fq>(8, 4, 7)),
+            Ok(GoldilocksPoseidonConfig::get_poseidon2_config())
+        );
+        assert_eq!(
+            Poseidon2Config::<babybear::Fq>::generate(&spec::<babybear::Fq>(8, 8, 7)),
+            Ok(BabyBearPoseidonConfig::get_poseidon2_config())
+        );
+        assert_eq!(
+            Poseidon2Config::<frog_ring::Fq>::generate(&spec::<frog_ring::Fq>(8, 4, 7)),
+            Ok(FrogPoseidonConfig::get_poseidon2_config())
+        );
+        assert_eq!(
+            Poseidon2Config::<stark_prime::Fq>::generate(&spec::<stark_prime::Fq>(2, 1, 3)),
+            Ok(StarkPoseidonConfig::get_poseidon2_config())
+        );
+    }
+
+    #[test]
+    fn test_permutation_goldilocks() {
+        let expected: Vec<goldilocks::Fq> = vec![
+            goldilocks::Fq::from(12929404488009577717u64),
+            goldilocks::Fq::from(8087046626297297827u64),
+            goldilocks::Fq::from(10961442002881082062u64),
+            goldilocks::Fq::from(14266939964567656373u64),
+            goldilocks::Fq::from(6396289904243898545u64),
+            goldilocks::Fq::from(12219289280524731154u64),
+            goldilocks::Fq::from(2804354341750211677u64),
+            goldilocks::Fq::from(12032622465824242860u64),
+            goldilocks::Fq::from(6014879618417247422u64),
+            goldilocks::Fq::from(17792094849680317174u64),
+            goldilocks::Fq::from(14332144881437661379u64),
+            goldilocks::Fq::from(2604991473252298265u64),
+        ];
+
+        assert_eq!(
+            permute(&GoldilocksPoseidonConfig::get_poseidon2_config()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_permutation_babybear() {
+        let expected: Vec<babybear::Fq> = vec![
+            babybear::Fq::from(843311208u64),
+            babybear::Fq::from(1056089221u64),
+            babybear::Fq::from(977190432u64),
+            babybear::Fq::from(1461219194u64),
+            babybear::Fq::from(1878476856u64),
+            babybear::Fq::from(1994406198u64),
+            babybear::Fq::from(1164611770u64),
+            babybear::Fq::from(1050279027u64),
+            babybear::Fq::from(1939759028u64),
+            babybear::Fq::from(1532019825u64),
+            babybear::Fq::from(1523009043u64),
+            babybear::Fq::from(14173610u64),
+            babybear::Fq::from(1580722226u64),
+            babybear::Fq::from(1130643604u64),
+            babybear::Fq::from(1347718641u64),
+            babybear::Fq::from(462524924u64),
+        ];
+
+        assert_eq!(
+            permute(&BabyBearPoseidonConfig::get_poseidon2_config()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_permutation_frog() {
+        let expected: Vec<frog_ring::Fq> = vec![
+            frog_ring::Fq::from(7697544574787616381u64),
+            frog_ring::Fq::from(4381422348894254593u64),
+            frog_ring::Fq::from(12482844268768155586u64),
+            frog_ring::Fq::from(2107205332857512033u64),
+            frog_ring::Fq::from(13487134905023659631u64),
+            frog_ring::Fq::from(5463912914109534698u64),
+            frog_ring::Fq::from(1311523926825618993u64),
+            frog_ring::Fq::from(7580749811463081042u64),
+            frog_ring::Fq::from(5454067457013186435u64),
+            frog_ring::Fq::from(8660869249289617159u64),
+            frog_ring::Fq::from(8943947149262119008u64),
+            frog_ring::Fq::from(3292931255339363198u64),
+        ];
+
+        assert_eq!(
+            permute(&FrogPoseidonConfig::get_poseidon2_config()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_permutation_stark() {
+        let expected: Vec<stark_prime::Fq> = vec![
+            MontFp!("2636513244757622239477671532006709592893292798319452571875667726569061209941"),
+            MontFp!("3053597153234500627260178284222583714873883608991212969292943598348675525275"),
+            MontFp!("3310116417005377841962502657772976450982263931205216567004330596558028302674"),
+        ];
+
+        assert_eq!(
+            permute(&StarkPoseidonConfig::get_poseidon2_config()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_is_irreducible() {
+        type Fq = goldilocks::Fq;
+
+        // 7 generates the multiplicative group of the Goldilocks field, so it is not a square.
+        assert!(is_irreducible(&[-Fq::from(7u64), Fq::zero(), Fq::one()]));
+        assert!(!is_irreducible(&[-Fq::from(4u64), Fq::zero(), Fq::one()]));
+        assert!(!is_irreducible(&internal_charpoly(&[
+            Fq::from(3u64),
+            Fq::from(3u64),
+            Fq::from(5u64),
+            Fq::from(7u64),
+        ])));
+    }
+
+    #[test]
+    fn test_has_irreducible_powers() {
+        type Fq = goldilocks::Fq;
+
+        // X^4 - 7 is irreducible, but its root squared is a root of X^2 - 7, i.e. lies in F_{p^2}.
+        let binomial = [
+            -Fq::from(7u64),
+            Fq::zero(),
+            Fq::zero(),
+            Fq::zero(),
+            Fq::one(),
+        ];
+        assert!(is_irreducible(&binomial));
+        assert!(!has_irreducible_powers(&binomial));
+
+        // The diagonals of the tables satisfy the condition.
+        assert!(has_irreducible_powers(&internal_charpoly(
+            &GoldilocksPoseidonConfig::get_poseidon2_config().internal_diag
+        )));
+        assert!(has_irreducible_powers(&internal_charpoly(
+            &BabyBearPoseidonConfig::get_poseidon2_config().internal_diag
+        )));
+        assert!(has_irreducible_powers(&internal_charpoly(
+            &FrogPoseidonConfig::get_poseidon2_config().internal_diag
+        )));
+    }
+
+    #[test]
+    fn test_errors() {
+        let spec = PoseidonSpec {
+            rate: 4,
+            capacity: 2,
+            alpha: 7,
+            full_rounds: 8,
+            partial_rounds: 22,
+        };
+        assert_eq!(
+            Poseidon2Config::<goldilocks::Fq>::generate(&spec),
+            Err(PoseidonParamsError::UnsupportedWidth(6))
+        );
+        assert_eq!(
+            Poseidon2Config::<goldilocks::Fq>::generate(&PoseidonSpec {
+                capacity: 4,
+                alpha: 5,
+                ..spec
+            }),
+            Err(PoseidonParamsError::InvalidAlpha(5))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use stark_rings::cyclotomic_ring::models::babybear::Fq;
+
+use crate::{
+    ark_base::*,
+    rings::{poseidon2::Poseidon2Config, BabyBearPoseidonConfig, GetPoseidon2Params},
+};
+
+impl GetPoseidon2Params<Fq> for BabyBearPoseidonConfig {
+    fn get_poseidon2_config() -> Poseidon2Config<Fq> {
+        let full_rounds = 8;
+        let partial_rounds = 13;
+        let alpha = 7;
+
+        let external_rc = vec![
+            vec![
+                Fq::from(0x69cbb6af_i128),
+                Fq::from(0x46ad93f9_i128),
+                Fq::from(0x60a00f4e_i128),
+                Fq::from(0x6b1297cd_i128),
+                Fq::from(0x23189afe_i128),
+                Fq::from(0x732e7bef_i128),
+                Fq::from(0x72c246de_i128),
+                Fq::from(0x2c941900_i128),
+                Fq::from(0x0557eede_i128),
+                Fq::from(0x1580496f_i128),
+                Fq::from(0x3a3ea77b_i128),
+                Fq::from(0x54f3f271_i128),
+                Fq::from(0x0f49b029_i128),
+                Fq::from(0x47872fe1_i128),
+                Fq::from(0x221e2e36_i128),
+                Fq::from(0x1ab7202e_i128),
+            ],
+            vec![
+                Fq::from(0x487779a6_i128),
+                Fq::from(0x3851c9d8_i128),
+                Fq::from(0x38dc17c0_i128),
+                Fq::from(0x209f8849_i128),
+                Fq::from(0x268dcee8_i128),
+                Fq::from(0x350c48da_i128),
+                Fq::from(0x5b9ad32e_i128),
+                Fq::from(0x0523272b_i128),
+                Fq::from(0x3f89055b_i128),
+                Fq::from(0x01e894b2_i128),
+                Fq::from(0x13ddedde_i128),
+                Fq::from(0x1b2ef334_i128),
+                Fq::from(0x7507d8b4_i128),
+                Fq::from(0x6ceeb94e_i128),
+                Fq::from(0x52eb6ba2_i128),
+                Fq::from(0x50642905_i128),
+            ],
+            vec![
+                Fq::from(0x05453f3f_i128),
+                Fq::from(0x06349efc_i128),
+                Fq::from(0x6922787c_i128),
+                Fq::from(0x04bfff9c_i128),
+                Fq::from(0x768c714a_i128),
+                Fq::from(0x3e9ff21a_i128),
+                Fq::from(0x15737c9c_i128),
+                Fq::from(0x2229c807_i128),
+                Fq::from(0x0d47f88c_i128),
+                Fq::from(0x097e0ecc_i128),
+                Fq::from(0x27eadba0_i128),
+                Fq::from(0x2d7d29e4_i128),
+                Fq::from(0x3502aaa0_i128),
+                Fq::from(0x0f475fd7_i128),
+                Fq::from(0x29fbda49_i128),
+                Fq::from(0x018afffd_i128),
+            ],
+            vec![
+                Fq::from(0x0315b618_i128),
+                Fq::from(0x6d4497d1_i128),
+                Fq::from(0x1b171d9e_i128),
+                Fq::from(0x52861abd_i128),
+                Fq::from(0x2e5d0501_i128),
+                Fq::from(0x3ec8646c_i128),
+                Fq::from(0x6e5f250a_i128),
+                Fq::from(0x148ae8e6_i128),
+                Fq::from(0x17f5fa4a_i128),
+                Fq::from(0x3e66d284_i128),
+                Fq::from(0x0051aa3b_i128),
+                Fq::from(0x483f7913_i128),
+                Fq::from(0x2cfe5f15_i128),
+                Fq::from(0x023427ca_i128),
+                Fq::from(0x2cc78315_i128),
+                Fq::from(0x1e36ea47_i128),
+            ],
+            vec![
+                Fq::from(0x7290a80d_i128),
+                Fq::from(0x6f7e5329_i128),
+                Fq::from(0x598ec8a8_i128),
+                Fq::from(0x76a859a0_i128),
+                Fq::from(0x6559e868_i128),
+                Fq::from(0x657b83af_i128),
+                Fq::from(0x13271d3f_i128),
+                Fq::from(0x1f876063_i128),
+                Fq::from(0x0aeeae37_i128),
+                Fq::from(0x706e9ca6_i128),
+                Fq::from(0x46400cee_i128),
+                Fq::from(0x72a05c26_i128),
+                Fq::from(0x2c589c9e_i128),
+                Fq::from(0x20bd37a7_i128),
+                Fq::from(0x6a2d3d10_i128),
+                Fq::from(0x20523767_i128),
+            ],
+            vec![
+                Fq::from(0x5b8fe9c4_i128),
+                Fq::from(0x2aa501d6_i128),
+                Fq::from(0x1e01ac3e_i128),
+                Fq::from(0x1448bc54_i128),
+                Fq::from(0x5ce5ad1c_i128),
+                Fq::from(0x4918a14d_i128),
+                Fq::from(0x2c46a83f_i128),
+                Fq::from(0x4fcf6876_i128),
+                Fq::from(0x61d8d5c8_i128),
+                Fq::from(0x6ddf4ff9_i128),
+                Fq::from(0x11fda4d3_i128),
+                Fq::from(0x02933a8f_i128),
+                Fq::from(0x170eaf81_i128),
+                Fq::from(0x5a9c314f_i128),
+                Fq::from(0x49a12590_i128),
+                Fq::from(0x35ec52a1_i128),
+            ],
+            vec![
+                Fq::from(0x58eb1611_i128),
+                Fq::from(0x5e481e65_i128),
+                Fq::from(0x367125c9_i128),
+                Fq::from(0x0eba33ba_i128),
+                Fq::from(0x1fc28ded_i128),
+                Fq::from(0x066399ad_i128),
+                Fq::from(0x0cbec0ea_i128),
+                Fq::from(0x75fd1af0_i128),
+                Fq::from(0x50f5bf4e_i128),
+                Fq::from(0x643d5f41_i128),
+                Fq::from(0x6f4fe718_i128),
+                Fq::from(0x5b3cbbde_i128),
+                Fq::from(0x1e3afb3e_i128),
+                Fq::from(0x296fb027_i128),
+                Fq::from(0x45e1547b_i128),
+                Fq::from(0x4a8db2ab_i128),
+            ],
+            vec![
+                Fq::from(0x59986d19_i128),
+                Fq::from(0x30bcdfa3_i128),
+                Fq::from(0x1db63932_i128),
+                Fq::from(0x1d7c2824_i128),
+                Fq::from(0x53b33681_i128),
+                Fq::from(0x0673b747_i128),
+                Fq::from(0x038a98a3_i128),
+                Fq::from(0x2c5bce60_i128),
+                Fq::from(0x351979cd_i128),
+                Fq::from(0x5008fb73_i128),
+                Fq::from(0x547bca78_i128),
+                Fq::from(0x711af481_i128),
+                Fq::from(0x3f93bf64_i128),
+                Fq::from(0x644d987b_i128),
+                Fq::from(0x3c8bcd87_i128),
+                Fq::from(0x608758b8_i128),
+            ],
+        ];
+
+        let internal_rc = vec![
+            Fq::from(0x5a8053c0_i128),
+            Fq::from(0x693be639_i128),
+            Fq::from(0x3858867d_i128),
+            Fq::from(0x19334f6b_i128),
+            Fq::from(0x128f0fd8_i128),
+            Fq::from(0x4e2b1ccb_i128),
+            Fq::from(0x61210ce0_i128),
+            Fq::from(0x3c318939_i128),
+            Fq::from(0x0b5b2f22_i128),
+            Fq::from(0x2edb11d5_i128),
+            Fq::from(0x213effdf_i128),
+            Fq::from(0x0cac4606_i128),
+            Fq::from(0x241af16d_i128),
+        ];
+
+        let internal_diag = vec![
+            Fq::from(0x0a632d95_i128),
+            Fq::from(0x6db657b8_i128),
+            Fq::from(0x56fbdc9f_i128),
+            Fq::from(0x052b3d8b_i128),
+            Fq::from(0x33745202_i128),
+            Fq::from(0x5c03108d_i128),
+            Fq::from(0x0beba37c_i128),
+            Fq::from(0x258c2e8c_i128),
+            Fq::from(0x12029f3a_i128),
+            Fq::from(0x694909cf_i128),
+            Fq::from(0x6d231725_i128),
+            Fq::from(0x21c3b223_i128),
+            Fq::from(0x3c0904a6_i128),
+            Fq::from(0x01d6acdb_i128),
+            Fq::from(0x27705c84_i128),
+            Fq::from(0x5231c803_i128),
+        ];
+
+        Poseidon2Config::<Fq>::new(
+            full_rounds,
+            partial_rounds,
+            alpha,
+            external_rc,
+            internal_rc,
+            internal_diag,
+            8,
+            8,
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use stark_rings::cyclotomic_ring::models::goldilocks::Fq;
+
+use crate::{
+    ark_base::*,
+    rings::{poseidon2::Poseidon2Config, GetPoseidon2Params, GoldilocksPoseidonConfig},
+};
+
+impl GetPoseidon2Params<Fq> for GoldilocksPoseidonConfig {
+    fn get_poseidon2_config() -> Poseidon2Config<Fq> {
+        let full_rounds = 8;
+        let partial_rounds = 22;
+        let alpha = 7;
+
+        let external_rc = vec![
+            vec![
+                Fq::from(0x13dcf33aba214f46_i128),
+                Fq::from(0x30b3b654a1da6d83_i128),
+                Fq::from(0x1fc634ada6159b56_i128),
+                Fq::from(0x937459964dc03466_i128),
+                Fq::from(0xedd2ef2ca7949924_i128),
+                Fq::from(0xede9affde0e22f68_i128),
+                Fq::from(0x8515b9d6bac9282d_i128),
+                Fq::from(0x6b5c07b4e9e900d8_i128),
+                Fq::from(0x1ec66368838c8a08_i128),
+                Fq::from(0x9042367d80d1fbab_i128),
+                Fq::from(0x400283564a3c3799_i128),
+                Fq::from(0x4a00be0466bca75e_i128),
+            ],
+            vec![
+                Fq::from(0x7913beee58e3817f_i128),
+                Fq::from(0xf545e88532237d90_i128),
+                Fq::from(0x22f8cb8736042005_i128),
+                Fq::from(0x6f04990e247a2623_i128),
+                Fq::from(0xfe22e87ba37c38cd_i128),
+                Fq::from(0xd20e32c85ffe2815_i128),
+                Fq::from(0x117227674048fe73_i128),
+                Fq::from(0x4e9fb7ea98a6b145_i128),
+                Fq::from(0xe0866c232b8af08b_i128),
+                Fq::from(0x00bbc77916884964_i128),
+                Fq::from(0x7031c0fb990d7116_i128),
+                Fq::from(0x240a9e87cf35108f_i128),
+            ],
+            vec![
+                Fq::from(0x2e6363a5a12244b3_i128),
+                Fq::from(0x5e1c3787d1b5011c_i128),
+                Fq::from(0x4132660e2a196e8b_i128),
+                Fq::from(0x3a013b648d3d4327_i128),
+                Fq::from(0xf79839f49888ea43_i128),
+                Fq::from(0xfe85658ebafe1439_i128),
+                Fq::from(0xb6889825a14240bd_i128),
+                Fq::from(0x578453605541382b_i128),
+                Fq::from(0x4508cda8f6b63ce9_i128),
+                Fq::from(0x9c3ef35848684c91_i128),
+                Fq::from(0x0812bde23c87178c_i128),
+                Fq::from(0xfe49638f7f722c14_i128),
+            ],
+            vec![
+                Fq::from(0x8e3f688ce885cbf5_i128),
+                Fq::from(0xb8e110acf746a87d_i128),
+                Fq::from(0xb4b2e8973a6dabef_i128),
+                Fq::from(0x9e714c5da3d462ec_i128),
+                Fq::from(0x6438f9033d3d0c15_i128),
+                Fq::from(0x24312f7cf1a27199_i128),
+                Fq::from(0x23f843bb47acbf71_i128),
+                Fq::from(0x9183f11a34be9f01_i128),
+                Fq::from(0x839062fbb9d45dbf_i128),
+                Fq::from(0x24b56e7e6c2e43fa_i128),
+                Fq::from(0xe1683da61c962a72_i128),
+                Fq::from(0xa95c63971a19bfa7_i128),
+            ],
+            vec![
+                Fq::from(0xc68be7c94882a24d_i128),
+                Fq::from(0xaf996d5d5cdaedd9_i128),
+                Fq::from(0x9717f025e7daf6a5_i128),
+                Fq::from(0x6436679e6e7216f4_i128),
+                Fq::from(0x8a223d99047af267_i128),
+                Fq::from(0xbb512e35a133ba9a_i128),
+                Fq::from(0xfbbf44097671aa03_i128),
+                Fq::from(0xf04058ebf6811e61_i128),
+                Fq::from(0x5cca84703fac7ffb_i128),
+                Fq::from(0x9b55c7945de6469f_i128),
+                Fq::from(0x8e05bf09808e934f_i128),
+                Fq::from(0x2ea900de876307d7_i128),
+            ],
+            vec![
+                Fq::from(0x7748fff2b38dfb89_i128),
+                Fq::from(0x6b99a676dd3b5d81_i128),
+                Fq::from(0xac4bb7c627cf7c13_i128),
+                Fq::from(0xadb6ebe5e9e2f5ba_i128),
+                Fq::from(0x2d33378cafa24ae3_i128),
+                Fq::from(0x1e5b73807543f8c2_i128),
+                Fq::from(0x09208814bfebb10f_i128),
+                Fq::from(0x782e64b6bb5b93dd_i128),
+                Fq::from(0xadd5a48eac90b50f_i128),
+                Fq::from(0xadd4c54c736ea4b1_i128),
+                Fq::from(0xd58dbb86ed817fd8_i128),
+                Fq::from(0x6d5ed1a533f34ddd_i128),
+            ],
+            vec![
+                Fq::from(0x28686aa3e36b7cb9_i128),
+                Fq::from(0x591abd3476689f36_i128),
+                Fq::from(0x047d766678f13875_i128),
+                Fq::from(0xa2a11112625f5b49_i128),
+                Fq::from(0x21fd10a3f8304958_i128),
+                Fq::from(0xf9b40711443b0280_i128),
+                Fq::from(0xd2697eb8b2bde88e_i128),
+                Fq::from(0x3493790b51731b3f_i128),
+                Fq::from(0x11caf9dd73764023_i128),
+                Fq::from(0x7acfb8f72878164e_i128),
+                Fq::from(0x744ec4db23cefc26_i128),
+                Fq::from(0x1e00e58f422c6340_i128),
+            ],
+            vec![
+                Fq::from(0x21dd28d906a62dda_i128),
+                Fq::from(0xf32a46ab5f465b5f_i128),
+                Fq::from(0xbfce13201f3f7e6b_i128),
+                Fq::from(0xf30d2e7adb5304e2_i128),
+                Fq::from(0xecdf4ee4abad48e9_i128),
+                Fq::from(0xf94e82182d395019_i128),
+                Fq::from(0x4ee52e3744d887c5_i128),
+                Fq::from(0xa1341c7cac0083b2_i128),
+                Fq::from(0x2302fb26c30c834a_i128),
+                Fq::from(0xaea3c587273bf7d3_i128),
+                Fq::from(0xf798e24961823ec7_i128),
+                Fq::from(0x962deba3e9a2cd94_i128),
+            ],
+        ];
+
+        let internal_rc = vec![
+            Fq::from(0x4adf842aa75d4316_i128),
+            Fq::from(0xf8fbb871aa4ab4eb_i128),
+            Fq::from(0x68e85b6eb2dd6aeb_i128),
+            Fq::from(0x07a0b06b2d270380_i128),
+            Fq::from(0xd94e0228bd282de4_i128),
+            Fq::from(0x8bdd91d3250c5278_i128),
+            Fq::from(0x209c68b88bba778f_i128),
+            Fq::from(0xb5e18cdab77f3877_i128),
+            Fq::from(0xb296a3e808da93fa_i128),
+            Fq::from(0x8370ecbda11a327e_i128),
+            Fq::from(0x3f9075283775dad8_i128),
+            Fq::from(0xb78095bb23c6aa84_i128),
+            Fq::from(0x3f36b9fe72ad4e5f_i128),
+            Fq::from(0x69bc96780b10b553_i128),
+            Fq::from(0x3f1d341f2eb7b881_i128),
+            Fq::from(0x4e939e9815838818_i128),
+            Fq::from(0xda366b3ae2a31604_i128),
+            Fq::from(0xbc89db1e7287d509_i128),
+            Fq::from(0x6102f411f9ef5659_i128),
+            Fq::from(0x58725c5e7ac1f0ab_i128),
+            Fq::from(0x0df5856c798883e7_i128),
+            Fq::from(0xf7bb62a8da4c961b_i128),
+        ];
+
+        let internal_diag = vec![
+            Fq::from(0xc3b6c08e23ba9301_i128),
+            Fq::from(0xd84b5de94a324fb7_i128),
+            Fq::from(0x0d0c371c5b35b850_i128),
+            Fq::from(0x7964f570e7188038_i128),
+            Fq::from(0x5daf18bbd996604c_i128),
+            Fq::from(0x6743bc47b9595258_i128),
+            Fq::from(0x5528b9362c59bb71_i128),
+            Fq::from(0xac45e25b7127b68c_i128),
+            Fq::from(0xa2077d7dfbb606b6_i128),
+            Fq::from(0xf3faac6faee378af_i128),
+            Fq::from(0x0c6388b51545e884_i128),
+            Fq::from(0xd27dbb6944917b61_i128),
+        ];
+
+        Poseidon2Config::<Fq>::new(
+            full_rounds,
+            partial_rounds,
+            alpha,
+            external_rc,
+            internal_rc,
+            internal_diag,
+            8,
+            4,
+        )
+    }
+}
@@ -23,6 +23,7 @@ impl SuitableRing for StarkRingNTT {
     type CoefficientRepresentation = StarkRingPoly;
 
     type PoseidonParams = StarkPoseidonConfig;
+    type Poseidon2Params = StarkPoseidonConfig;
 }
 
 pub struct StarkPoseidonConfig;
@@ -3,13 +3,43 @@ use criterion::{
     criterion_group, criterion_main, AxisScale, BatchSize::SmallInput, BenchmarkId, Criterion,
     PlotConfiguration,
 };
-use cyclotomic_rings::rings::{BabyBearRingNTT, FrogRingNTT, GoldilocksRingNTT, StarkRingNTT};
+use cyclotomic_rings::rings::{
+    BabyBearRingNTT, FrogRingNTT, GoldilocksRingNTT, StarkRingNTT, SuitableRing,
+};
 use env::ENV;
 use latticefold::commitment::AjtaiCommitmentScheme;
-use stark_rings::cyclotomic_ring::{CRT, ICRT};
+use rand::Rng;
+use stark_rings::{
+    cyclotomic_ring::{CRT, ICRT},
+    PolyRing,
+};
 
 mod env;
 
+/// Returns a witness of length `w` shaped like the output of the radix-2 decomposition,
+/// i.e. with every coefficient drawn uniformly from the balanced digits `{-1, 0, 1}`,
+/// so that no digit is biased towards zero.
+fn decomposed_witness<R: SuitableRing>(
+    w: usize,
+    rng: &mut impl Rng,
+) -> Vec<R::CoefficientRepresentation> {
+    let one = <R::CoefficientRepresentation as PolyRing>::BaseRing::from(1u64);
+
+    (0..w)
+        .map(|_| {
+            let coeffs: Vec<<R::CoefficientRepresentation as PolyRing>::BaseRing> = (0
+                ..R::CoefficientRepresentation::dimension())
+                .map(|_| {
+                    <R::CoefficientRepresentation as PolyRing>::BaseRing::from(
+                        rng.gen_range(0..3u64),
+                    ) - one
+                })
+                .collect();
+            R::CoefficientRepresentation::from(coeffs)
+        })
+        .collect()
+}
+
 include!(concat!(env!("OUT_DIR"), "/generated_ajtai_benchmarks.rs"));
 
 fn ajtai_benchmarks(c: &mut Criterion) {
@@ -24,6 +24,7 @@ use latticefold::{
             LFLinearizationProver, LFLinearizationVerifier, LinearizationProof,
             LinearizationProver, LinearizationVerifier,
         },
+        statement::{statement_digest, VerifierKey},
         NIFSProver, NIFSVerifier,
     },
     transcript::poseidon::PoseidonTranscript,
@@ -85,7 +86,7 @@ pub fn wit_and_ccs_gen<
     let wit: Witness<R> = Witness::from_w_ccs::<P>(w_ccs);
 
     let cm_i: CCCS<C, R> = CCCS {
-        cm: wit.commit::<C, W, P>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
 
@@ -128,7 +129,7 @@ pub fn wit_and_ccs_gen_non_scalar<
     let wit: Witness<R> = Witness::from_w_ccs::<P>(w_ccs);
 
     let cm_i: CCCS<C, R> = CCCS {
-        cm: wit.commit::<C, W, P>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
 
@@ -187,7 +188,7 @@ pub fn wit_and_ccs_gen_degree_three_non_scalar<
     let wit: Witness<R> = Witness::from_w_ccs::<P>(w_ccs);
 
     let cm_i: CCCS<C, R> = CCCS {
-        cm: wit.commit::<C, W, P>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
 
@@ -252,15 +253,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Linearization Prover",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let transcript = PoseidonTranscript::<R, CS>::default();
@@ -286,15 +279,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Linearization Verifier",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut transcript = PoseidonTranscript::<R, CS>::default();
@@ -325,15 +310,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Decomposition Prover",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut prover_transcript = PoseidonTranscript::<R, CS>::default();
@@ -350,15 +327,14 @@ impl<
                     Self::verify_linearization_proof(&proof, &mut verifier_transcript, &cm_i, &ccs);
 
                 b.iter(|| {
-                    let _ =
-                        LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<W, C, P>(
-                            &lcccs,
-                            &wit,
-                            &mut prover_transcript,
-                            &ccs,
-                            &scheme,
-                        )
-                        .expect("Failed to generate decomposition proof");
+                    let _ = LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<C, P>(
+                        &lcccs,
+                        &wit,
+                        &mut prover_transcript,
+                        &ccs,
+                        &scheme,
+                    )
+                    .expect("Failed to generate decomposition proof");
                 });
             },
         );
@@ -368,15 +344,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Decomposition Verifier",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut prover_transcript = PoseidonTranscript::<R, CS>::default();
@@ -399,7 +367,7 @@ impl<
                 .expect("Failed to verify linearization proof");
 
                 let (_, _, _, proof) =
-                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<W, C, P>(
+                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<C, P>(
                         &lcccs,
                         &wit,
                         &mut prover_transcript,
@@ -429,15 +397,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Folding Prover",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let (cm_i, wit, ccs, scheme) = Self::setup_r1cs(t);
@@ -462,7 +422,7 @@ impl<
                 .unwrap();
 
                 let (mz_mles, _, wit_vec, decomposition_proof) =
-                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<W, C, P>(
+                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<C, P>(
                         &lcccs,
                         &wit,
                         &mut prover_transcript,
@@ -515,15 +475,7 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "Folding Verifier",
-                format!(
-                    "Param. Kappa={}, Cols={},  B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut prover_transcript = PoseidonTranscript::<R, CS>::default();
@@ -547,7 +499,7 @@ impl<
                 .expect("Failed to verify linearization proof");
 
                 let (mz_mles, _, wit_vec, decomposition_proof) =
-                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<W, C, P>(
+                    LFDecompositionProver::<_, PoseidonTranscript<R, CS>>::prove::<C, P>(
                         &lcccs,
                         &wit,
                         &mut prover_transcript,
@@ -610,20 +562,13 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "E2E Prover",
-                format!(
-                    "Param. Kappa={}, Cols={}, B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut prover_transcript = PoseidonTranscript::<R, CS>::default();
                 let mut verifier_transcript = PoseidonTranscript::<R, CS>::default();
                 let (cm_i, wit, ccs, scheme) = Self::setup_r1cs(t);
+                let statement = statement_digest::<C, R, P>(&ccs, &scheme);
 
                 let (_, linearization_proof) =
                     LFLinearizationProver::<_, PoseidonTranscript<R, CS>>::prove(
@@ -645,7 +590,7 @@ impl<
                 b.iter_batched(
                     || prover_transcript.clone(),
                     |mut bench_prover_transcript| {
-                        let _ = NIFSProver::<C, W, R, P, PoseidonTranscript<R, CS>>::prove(
+                        let _ = NIFSProver::<C, R, P, PoseidonTranscript<R, CS>>::prove(
                             &lcccs,
                             &wit,
                             &cm_i,
@@ -653,6 +598,7 @@ impl<
                             &mut bench_prover_transcript,
                             &ccs,
                             &scheme,
+                            &statement,
                         )
                         .expect("Failed to generate proof");
                     },
@@ -666,20 +612,14 @@ impl<
         group.bench_function(
             BenchmarkId::new(
                 "E2E Verifier",
-                format!(
-                    "Param. Kappa={}, Cols={}, B={}, L={}, B_small={}, K={}",
-                    C,
-                    { W / P::L },
-                    P::B,
-                    P::L,
-                    P::B_SMALL,
-                    P::K
-                ),
+                format!("Param. Kappa={}, Cols={}, {}", C, { W / P::L }, P::CONFIG),
             ),
             |b| {
                 let mut prover_transcript = PoseidonTranscript::<R, CS>::default();
                 let mut verifier_transcript = PoseidonTranscript::<R, CS>::default();
                 let (cm_i, wit, ccs, scheme) = Self::setup_r1cs(t);
+                let statement = statement_digest::<C, R, P>(&ccs, &scheme);
+                let vk = VerifierKey::new::<P>(&ccs, &scheme);
 
                 let (acc_lcccs, linearization_proof) = LFLinearizationProver::<
                     _,
@@ -697,7 +637,7 @@ impl<
                 )
                 .expect("Failed to verify linearization");
 
-                let (_, _, proof) = NIFSProver::<C, W, R, P, PoseidonTranscript<R, CS>>::prove(
+                let (_, _, proof) = NIFSProver::<C, R, P, PoseidonTranscript<R, CS>>::prove(
                     &lcccs,
                     &wit,
                     &cm_i,
@@ -705,6 +645,7 @@ impl<
                     &mut prover_transcript,
                     &ccs,
                     &scheme,
+                    &statement,
                 )
                 .expect("Failed to generate proof");
 
@@ -716,7 +657,7 @@ impl<
                             &cm_i,
                             &proof,
                             &mut bench_verifier_transcript,
-                            &ccs,
+                            &vk,
                         );
                         assert!(result.is_ok());
                     },
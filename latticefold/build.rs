@@ -21,12 +21,10 @@ fn generate_code_for_examples() {
     let l = std::env::var("PARAM_L").unwrap_or("5".to_string());
     let b_small = std::env::var("PARAM_B_SMALL").unwrap_or("2".to_string());
     let k = std::env::var("PARAM_K").unwrap_or("15".to_string());
-    let c = std::env::var("PARAM_C").unwrap_or("4".to_string());
     let wit_len = std::env::var("PARAM_WIT_LEN").unwrap_or("4".to_string());
     let x_len = std::env::var("PARAM_X_LEN").unwrap_or("1".to_string());
 
     let x_len_stark = std::env::var("PARAM_X_LEN_STARK").unwrap_or("1".to_string());
-    let c_stark = std::env::var("PARAM_C_STARK").unwrap_or("15".to_string());
     let b_stark = std::env::var("PARAM_B_STARK").unwrap_or("1073741824u128".to_string());
     let l_stark = std::env::var("PARAM_L_STARK").unwrap_or("9".to_string());
     let b_small_stark = std::env::var("PARAM_B_SMALL_STARK").unwrap_or("2".to_string());
@@ -85,7 +83,6 @@ fn generate_code_for_examples() {
         }}
 
         const X_LEN: usize = {x_len}; // Default = 1
-        const C: usize = {c}; // Default = 4
         const WIT_LEN: usize = {wit_len}; // Default = 4
 
         const W_GOLDILOCKS: usize = WIT_LEN * GoldilocksExampleDP::L;
@@ -93,7 +90,6 @@ fn generate_code_for_examples() {
         const W_FROG: usize = WIT_LEN * FrogExampleDP::L;
 
         const X_LEN_STARK: usize = {x_len_stark}; // Default = 1
-        const C_STARK: usize = {c_stark}; // Default = 4
         const WIT_LEN_STARK: usize = {wit_len_stark}; // Default = 4
 
         const W_STARK: usize = WIT_LEN_STARK * StarkPrimeExampleDP::L;
@@ -109,11 +105,9 @@ fn generate_code_for_examples() {
     println!("cargo:rerun-if-env-changed=PARAM_L");
     println!("cargo:rerun-if-env-changed=PARAM_B_SMALL");
     println!("cargo:rerun-if-env-changed=PARAM_K");
-    println!("cargo:rerun-if-env-changed=PARAM_C");
     println!("cargo:rerun-if-env-changed=PARAM_WIT_LEN");
     println!("cargo:rerun-if-env-changed=PARAM_X_LEN");
     println!("cargo:rerun-if-env-changed=PARAM_X_LEN_STARK");
-    println!("cargo:rerun-if-env-changed=PARAM_C_STARK");
     println!("cargo:rerun-if-env-changed=PARAM_B_STARK");
     println!("cargo:rerun-if-env-changed=PARAM_L_STARK");
     println!("cargo:rerun-if-env-changed=PARAM_B_SMALL_STARK");
@@ -430,6 +424,40 @@ fn write_ajtai_group(file: &mut File, benchmarks: &[AjtaiRecord], name: &str, ri
                 },
                 );
 
+                // Commitment of a decomposed coefficient form witness via CRT and CommitNTT
+                group.bench_function(
+                    BenchmarkId::new("CommitCoeffCRT", format!("C={}, W={}", C, W)),
+                    |b| {
+                        let mut rng = ark_std::test_rng();
+                        let witness = decomposed_witness::<R>(W, &mut rng);
+                        let ajtai_data: AjtaiCommitmentScheme<C, W, R> = AjtaiCommitmentScheme::rand(&mut rng);
+                        b.iter_batched(
+                            || witness.clone(),
+                            |witness| {
+                                let _ = ajtai_data.commit_ntt(&CRT::elementwise_crt(witness));
+                            },
+                            SmallInput,
+                        );
+                    },
+                );
+
+                // Commitment of the same witness via CommitCoeff
+                group.bench_function(
+                    BenchmarkId::new("CommitCoeff", format!("C={}, W={}", C, W)),
+                    |b| {
+                        let mut rng = ark_std::test_rng();
+                        let witness = decomposed_witness::<R>(W, &mut rng);
+                        let ajtai_data: AjtaiCommitmentScheme<C, W, R> = AjtaiCommitmentScheme::rand(&mut rng);
+                        b.iter_batched(
+                            || witness.clone(),
+                            |witness| {
+                                let _ = ajtai_data.commit_coeff(witness);
+                            },
+                            SmallInput,
+                        );
+                    },
+                );
+
                 // NTT -> INTT (coefficients)
                 group.bench_function(
                     BenchmarkId::new("NTT->INTT", format!("C={}, W={}", C, W)),
@@ -13,9 +13,10 @@ use latticefold::{
         ccs::get_test_dummy_degree_three_ccs_non_scalar, r1cs::get_test_dummy_z_split_ntt, Arith,
         Witness, CCCS, CCS, LCCCS,
     },
-    commitment::AjtaiCommitmentScheme,
+    commitment::DynAjtaiCommitmentScheme,
     nifs::{
         linearization::{LFLinearizationProver, LinearizationProver},
+        statement::{statement_digest, VerifierKey},
         NIFSProver, NIFSVerifier,
     },
     transcript::poseidon::PoseidonTranscript,
@@ -23,6 +24,11 @@ use latticefold::{
 
 include!(concat!(env!("OUT_DIR"), "/examples_generated.rs"));
 
+/// The length of the commitments, i.e. the number of rows of the Ajtai matrix.
+/// It is set by the security level of the commitment rather than by the circuit,
+/// while the witness length of the runtime-sized key follows the circuit.
+const C: usize = 4;
+
 #[allow(dead_code)]
 pub fn wit_and_ccs_gen_degree_three_non_scalar<
     const X_LEN: usize,
@@ -33,12 +39,7 @@ pub fn wit_and_ccs_gen_degree_three_non_scalar<
     R: Clone + UniformRand + Debug + SuitableRing,
 >(
     r1cs_rows: usize,
-) -> (
-    CCCS<C, R>,
-    Witness<R>,
-    CCS<R>,
-    AjtaiCommitmentScheme<C, W, R>,
-) {
+) -> (CCCS<C, R>, Witness<R>, CCS<R>, DynAjtaiCommitmentScheme<R>) {
     let mut rng = ark_std::test_rng();
 
     let new_r1cs_rows = if P::L == 1 && (WIT_LEN > 0 && (WIT_LEN & (WIT_LEN - 1)) == 0) {
@@ -55,11 +56,11 @@ pub fn wit_and_ccs_gen_degree_three_non_scalar<
         get_test_dummy_degree_three_ccs_non_scalar::<R, X_LEN, WIT_LEN, W>(&z, P::L, new_r1cs_rows);
     ccs.check_relation(&z).expect("R1CS invalid!");
 
-    let scheme: AjtaiCommitmentScheme<C, W, R> = AjtaiCommitmentScheme::rand(&mut rng);
+    let scheme = DynAjtaiCommitmentScheme::rand(&mut rng, C, W);
     let wit: Witness<R> = Witness::from_w_ccs::<P>(w_ccs);
 
     let cm_i: CCCS<C, R> = CCCS {
-        cm: wit.commit::<C, W, P>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
 
@@ -81,7 +82,7 @@ fn setup_example_environment<
     CCCS<C, RqNTT>,
     Witness<RqNTT>,
     CCS<RqNTT>,
-    AjtaiCommitmentScheme<C, W, RqNTT>,
+    DynAjtaiCommitmentScheme<RqNTT>,
 ) {
     let r1cs_rows = X_LEN + WIT_LEN + 1;
 
@@ -129,10 +130,12 @@ fn main() {
 
     let mut prover_transcript = PoseidonTranscript::<RqNTT, CS>::default();
     let mut verifier_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let statement = statement_digest::<C, RqNTT, GoldilocksExampleDP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<GoldilocksExampleDP>(&ccs, &scheme);
     println!("Generating proof...");
     let start = Instant::now();
 
-    let (_, _, proof) = NIFSProver::<C, W_GOLDILOCKS, RqNTT, GoldilocksExampleDP, T>::prove(
+    let (_, _, proof) = NIFSProver::<C, RqNTT, GoldilocksExampleDP, T>::prove(
         &acc,
         &wit_acc,
         &cm_i,
@@ -140,6 +143,7 @@ fn main() {
         &mut prover_transcript,
         &ccs,
         &scheme,
+        &statement,
     )
     .unwrap();
     let duration = start.elapsed();
@@ -174,7 +178,7 @@ fn main() {
         &cm_i,
         &proof,
         &mut verifier_transcript,
-        &ccs,
+        &vk,
     )
     .unwrap();
     let duration = start.elapsed();
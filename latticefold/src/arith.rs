@@ -5,8 +5,13 @@
 use core::mem;
 
 use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::log2;
-use cyclotomic_rings::rings::SuitableRing;
+use cyclotomic_rings::{
+    norm::{centered_abs, vec_norm_inf},
+    rings::SuitableRing,
+};
+use num_bigint::BigUint;
 use r1cs::R1CS;
 use stark_rings::{
     balanced_decomposition::{gadget_decompose, gadget_recompose},
@@ -17,18 +22,20 @@ use stark_rings_linalg::SparseMatrix;
 use stark_rings_poly::mle::DenseMultilinearExtension;
 
 use self::{
-    error::CSError as Error,
+    error::{CSError as Error, NormError, RelationError},
     utils::{hadamard, mat_vec_mul, vec_add, vec_scalar_mul},
 };
 use crate::{
     ark_base::*,
-    commitment::{AjtaiCommitmentScheme, Commitment, CommitmentError},
-    decomposition_parameters::DecompositionParams,
+    commitment::{AjtaiCommitmentKey, AjtaiCommitmentScheme, Commitment, CommitmentError},
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
+    utils::mle_helpers::{calculate_Mz_mles, evaluate_mles},
 };
 
 pub mod ccs;
 pub mod error;
 pub mod r1cs;
+mod serialization;
 pub mod utils;
 
 /// A trait for defining the behaviour of an arithmetic constraint system.
@@ -42,6 +49,8 @@ pub trait Arith<R: Ring> {
 
     /// Returns the bytes that represent the parameters, that is, the matrices sizes, the amount of
     /// public inputs, etc, without the matrices/polynomials values.
+    ///
+    /// The sizes are encoded as little-endian `u64`s, so the bytes do not depend on the platform.
     fn params_to_le_bytes(&self) -> Vec<u8>;
 }
 
@@ -106,15 +115,10 @@ impl<R: Ring> Arith<R> for CCS<R> {
     }
 
     fn params_to_le_bytes(&self) -> Vec<u8> {
-        [
-            self.l.to_le_bytes(),
-            self.m.to_le_bytes(),
-            self.n.to_le_bytes(),
-            self.t.to_le_bytes(),
-            self.q.to_le_bytes(),
-            self.d.to_le_bytes(),
-        ]
-        .concat()
+        [self.l, self.m, self.n, self.t, self.q, self.d]
+            .into_iter()
+            .flat_map(|param| (param as u64).to_le_bytes())
+            .collect()
     }
 }
 
@@ -160,7 +164,7 @@ impl<R: Ring> CCS<R> {
         }
     }
 
-    fn pad_rows_to(&mut self, size: usize) {
+    pub(crate) fn pad_rows_to(&mut self, size: usize) {
         let size = size.next_power_of_two();
         if size > self.m {
             self.m = size;
@@ -178,7 +182,7 @@ impl<R: Ring> CCS<R> {
 /// - `C`: The length of the commitment vector.
 /// - `R`: The ring in which the CCS is operating.
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct CCCS<const C: usize, R: Ring> {
     /// A commitment to the B-decomposed CCS witness.
     pub cm: Commitment<C, R>,
@@ -192,7 +196,7 @@ pub struct CCCS<const C: usize, R: Ring> {
 /// - `C`: The length of the commitment vector.
 /// - `R`: The ring in which the CCS is operating.
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct LCCCS<const C: usize, R: Ring> {
     /// The linearization sumcheck challenge vector
     pub r: Vec<R>,
@@ -231,11 +235,16 @@ impl<NTT: SuitableRing> Witness<NTT> {
     /// The main operations that need to be done are decomposing the ccs witness.
     /// We can then construct [`f_hat`](crate::arith::Witness::get_fhat).
     pub fn from_w_ccs<P: DecompositionParams>(w_ccs: Vec<NTT>) -> Self {
+        Self::from_w_ccs_with_config(w_ccs, &P::CONFIG)
+    }
+
+    /// Create a [`Witness`] from a ccs witness, decomposing it with the parameters `dp` chosen at runtime.
+    pub fn from_w_ccs_with_config(w_ccs: Vec<NTT>, dp: &DecompositionConfig) -> Self {
         // iNTT
         let w_coeff: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(w_ccs.clone());
 
         // decompose radix-B
-        let f_coeff: Vec<NTT::CoefficientRepresentation> = gadget_decompose(&w_coeff, P::B, P::L);
+        let f_coeff: Vec<NTT::CoefficientRepresentation> = gadget_decompose(&w_coeff, dp.b, dp.l);
 
         // NTT(coef_repr_decomposed)
         let f: Vec<NTT> = CRT::elementwise_crt(f_coeff.clone());
@@ -300,12 +309,16 @@ impl<NTT: SuitableRing> Witness<NTT> {
     }
 
     pub(crate) fn from_f<P: DecompositionParams>(f: Vec<NTT>) -> Self {
+        Self::from_f_with_config(f, &P::CONFIG)
+    }
+
+    pub(crate) fn from_f_with_config(f: Vec<NTT>, dp: &DecompositionConfig) -> Self {
         let f_coeff: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(f.clone());
         let f_hat: Vec<DenseMultilinearExtension<NTT>> = Self::get_fhat(&f_coeff);
         // Reconstruct the original CCS witness from the Ajtai witness
         // Ajtai witness has bound B
         // WE multiply by the base B gadget matrix to reconstruct w_ccs
-        let w_ccs = gadget_recompose(&f, P::B, P::L);
+        let w_ccs = gadget_recompose(&f, dp.b, dp.l);
 
         Self {
             f,
@@ -326,11 +339,20 @@ impl<NTT: SuitableRing> Witness<NTT> {
     /// We can multiply by the base B gadget matrix to reconstruct w_ccs.
     pub fn from_f_coeff<P: DecompositionParams>(
         f_coeff: Vec<NTT::CoefficientRepresentation>,
+    ) -> Self {
+        Self::from_f_coeff_with_config(f_coeff, &P::CONFIG)
+    }
+
+    /// Reconstruct the original CCS witness from the Ajtai witness,
+    /// with the decomposition parameters `dp` chosen at runtime.
+    pub fn from_f_coeff_with_config(
+        f_coeff: Vec<NTT::CoefficientRepresentation>,
+        dp: &DecompositionConfig,
     ) -> Self {
         let f: Vec<NTT> = CRT::elementwise_crt(f_coeff.clone());
         let f_hat: Vec<DenseMultilinearExtension<NTT>> = Self::get_fhat(&f_coeff);
 
-        let w_ccs = gadget_recompose(&f, P::B, P::L);
+        let w_ccs = gadget_recompose(&f, dp.b, dp.l);
 
         Self {
             f,
@@ -356,10 +378,12 @@ impl<NTT: SuitableRing> Witness<NTT> {
 
     /// Produces a commitment from a witness
     ///
-    /// Ajtai commitments are produced by multiplying an Ajtai matrix by the witness vector
-    pub fn commit<const C: usize, const W: usize, P: DecompositionParams>(
+    /// Ajtai commitments are produced by multiplying an Ajtai matrix by the witness vector.
+    /// `ajtai` is any commitment key with `C` rows, e.g. an [`AjtaiCommitmentScheme`]
+    /// or a [`DynAjtaiCommitmentScheme`](crate::commitment::DynAjtaiCommitmentScheme) at least as wide as `f`.
+    pub fn commit<const C: usize>(
         &self,
-        ajtai: &AjtaiCommitmentScheme<C, W, NTT>,
+        ajtai: &impl AjtaiCommitmentKey<C, NTT>,
     ) -> Result<Commitment<C, NTT>, CommitmentError> {
         ajtai.commit_ntt(&self.f)
     }
@@ -371,21 +395,128 @@ impl<NTT: SuitableRing> Witness<NTT> {
         mem::take(&mut self.f_hat)
     }
 
-    #[allow(dead_code)]
-    fn within_bound(&self, b: u128) -> bool {
-        // TODO consider using signed representatives instead
+    /// Returns the infinity norm of `f`, i.e. the largest absolute value
+    /// of the centered representatives of the coefficients of `f`.
+    pub fn norm_inf(&self) -> BigUint {
+        vec_norm_inf::<NTT::CoefficientRepresentation>(&ICRT::elementwise_icrt(self.f.clone()))
+    }
 
+    /// Checks that the centered representatives of all coefficients of `f` are below `bound` in absolute value.
+    ///
+    /// Reports the first coefficient that is not.
+    pub fn check_norm(&self, bound: u128) -> Result<(), NormError> {
         let coeffs_repr: Vec<NTT::CoefficientRepresentation> =
             ICRT::elementwise_icrt(self.f.clone());
+        let big_bound = BigUint::from(bound);
+
+        for (index, f_i) in coeffs_repr.iter().enumerate() {
+            for (coeff_index, coeff) in f_i.coeffs().iter().enumerate() {
+                let value = centered_abs(coeff);
+                if value >= big_bound {
+                    return Err(NormError {
+                        index,
+                        coeff_index,
+                        value,
+                        bound,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `f_coeff` and `f_hat` are derived from `f`.
+    ///
+    /// Only `f` is bound by the commitment, the other representations are trusted by the prover.
+    pub(crate) fn check_consistency(&self) -> Result<(), RelationError> {
+        let f_coeff: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(self.f.clone());
+        if f_coeff != self.f_coeff || Self::get_fhat(&f_coeff) != self.f_hat {
+            return Err(RelationError::InconsistentWitness);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the witness opens `cm`, has norm below `bound`
+    /// and that `w_ccs` is the gadget recomposition of `f`.
+    fn check_opening<const C: usize, const W: usize, P: DecompositionParams>(
+        &self,
+        cm: &Commitment<C, NTT>,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        bound: u128,
+    ) -> Result<(), RelationError> {
+        if self.commit(scheme)? != *cm {
+            return Err(RelationError::CommitmentMismatch);
+        }
 
-        // linf_norm should be used in CyclotomicGeneral not in specific ring
-        let b = <<NTT as PolyRing>::BaseRing as Field>::BasePrimeField::from(b);
-        let all_under_bound = coeffs_repr.iter().all(|ele| {
-            let coeffs = ele.coeffs();
-            coeffs.iter().all(|x| x < &b)
-        });
+        self.check_norm(bound)?;
 
-        all_under_bound
+        if gadget_recompose(&self.f, P::B, P::L) != self.w_ccs {
+            return Err(RelationError::RecompositionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+impl<const C: usize, NTT: SuitableRing> CCCS<C, NTT> {
+    /// Checks that `wit` satisfies the instance, i.e. that
+    ///  * `wit.f` opens the commitment `cm`,
+    ///  * all coefficients of `wit.f` are below `bound` in absolute value,
+    ///  * `wit.w_ccs` is the gadget recomposition of `wit.f`,
+    ///  * `x_ccs || 1 || w_ccs` satisfies `ccs`.
+    ///
+    /// Returns the first condition that does not hold.
+    pub fn check<const W: usize, P: DecompositionParams>(
+        &self,
+        wit: &Witness<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        bound: u128,
+    ) -> Result<(), RelationError> {
+        wit.check_opening::<C, W, P>(&self.cm, scheme, bound)?;
+
+        ccs.check_relation(&self.get_z_vector(&wit.w_ccs))?;
+
+        Ok(())
+    }
+}
+
+impl<const C: usize, NTT: SuitableRing> LCCCS<C, NTT> {
+    /// Checks that `wit` satisfies the instance, i.e. that
+    ///  * `wit.f` opens the commitment `cm`,
+    ///  * all coefficients of `wit.f` are below `bound` in absolute value,
+    ///  * `wit.w_ccs` is the gadget recomposition of `wit.f`,
+    ///  * `wit.f_coeff` and `wit.f_hat` are derived from `wit.f`,
+    ///  * `v` is the evaluation of the MLEs of `wit.f_hat` at `r`,
+    ///  * `u` is the evaluation of the MLEs of $\{ M_j \mathbf{z} \mid j = 1, 2, \dots, t \}$ at `r`,
+    ///    where $\mathbf{z} =$ `x_w || h || w_ccs`.
+    ///
+    /// Returns the first condition that does not hold.
+    pub fn check<const W: usize, P: DecompositionParams>(
+        &self,
+        wit: &Witness<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        bound: u128,
+    ) -> Result<(), RelationError> {
+        wit.check_opening::<C, W, P>(&self.cm, scheme, bound)?;
+        wit.check_consistency()?;
+
+        let v = evaluate_mles::<NTT, _, _, RelationError>(&wit.f_hat, &self.r)?;
+        if v != self.v {
+            return Err(RelationError::VMismatch);
+        }
+
+        let z = self.get_z_vector(&wit.w_ccs);
+        let Mz_mles = calculate_Mz_mles::<NTT, RelationError>(ccs, &z)?;
+        let u = evaluate_mles::<NTT, _, _, RelationError>(&Mz_mles, &self.r)?;
+        if u != self.u {
+            return Err(RelationError::UMismatch);
+        }
+
+        Ok(())
     }
 }
 
@@ -427,14 +558,17 @@ impl<const C: usize, R: Ring> Instance<R> for LCCCS<C, R> {
 pub mod tests {
     use ark_ff::{One, Zero};
     use cyclotomic_rings::rings::{
-        BabyBearRingNTT, GoldilocksRingNTT, GoldilocksRingPoly, StarkRingNTT,
+        BabyBearRingNTT, GoldilocksChallengeSet, GoldilocksRingNTT, GoldilocksRingPoly,
+        StarkRingNTT,
     };
     use stark_rings::cyclotomic_ring::models::goldilocks::{Fq, Fq3};
 
     use super::*;
     use crate::{
-        arith::r1cs::{get_test_r1cs, get_test_z as r1cs_get_test_z},
+        arith::r1cs::{get_test_r1cs, get_test_z as r1cs_get_test_z, get_test_z_split},
         decomposition_parameters::test_params::{BabyBearDP, GoldilocksDP, StarkDP},
+        nifs::linearization::{LFLinearizationProver, LinearizationProver},
+        transcript::poseidon::PoseidonTranscript,
     };
 
     pub(crate) fn get_test_ccs<R: Ring>(W: usize, L: usize) -> CCS<R> {
@@ -549,4 +683,122 @@ pub mod tests {
         assert!(recreated_witness.check_data::<StarkDP>());
         assert_eq!(recreated_witness, random_witness);
     }
+
+    #[test]
+    fn test_witness_norm() {
+        let wit = Witness::<GoldilocksRingNTT>::from_w_ccs::<GoldilocksDP>(vec![
+            GoldilocksRingNTT::from(3u64),
+            -GoldilocksRingNTT::from(5u64),
+        ]);
+
+        assert_eq!(wit.norm_inf(), BigUint::from(5u64));
+        wit.check_norm(6).unwrap();
+
+        let err = wit.check_norm(5).unwrap_err();
+        assert_eq!(err.index, GoldilocksDP::L);
+        assert_eq!(err.coeff_index, 0);
+        assert_eq!(err.value, BigUint::from(5u64));
+    }
+
+    const CM_LEN: usize = 4;
+    const AJTAI_WIT_LEN: usize = 4 * GoldilocksDP::L;
+
+    fn setup_cccs() -> (
+        CCCS<CM_LEN, GoldilocksRingNTT>,
+        Witness<GoldilocksRingNTT>,
+        CCS<GoldilocksRingNTT>,
+        AjtaiCommitmentScheme<CM_LEN, AJTAI_WIT_LEN, GoldilocksRingNTT>,
+    ) {
+        let ccs = get_test_ccs::<GoldilocksRingNTT>(AJTAI_WIT_LEN, GoldilocksDP::L);
+        let scheme = AjtaiCommitmentScheme::rand(&mut ark_std::test_rng());
+
+        let (_, x_ccs, w_ccs) = get_test_z_split::<GoldilocksRingNTT>(3);
+        let wit = Witness::from_w_ccs::<GoldilocksDP>(w_ccs);
+        let cccs = CCCS {
+            cm: wit.commit::<CM_LEN>(&scheme).unwrap(),
+            x_ccs,
+        };
+
+        (cccs, wit, ccs, scheme)
+    }
+
+    #[test]
+    fn test_cccs_check() {
+        let (cccs, wit, ccs, scheme) = setup_cccs();
+
+        cccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B)
+            .unwrap();
+
+        let mut bad_cccs = cccs.clone();
+        bad_cccs.x_ccs[0] += GoldilocksRingNTT::one();
+        assert!(matches!(
+            bad_cccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B),
+            Err(RelationError::ConstraintSystemError(Error::NotSatisfied))
+        ));
+
+        let mut bad_cccs = cccs.clone();
+        let mut cm = bad_cccs.cm.as_ref().to_vec();
+        cm[0] += GoldilocksRingNTT::one();
+        bad_cccs.cm = cm.try_into().unwrap();
+        assert!(matches!(
+            bad_cccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B),
+            Err(RelationError::CommitmentMismatch)
+        ));
+
+        let mut bad_wit = wit.clone();
+        bad_wit.w_ccs[0] += GoldilocksRingNTT::one();
+        assert!(matches!(
+            cccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&bad_wit, &ccs, &scheme, GoldilocksDP::B),
+            Err(RelationError::RecompositionMismatch)
+        ));
+
+        assert!(matches!(
+            cccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, 1),
+            Err(RelationError::NormBoundExceeded(NormError { bound: 1, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_lcccs_check() {
+        let (cccs, wit, ccs, scheme) = setup_cccs();
+
+        let (lcccs, _) = LFLinearizationProver::<
+            _,
+            PoseidonTranscript<GoldilocksRingNTT, GoldilocksChallengeSet>,
+        >::prove(&cccs, &wit, &mut PoseidonTranscript::default(), &ccs)
+        .unwrap();
+
+        lcccs
+            .check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B)
+            .unwrap();
+
+        let mut bad_lcccs = lcccs.clone();
+        bad_lcccs.v[0] += GoldilocksRingNTT::one();
+        assert!(matches!(
+            bad_lcccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B),
+            Err(RelationError::VMismatch)
+        ));
+
+        let mut bad_lcccs = lcccs.clone();
+        bad_lcccs.u[0] += GoldilocksRingNTT::one();
+        assert!(matches!(
+            bad_lcccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(&wit, &ccs, &scheme, GoldilocksDP::B),
+            Err(RelationError::UMismatch)
+        ));
+
+        // An f_hat that does not match f is rejected, even if v is its evaluation.
+        let mut bad_wit = wit.clone();
+        bad_wit.f_hat[0].evaluations[0] += GoldilocksRingNTT::one();
+        let mut bad_lcccs = lcccs.clone();
+        bad_lcccs.v = evaluate_mles::<_, _, _, RelationError>(&bad_wit.f_hat, &lcccs.r).unwrap();
+        assert!(matches!(
+            bad_lcccs.check::<AJTAI_WIT_LEN, GoldilocksDP>(
+                &bad_wit,
+                &ccs,
+                &scheme,
+                GoldilocksDP::B
+            ),
+            Err(RelationError::InconsistentWitness)
+        ));
+    }
 }
@@ -1,7 +1,8 @@
 //! Provides error functionality for constraint systems.
+use num_bigint::BigUint;
 use thiserror::Error;
 
-use crate::ark_base::*;
+use crate::{ark_base::*, commitment::CommitmentError, utils::mle_helpers::MleEvaluationError};
 
 /// Errors that can arise in constraint system calculations
 #[derive(Debug, Error)]
@@ -27,3 +28,57 @@ pub enum CSError {
     #[error("vectors {0} and {1} have different lengths: {0} and {1}")]
     LengthsNotEqual(String, String, usize, usize),
 }
+
+/// Errors that can arise when checking an instance against its witness
+#[derive(Debug, Error)]
+pub enum RelationError {
+    /// The witness does not open the commitment of the instance.
+    #[error("witness does not open the commitment")]
+    CommitmentMismatch,
+
+    /// A coefficient of the witness is not below the norm bound.
+    #[error("witness norm is not below the bound: {0}")]
+    NormBoundExceeded(#[from] NormError),
+
+    /// The CCS witness is not the gadget recomposition of the Ajtai witness.
+    #[error("CCS witness does not recompose from the Ajtai witness")]
+    RecompositionMismatch,
+
+    /// The coefficient representation or $\hat{f}$ of the witness are not derived from `f`.
+    #[error("f_coeff or f_hat of the witness do not match f")]
+    InconsistentWitness,
+
+    /// The evaluations of $\hat{f}$ at `r` do not match `v`.
+    #[error("f_hat evaluations do not match v")]
+    VMismatch,
+
+    /// The evaluations of the MLEs of $\{ M_j \mathbf{z} \}$ at `r` do not match `u`.
+    #[error("M_j z evaluations do not match u")]
+    UMismatch,
+
+    /// The witness does not satisfy the constraint system.
+    #[error("constraint system related error: {0}")]
+    ConstraintSystemError(#[from] CSError),
+
+    /// The witness has the wrong length for the commitment scheme.
+    #[error("ajtai commitment error: {0}")]
+    CommitmentError(#[from] CommitmentError),
+
+    /// The evaluation point has the wrong length.
+    #[error("mle evaluation failed: {0}")]
+    EvaluationError(#[from] MleEvaluationError),
+}
+
+/// A witness coefficient that is not below the norm bound
+#[derive(Debug, Error)]
+#[error("coefficient {coeff_index} of witness element {index} has absolute value {value}, expected below {bound}")]
+pub struct NormError {
+    /// The index of the offending ring element of the witness.
+    pub index: usize,
+    /// The index of the offending coefficient of the ring element.
+    pub coeff_index: usize,
+    /// The absolute value of the centered representative of the offending coefficient.
+    pub value: BigUint,
+    /// The norm bound.
+    pub bound: u128,
+}
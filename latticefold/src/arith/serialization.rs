@@ -0,0 +1,286 @@
+//! Canonical serialization of constraint systems and witnesses.
+//!
+//! [`SparseMatrix`] is defined outside of this crate, hence the [`CCS`] matrices are serialized field by field.
+//! A [`Witness`] is serialized as its CCS witness and its Ajtai witness `f`,
+//! the other fields are recomputed from `f` on deserialization.
+
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::log2;
+use cyclotomic_rings::rings::SuitableRing;
+use stark_rings::{cyclotomic_ring::ICRT, Ring};
+use stark_rings_linalg::SparseMatrix;
+
+use super::{Witness, CCS};
+use crate::ark_base::*;
+
+fn serialize_matrix<R: Ring, W: Write>(
+    M: &SparseMatrix<R>,
+    mut writer: W,
+    compress: Compress,
+) -> Result<(), SerializationError> {
+    M.n_rows.serialize_with_mode(&mut writer, compress)?;
+    M.n_cols.serialize_with_mode(&mut writer, compress)?;
+    M.coeffs.serialize_with_mode(&mut writer, compress)
+}
+
+fn matrix_serialized_size<R: Ring>(M: &SparseMatrix<R>, compress: Compress) -> usize {
+    M.n_rows.serialized_size(compress)
+        + M.n_cols.serialized_size(compress)
+        + M.coeffs.serialized_size(compress)
+}
+
+fn deserialize_matrix<R: Ring, Rd: Read>(
+    mut reader: Rd,
+    compress: Compress,
+    validate: Validate,
+) -> Result<SparseMatrix<R>, SerializationError> {
+    Ok(SparseMatrix {
+        n_rows: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+        n_cols: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+        coeffs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+    })
+}
+
+/// Checks that `M` has at most `m` rows, exactly `n` columns and no entries out of bounds.
+fn check_matrix<R: Ring>(
+    M: &SparseMatrix<R>,
+    m: usize,
+    n: usize,
+) -> Result<(), SerializationError> {
+    if M.n_rows > m
+        || M.n_cols != n
+        || M.coeffs.len() > M.n_rows
+        || M.coeffs.iter().flatten().any(|&(_, col)| col >= n)
+    {
+        return Err(SerializationError::InvalidData);
+    }
+
+    M.coeffs.check()
+}
+
+impl<R: Ring> CanonicalSerialize for CCS<R> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        for param in [
+            self.m,
+            self.n,
+            self.l,
+            self.t,
+            self.q,
+            self.d,
+            self.s,
+            self.s_prime,
+        ] {
+            param.serialize_with_mode(&mut writer, compress)?;
+        }
+
+        self.M.len().serialize_with_mode(&mut writer, compress)?;
+        for M in &self.M {
+            serialize_matrix(M, &mut writer, compress)?;
+        }
+
+        self.S.serialize_with_mode(&mut writer, compress)?;
+        self.c.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        8 * self.m.serialized_size(compress)
+            + self.M.len().serialized_size(compress)
+            + self
+                .M
+                .iter()
+                .map(|M| matrix_serialized_size(M, compress))
+                .sum::<usize>()
+            + self.S.serialized_size(compress)
+            + self.c.serialized_size(compress)
+    }
+}
+
+impl<R: Ring> Valid for CCS<R> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.M.len() != self.t
+            || self.S.len() != self.q
+            || self.c.len() != self.q
+            || self.l >= self.n
+            || self.s != log2(self.m) as usize
+            || self.s_prime != log2(self.n) as usize
+            || self
+                .S
+                .iter()
+                .any(|S_i| S_i.len() > self.d || S_i.iter().any(|&j| j >= self.t))
+        {
+            return Err(SerializationError::InvalidData);
+        }
+
+        for M in &self.M {
+            check_matrix(M, self.m, self.n)?;
+        }
+
+        self.c.check()
+    }
+}
+
+impl<R: Ring> CanonicalDeserialize for CCS<R> {
+    fn deserialize_with_mode<Rd: Read>(
+        mut reader: Rd,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut params = [0usize; 8];
+        for param in params.iter_mut() {
+            *param = usize::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        }
+        let [m, n, l, t, q, d, s, s_prime] = params;
+
+        let len = usize::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        if len != t {
+            return Err(SerializationError::InvalidData);
+        }
+        let M = (0..len)
+            .map(|_| deserialize_matrix(&mut reader, compress, Validate::No))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ccs = Self {
+            m,
+            n,
+            l,
+            t,
+            q,
+            d,
+            s,
+            s_prime,
+            M,
+            S: Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            c: Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+        };
+
+        if let Validate::Yes = validate {
+            ccs.check()?;
+        }
+
+        Ok(ccs)
+    }
+}
+
+impl<NTT: SuitableRing> CanonicalSerialize for Witness<NTT> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.w_ccs.serialize_with_mode(&mut writer, compress)?;
+        self.f.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.w_ccs.serialized_size(compress) + self.f.serialized_size(compress)
+    }
+}
+
+impl<NTT: SuitableRing> Valid for Witness<NTT> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Every CCS witness element is decomposed into the same amount of Ajtai witness elements.
+        if (!self.w_ccs.is_empty() && self.f.len() % self.w_ccs.len() != 0)
+            || self.f_coeff.len() != self.f.len()
+        {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.w_ccs.check()?;
+        self.f.check()
+    }
+}
+
+impl<NTT: SuitableRing> CanonicalDeserialize for Witness<NTT> {
+    fn deserialize_with_mode<Rd: Read>(
+        mut reader: Rd,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let w_ccs: Vec<NTT> = Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let f: Vec<NTT> = Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+
+        let f_coeff: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(f.clone());
+        let f_hat = Witness::<NTT>::get_fhat(&f_coeff);
+
+        let wit = Self {
+            w_ccs,
+            f,
+            f_coeff,
+            f_hat,
+        };
+
+        if let Validate::Yes = validate {
+            wit.check()?;
+        }
+
+        Ok(wit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{io::Cursor, test_rng};
+    use cyclotomic_rings::rings::GoldilocksRingNTT;
+    use num_traits::One;
+
+    use super::*;
+    use crate::{arith::tests::get_test_ccs, decomposition_parameters::test_params::GoldilocksDP};
+
+    fn round_trip<T: CanonicalSerialize + CanonicalDeserialize>(
+        value: &T,
+        compress: Compress,
+    ) -> Result<T, SerializationError> {
+        let mut bytes = Vec::new();
+        value.serialize_with_mode(&mut bytes, compress)?;
+        assert_eq!(bytes.len(), value.serialized_size(compress));
+
+        T::deserialize_with_mode(&mut Cursor::new(bytes), compress, Validate::Yes)
+    }
+
+    #[test]
+    fn test_ccs_serialization() {
+        let ccs = get_test_ccs::<GoldilocksRingNTT>(8, GoldilocksDP::L);
+
+        for compress in [Compress::Yes, Compress::No] {
+            assert_eq!(round_trip(&ccs, compress).unwrap(), ccs);
+        }
+    }
+
+    #[test]
+    fn test_ccs_serialization_rejects_invalid_multiset() {
+        let mut ccs = get_test_ccs::<GoldilocksRingNTT>(8, GoldilocksDP::L);
+        ccs.S[0][0] = ccs.t;
+
+        assert!(matches!(
+            round_trip(&ccs, Compress::Yes),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn test_ccs_serialization_rejects_out_of_bounds_entry() {
+        let mut ccs = get_test_ccs::<GoldilocksRingNTT>(8, GoldilocksDP::L);
+        ccs.M[0].coeffs[0].push((GoldilocksRingNTT::one(), ccs.n));
+
+        assert!(matches!(
+            round_trip(&ccs, Compress::Yes),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn test_witness_serialization() {
+        let wit = Witness::<GoldilocksRingNTT>::rand::<_, GoldilocksDP>(&mut test_rng(), 16);
+
+        for compress in [Compress::Yes, Compress::No] {
+            assert_eq!(round_trip(&wit, compress).unwrap(), wit);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Searches Ajtai and decomposition parameters, see [`latticefold::utils::param_search`].
+//!
+//! ```bash
+//! cargo run --release --bin param-search -- <ring> <witness length> [options]
+//! ```
+
+use std::{env, process};
+
+use cyclotomic_rings::rings::{BabyBearRingNTT, FrogRingNTT, GoldilocksRingNTT, StarkRingNTT};
+use latticefold::utils::{
+    param_search::{search, RankBy, RingInfo, SearchOptions},
+    security_check::{CostModel, SecurityLevel},
+};
+
+const USAGE: &str = "\
+Usage: param-search <ring> <witness length> [options]
+
+Rings: goldilocks, babybear, starkprime, frog
+
+Options:
+    --security <bits>     target security level, one of 80, 100, 128, 192 (default: 128)
+    --model <model>       cost model of the estimate, classical or quantum (default: classical)
+    --max-kappa <C>       largest number of rows of the Ajtai matrix (default: 32)
+    --max-l <L>           largest length of the radix-B decomposition (default: 8)
+    --b-small <b,...>     comma separated small bases (default: 2,3,4,8,16)
+    --rank <cost|size>    rank by prover cost or by proof size (default: cost)
+    --top <n>             print only the n best parameter sets
+    --format <toml|rust>  print entries of benches/config.toml or DecompositionParams impls (default: toml)";
+
+enum Format {
+    Toml,
+    Rust,
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}\n\n{USAGE}");
+    process::exit(1)
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: Option<String>) -> T {
+    value
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| fail(&format!("invalid value of {name}")))
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let (ring_name, ring) = match args.next().as_deref() {
+        Some("goldilocks") => ("Goldilocks", RingInfo::of::<GoldilocksRingNTT>()),
+        Some("babybear") => ("BabyBear", RingInfo::of::<BabyBearRingNTT>()),
+        Some("starkprime") => ("StarkPrime", RingInfo::of::<StarkRingNTT>()),
+        Some("frog") => ("Frog", RingInfo::of::<FrogRingNTT>()),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return;
+        }
+        Some(ring) => fail(&format!("unknown ring {ring}")),
+        None => fail("missing ring"),
+    };
+    let mut options = SearchOptions::new(parse("witness length", args.next()));
+    let mut top = usize::MAX;
+    let mut format = Format::Toml;
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--security" => {
+                let bits = parse(&option, args.next());
+                options.level = SecurityLevel::from_bits(bits).unwrap_or_else(|| {
+                    fail(&format!("security level of {bits} bits is not supported"))
+                })
+            }
+            "--model" => {
+                options.model = match args.next().as_deref() {
+                    Some("classical") => CostModel::ClassicalCoreSvp,
+                    Some("quantum") => CostModel::QuantumCoreSvp,
+                    _ => fail("invalid value of --model"),
+                }
+            }
+            "--max-kappa" => options.max_kappa = parse(&option, args.next()),
+            "--max-l" => options.max_l = parse(&option, args.next()),
+            "--b-small" => {
+                options.b_small = parse::<String>(&option, args.next())
+                    .split(',')
+                    .map(|b| parse(&option, Some(b.to_string())))
+                    .collect()
+            }
+            "--rank" => {
+                options.rank_by = match args.next().as_deref() {
+                    Some("cost") => RankBy::ProverCost,
+                    Some("size") => RankBy::ProofSize,
+                    _ => fail("invalid value of --rank"),
+                }
+            }
+            "--top" => top = parse(&option, args.next()),
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("toml") => Format::Toml,
+                    Some("rust") => Format::Rust,
+                    _ => fail("invalid value of --format"),
+                }
+            }
+            _ => fail(&format!("unknown option {option}")),
+        }
+    }
+
+    let sets = search(&ring, &options).unwrap_or_else(|err| fail(&err.to_string()));
+    if sets.is_empty() {
+        fail("no parameter set found, try increasing --max-kappa or --max-l");
+    }
+
+    for (i, set) in sets.iter().take(top).enumerate() {
+        match format {
+            Format::Toml => println!(
+                "{}, # proof size ~ {} B, prover cost ~ {} ring multiplications",
+                set.to_toml(),
+                set.proof_size,
+                set.prover_cost
+            ),
+            Format::Rust => println!(
+                "// proof size ~ {} B, prover cost ~ {} ring multiplications\n{}",
+                set.proof_size,
+                set.prover_cost,
+                set.to_decomposition_params(&format!("{ring_name}DP{i}"))
+            ),
+        }
+    }
+}
@@ -1,14 +1,33 @@
 //! Provides utility for committing to witnesses.
 
+use ark_serialize::CanonicalSerialize;
+use cyclotomic_rings::rings::SuitableRing;
 use thiserror::Error;
 
 mod commitment_scheme;
+mod dyn_commitment_scheme;
 mod homomorphic_commitment;
 #[macro_use]
 mod operations;
 pub use commitment_scheme::*;
+pub use dyn_commitment_scheme::*;
 pub use homomorphic_commitment::*;
 
+/// An Ajtai commitment key producing commitments of length `C`.
+///
+/// The NIFS is generic over this trait, so it runs both with [`AjtaiCommitmentScheme`],
+/// whose dimensions are const generics, and with [`DynAjtaiCommitmentScheme`],
+/// whose witness length is chosen at runtime.
+/// The commitment length `C` stays a const generic of the NIFS, it is fixed by the security level,
+/// a [`DynAjtaiCommitmentScheme`] only commits when its number of rows equals `C`.
+pub trait AjtaiCommitmentKey<const C: usize, NTT: SuitableRing>: CanonicalSerialize + Sync {
+    /// Returns the maximal length of the witnesses, i.e. the number of columns of the Ajtai matrix.
+    fn witness_len(&self) -> usize;
+
+    /// Commit to a witness in the NTT form.
+    fn commit_ntt(&self, f: &[NTT]) -> Result<Commitment<C, NTT>, CommitmentError>;
+}
+
 /// Errors that can occur in commitment operations.
 #[derive(Debug, Error)]
 pub enum CommitmentError {
@@ -23,4 +42,13 @@ pub enum CommitmentError {
     /// An Ajtai matrix should have size commitment_length x witness_length.
     #[error("Ajtai matrix has dimensions: {0}x{1}, expected: {2}x{3}")]
     WrongAjtaiMatrixDimensions(usize, usize, usize, usize),
+    /// A witness update refers to a position outside of the witness.
+    #[error("Witness index {0} is out of bounds for the witness length {1}")]
+    WitnessIndexOutOfBounds(usize, usize),
+    /// The opening of a commitment has an infinity norm not below the binding bound.
+    #[error("Opening norm bound exceeded")]
+    NormBoundExceeded,
+    /// The opening does not commit to the given commitment.
+    #[error("Opening does not match the commitment")]
+    OpeningMismatch,
 }
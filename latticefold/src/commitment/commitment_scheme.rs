@@ -1,13 +1,23 @@
-use cyclotomic_rings::rings::SuitableRing;
+use ark_crypto_primitives::sponge::{poseidon::PoseidonSponge, CryptographicSponge};
+use ark_ff::{Field, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use cyclotomic_rings::{
+    norm::vec_norm_inf,
+    rings::{GetPoseidonParams, SuitableRing},
+};
+use num_bigint::BigUint;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use stark_rings::{
     balanced_decomposition::decompose_balanced_vec,
     cyclotomic_ring::{CRT, ICRT},
-    OverField,
+    OverField, PolyRing,
 };
 
-use super::homomorphic_commitment::Commitment;
+use super::{homomorphic_commitment::Commitment, AjtaiCommitmentKey};
 use crate::{
     ark_base::*, commitment::CommitmentError, decomposition_parameters::DecompositionParams,
 };
@@ -54,16 +64,129 @@ impl<const C: usize, const W: usize, NTT: OverField> TryFrom<Vec<Vec<NTT>>>
     }
 }
 
+impl<const C: usize, const W: usize, NTT: OverField> CanonicalSerialize
+    for AjtaiCommitmentScheme<C, W, NTT>
+{
+    fn serialize_with_mode<Wr: Write>(
+        &self,
+        writer: Wr,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.matrix.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.matrix.serialized_size(compress)
+    }
+}
+
+impl<const C: usize, const W: usize, NTT: OverField> Valid for AjtaiCommitmentScheme<C, W, NTT> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.matrix.len() != C || self.matrix.iter().any(|row| row.len() != W) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.matrix.check()
+    }
+}
+
+impl<const C: usize, const W: usize, NTT: OverField> CanonicalDeserialize
+    for AjtaiCommitmentScheme<C, W, NTT>
+{
+    fn deserialize_with_mode<Rd: Read>(
+        reader: Rd,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let scheme = Self {
+            matrix: Vec::deserialize_with_mode(reader, compress, Validate::No)?,
+        };
+
+        if let Validate::Yes = validate {
+            scheme.check()?;
+        }
+
+        Ok(scheme)
+    }
+}
+
 impl<const C: usize, const W: usize, NTT: OverField> AjtaiCommitmentScheme<C, W, NTT> {
     /// Returns a random Ajtai commitment matrix
     pub fn rand<Rng: rand::Rng + ?Sized>(rng: &mut Rng) -> Self {
         Self {
-            matrix: vec![vec![NTT::rand(rng); W]; C],
+            matrix: (0..C)
+                .map(|_| (0..W).map(|_| NTT::rand(rng)).collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the rows of the Ajtai matrix.
+    pub(crate) fn matrix(&self) -> &[Vec<NTT>] {
+        &self.matrix
+    }
+
+    /// Updates the commitment `cm` to a witness in the NTT form after some of its entries changed.
+    ///
+    /// Every change `(j, old, new)` replaces `f[j] = old` by `new`.
+    /// Since the Ajtai map is linear, `cm` is shifted by the `j`-th column of the matrix times `new - old`,
+    /// so the cost depends on the number of changes rather than on `W`.
+    pub fn update_commitment(
+        &self,
+        cm: &Commitment<C, NTT>,
+        changes: &[(usize, NTT, NTT)],
+    ) -> Result<Commitment<C, NTT>, CommitmentError> {
+        if let Some(&(j, _, _)) = changes.iter().find(|(j, _, _)| *j >= W) {
+            return Err(CommitmentError::WitnessIndexOutOfBounds(j, W));
         }
+
+        let deltas: Vec<(usize, NTT)> = changes
+            .iter()
+            .map(|(j, old, new)| (*j, *new - *old))
+            .collect();
+
+        self.shift_commitment(cm, &deltas)
+    }
+
+    /// Adds `sum_j A_j * delta_j` to `cm` where `A_j` is the `j`-th column of the matrix.
+    fn shift_commitment(
+        &self,
+        cm: &Commitment<C, NTT>,
+        deltas: &[(usize, NTT)],
+    ) -> Result<Commitment<C, NTT>, CommitmentError> {
+        if cm.as_ref().len() != C {
+            return Err(CommitmentError::WrongCommitmentLength(cm.as_ref().len(), C));
+        }
+
+        let commitment: Vec<NTT> = self
+            .matrix
+            .iter()
+            .zip(cm.as_ref())
+            .map(|(row, cm_i)| {
+                deltas
+                    .iter()
+                    .fold(*cm_i, |acc, (j, delta)| acc + row[*j] * delta)
+            })
+            .collect();
+
+        Ok(Commitment::from_vec_raw(commitment))
     }
 }
 
+/// Domain separator absorbed before the seed in [`AjtaiCommitmentScheme::from_seed`].
+pub const AJTAI_SEED_DOMAIN: &[u8] = b"LatticeFold Ajtai matrix v1";
+
 impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentScheme<C, W, NTT> {
+    /// Deterministically expands a public `seed` into an Ajtai commitment matrix.
+    ///
+    /// The Poseidon sponge of the ring absorbs [`AJTAI_SEED_DOMAIN`], the seed and the dimensions `C` and `W`,
+    /// then every entry of the matrix is squeezed, row by row, as `NTT::dimension()` independent uniform elements of `NTT::BaseRing`.
+    /// Hence anyone holding the seed can recompute and audit the matrix.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self {
+            matrix: expand_seed::<NTT>(seed, C, W),
+        }
+    }
+
     /// Commit to a witness in the NTT form.
     /// The most basic one just multiplies by the matrix.
     pub fn commit_ntt(&self, f: &[NTT]) -> Result<Commitment<C, NTT>, CommitmentError> {
@@ -83,8 +206,11 @@ impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentScheme<C,
     }
 
     /// Commit to a witness in the coefficient form.
-    /// Performs NTT on each component of the witness and then does Ajtai commitment.
-    pub fn commit_coeff<P: DecompositionParams>(
+    ///
+    /// Zero entries are skipped altogether and constant entries are lifted to the NTT form without a CRT,
+    /// so only the remaining entries are transformed and multiplied by the matrix.
+    /// The result is the same as of [`Self::commit_ntt`] on the CRT of `f`.
+    pub fn commit_coeff(
         &self,
         f: Vec<NTT::CoefficientRepresentation>,
     ) -> Result<Commitment<C, NTT>, CommitmentError> {
@@ -92,7 +218,56 @@ impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentScheme<C,
             return Err(CommitmentError::WrongWitnessLength(f.len(), W));
         }
 
-        self.commit_ntt(&CRT::elementwise_crt(f))
+        Ok(Commitment::from_vec_raw(mul_sparse_coeff(&self.matrix, &f)))
+    }
+
+    /// Checks that the NTT form witness `f` is a valid opening of `cm`,
+    /// i.e. `f` has length `W`, `||f||_inf < bound` and `f` commits to `cm`.
+    ///
+    /// The Ajtai commitment is only binding for openings of norm below `bound`, e.g. `P::B`.
+    pub fn verify_opening(
+        &self,
+        cm: &Commitment<C, NTT>,
+        f: &[NTT],
+        bound: &BigUint,
+    ) -> Result<(), CommitmentError> {
+        if f.len() != W {
+            return Err(CommitmentError::WrongWitnessLength(f.len(), W));
+        }
+
+        let f_coeff: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(f.to_vec());
+        if vec_norm_inf(&f_coeff) >= *bound {
+            return Err(CommitmentError::NormBoundExceeded);
+        }
+
+        if self.commit_ntt(f)? != *cm {
+            return Err(CommitmentError::OpeningMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the coefficient form witness `f` is a valid opening of `cm`,
+    /// see [`Self::verify_opening`].
+    pub fn verify_opening_coeff(
+        &self,
+        cm: &Commitment<C, NTT>,
+        f: &[NTT::CoefficientRepresentation],
+        bound: &BigUint,
+    ) -> Result<(), CommitmentError> {
+        if f.len() != W {
+            return Err(CommitmentError::WrongWitnessLength(f.len(), W));
+        }
+
+        if vec_norm_inf(f) >= *bound {
+            return Err(CommitmentError::NormBoundExceeded);
+        }
+
+        if Commitment::from_vec_raw(mul_sparse_coeff(&self.matrix, f)) != *cm {
+            return Err(CommitmentError::OpeningMismatch);
+        }
+
+        Ok(())
     }
 
     /// Takes a coefficient form witness, decomposes it vertically in radix-B,
@@ -106,7 +281,43 @@ impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentScheme<C,
             .flatten()
             .collect::<Vec<_>>();
 
-        self.commit_coeff::<P>(f)
+        self.commit_coeff(f)
+    }
+
+    /// Updates the commitment `cm` computed by [`Self::decompose_and_commit_coeff`]
+    /// after some entries of the coefficient form witness changed.
+    ///
+    /// Every change `(j, old, new)` replaces `f[j] = old` by `new`.
+    /// Only the two touched entries are decomposed in radix-B again,
+    /// and the columns `j * P::L..(j + 1) * P::L` are used to shift `cm` by the differences of the digits.
+    pub fn update_commitment_coeff<P: DecompositionParams>(
+        &self,
+        cm: &Commitment<C, NTT>,
+        changes: &[(
+            usize,
+            NTT::CoefficientRepresentation,
+            NTT::CoefficientRepresentation,
+        )],
+    ) -> Result<Commitment<C, NTT>, CommitmentError> {
+        let mut deltas = Vec::with_capacity(changes.len() * P::L);
+
+        for (j, old, new) in changes {
+            if j.checked_add(1)
+                .and_then(|end| end.checked_mul(P::L))
+                .is_none_or(|end| end > W)
+            {
+                return Err(CommitmentError::WitnessIndexOutOfBounds(*j, W / P::L));
+            }
+
+            let digits = decompose_balanced_vec(&[*old, *new], P::B, P::L);
+            for (i, (old_i, new_i)) in digits[0].iter().zip(&digits[1]).enumerate() {
+                if old_i != new_i {
+                    deltas.push((j * P::L + i, CRT::crt(*new_i - *old_i)));
+                }
+            }
+        }
+
+        self.shift_commitment(cm, &deltas)
     }
 
     /// Takes an NTT form witness, transforms it into the coefficient form,
@@ -122,13 +333,101 @@ impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentScheme<C,
     }
 }
 
+impl<const C: usize, const W: usize, NTT: SuitableRing> AjtaiCommitmentKey<C, NTT>
+    for AjtaiCommitmentScheme<C, W, NTT>
+{
+    fn witness_len(&self) -> usize {
+        W
+    }
+
+    fn commit_ntt(&self, f: &[NTT]) -> Result<Commitment<C, NTT>, CommitmentError> {
+        AjtaiCommitmentScheme::commit_ntt(self, f)
+    }
+}
+
+/// Computes the product of the Ajtai `matrix` and a coefficient form witness `f`,
+/// see [`AjtaiCommitmentScheme::commit_coeff`].
+/// The missing trailing entries of `f` are treated as zeros.
+pub(super) fn mul_sparse_coeff<NTT: SuitableRing>(
+    matrix: &[Vec<NTT>],
+    f: &[NTT::CoefficientRepresentation],
+) -> Vec<NTT> {
+    let columns: Vec<(usize, NTT)> = cfg_iter!(f)
+        .enumerate()
+        .filter_map(|(j, f_j)| {
+            let coeffs = f_j.coeffs();
+            if coeffs[1..].iter().any(|c| !c.is_zero()) {
+                Some((j, CRT::crt(*f_j)))
+            } else if coeffs[0].is_zero() {
+                None
+            } else {
+                // A constant polynomial takes the same value in every NTT slot.
+                let c = NTT::BaseRing::from_base_prime_field(coeffs[0]);
+                Some((j, NTT::from(vec![c; NTT::dimension()])))
+            }
+        })
+        .collect();
+
+    cfg_iter!(matrix)
+        .map(|row| {
+            columns
+                .iter()
+                .fold(NTT::zero(), |acc, (j, f_j)| acc + row[*j] * f_j)
+        })
+        .collect()
+}
+
+/// Expands `seed` into a `rows x cols` Ajtai matrix, see [`AjtaiCommitmentScheme::from_seed`].
+pub(super) fn expand_seed<NTT: SuitableRing>(
+    seed: &[u8; 32],
+    rows: usize,
+    cols: usize,
+) -> Vec<Vec<NTT>> {
+    let mut sponge = PoseidonSponge::new(&NTT::PoseidonParams::get_poseidon_config());
+    sponge.absorb(&AJTAI_SEED_DOMAIN);
+    sponge.absorb(&seed.as_slice());
+    sponge.absorb(&(rows as u64));
+    sponge.absorb(&(cols as u64));
+
+    let extension_degree = NTT::BaseRing::extension_degree() as usize;
+    let mut matrix = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let mut row = Vec::with_capacity(cols);
+        for _ in 0..cols {
+            let elems = sponge.squeeze_field_elements::<<NTT::BaseRing as Field>::BasePrimeField>(
+                NTT::dimension() * extension_degree,
+            );
+            let coeffs: Vec<NTT::BaseRing> = elems
+                .chunks(extension_degree)
+                .map(|c| {
+                    <NTT::BaseRing as Field>::from_base_prime_field_elems(c)
+                        .expect("chunks contain extension_degree elements")
+                })
+                .collect();
+            row.push(NTT::from(coeffs));
+        }
+        matrix.push(row);
+    }
+
+    matrix
+}
+
 #[cfg(test)]
 mod tests {
-    use cyclotomic_rings::rings::GoldilocksRingNTT;
-    use stark_rings::OverField;
+    use ark_ff::{One, Zero};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+    use ark_std::{test_rng, UniformRand};
+    use cyclotomic_rings::rings::{GoldilocksRingNTT, GoldilocksRingPoly};
+    use num_bigint::BigUint;
+    use stark_rings::{
+        cyclotomic_ring::{models::goldilocks::Fq, CRT, ICRT},
+        OverField, PolyRing,
+    };
 
     use super::{AjtaiCommitmentScheme, CommitmentError};
-    use crate::ark_base::*;
+    use crate::{
+        ark_base::*, commitment::Commitment, decomposition_parameters::test_params::GoldilocksDP,
+    };
 
     pub(crate) fn generate_ajtai<const C: usize, const W: usize, NTT: OverField>(
     ) -> Result<AjtaiCommitmentScheme<C, W, NTT>, CommitmentError> {
@@ -164,4 +463,207 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_commit_coeff() -> Result<(), CommitmentError> {
+        let mut rng = test_rng();
+        let ajtai_data = AjtaiCommitmentScheme::<4, 16, GoldilocksRingNTT>::rand(&mut rng);
+
+        // Zero, constant and generic entries with coefficients in {-1, 0, 1}.
+        let witness: Vec<GoldilocksRingPoly> = (0..16)
+            .map(|j| {
+                let mut coeffs = vec![Fq::zero(); GoldilocksRingPoly::dimension()];
+                match j % 3 {
+                    0 => {}
+                    1 => coeffs[0] = -Fq::one(),
+                    _ => {
+                        for (i, c) in coeffs.iter_mut().enumerate() {
+                            *c = Fq::from((i + j) as i64 % 3 - 1);
+                        }
+                    }
+                }
+                GoldilocksRingPoly::from(coeffs)
+            })
+            .collect();
+        let expected = ajtai_data.commit_ntt(&CRT::elementwise_crt(witness.clone()))?;
+
+        assert_eq!(ajtai_data.commit_coeff(witness)?, expected);
+
+        // A dense witness with large coefficients.
+        let witness: Vec<GoldilocksRingPoly> = (0..16)
+            .map(|_| GoldilocksRingPoly::rand(&mut rng))
+            .collect();
+        let expected = ajtai_data.commit_ntt(&CRT::elementwise_crt(witness.clone()))?;
+
+        assert_eq!(ajtai_data.commit_coeff(witness)?, expected);
+
+        assert!(matches!(
+            ajtai_data.commit_coeff(vec![]),
+            Err(CommitmentError::WrongWitnessLength(0, 16))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_commitment() -> Result<(), CommitmentError> {
+        let mut rng = test_rng();
+        let ajtai_data = AjtaiCommitmentScheme::<4, 16, GoldilocksRingNTT>::rand(&mut rng);
+        let mut witness: Vec<GoldilocksRingNTT> =
+            (0..16).map(|_| GoldilocksRingNTT::rand(&mut rng)).collect();
+        let committed = ajtai_data.commit_ntt(&witness)?;
+
+        let changes: Vec<_> = [3, 11, 3]
+            .into_iter()
+            .map(|j| {
+                let new = GoldilocksRingNTT::rand(&mut rng);
+                let change = (j, witness[j], new);
+                witness[j] = new;
+                change
+            })
+            .collect();
+
+        assert_eq!(
+            ajtai_data.update_commitment(&committed, &changes)?,
+            ajtai_data.commit_ntt(&witness)?
+        );
+        assert_eq!(ajtai_data.update_commitment(&committed, &[])?, committed);
+        assert!(matches!(
+            ajtai_data.update_commitment(&committed, &[(16, witness[0], witness[1])]),
+            Err(CommitmentError::WitnessIndexOutOfBounds(16, 16))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_commitment_coeff() -> Result<(), CommitmentError> {
+        const W: usize = 4 * GoldilocksDP::L;
+
+        let mut rng = test_rng();
+        let ajtai_data = AjtaiCommitmentScheme::<4, W, GoldilocksRingNTT>::rand(&mut rng);
+        let mut witness: Vec<GoldilocksRingPoly> =
+            (0..4).map(|_| GoldilocksRingPoly::rand(&mut rng)).collect();
+        let committed = ajtai_data.decompose_and_commit_coeff::<GoldilocksDP>(&witness)?;
+
+        let new = GoldilocksRingPoly::rand(&mut rng);
+        let changes = [(2, witness[2], new)];
+        witness[2] = new;
+
+        assert_eq!(
+            ajtai_data.update_commitment_coeff::<GoldilocksDP>(&committed, &changes)?,
+            ajtai_data.decompose_and_commit_coeff::<GoldilocksDP>(&witness)?
+        );
+        assert!(matches!(
+            ajtai_data.update_commitment_coeff::<GoldilocksDP>(&committed, &[(4, new, new)]),
+            Err(CommitmentError::WitnessIndexOutOfBounds(4, 4))
+        ));
+        // (j + 1) * L overflows for this index.
+        let j = usize::MAX / GoldilocksDP::L + 1;
+        assert!(matches!(
+            ajtai_data.update_commitment_coeff::<GoldilocksDP>(&committed, &[(j, new, new)]),
+            Err(CommitmentError::WitnessIndexOutOfBounds(i, 4)) if i == j
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_opening() -> Result<(), CommitmentError> {
+        let mut rng = test_rng();
+        let ajtai_data = AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::rand(&mut rng);
+        let witness: Vec<GoldilocksRingNTT> = (0..8u128).map(GoldilocksRingNTT::from).collect();
+        let committed = ajtai_data.commit_ntt(&witness)?;
+        let bound = BigUint::from(GoldilocksDP::B);
+
+        ajtai_data.verify_opening(&committed, &witness, &bound)?;
+        let witness_coeff: Vec<GoldilocksRingPoly> = ICRT::elementwise_icrt(witness.clone());
+        ajtai_data.verify_opening_coeff(&committed, &witness_coeff, &bound)?;
+
+        assert!(matches!(
+            ajtai_data.verify_opening(&committed, &witness[1..], &bound),
+            Err(CommitmentError::WrongWitnessLength(7, 8))
+        ));
+        assert!(matches!(
+            ajtai_data.verify_opening(&committed, &witness, &BigUint::from(7u32)),
+            Err(CommitmentError::NormBoundExceeded)
+        ));
+
+        let mut other = witness.clone();
+        other.swap(0, 1);
+        assert!(matches!(
+            ajtai_data.verify_opening(&committed, &other, &bound),
+            Err(CommitmentError::OpeningMismatch)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_seed() {
+        let scheme = AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::from_seed(&[7; 32]);
+        let matrix = scheme.matrix();
+
+        assert_eq!(matrix.len(), 4);
+        assert!(matrix.iter().all(|row| row.len() == 8));
+        assert_eq!(
+            AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::from_seed(&[7; 32]).matrix(),
+            matrix
+        );
+        assert_ne!(
+            AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::from_seed(&[8; 32]).matrix(),
+            matrix
+        );
+        // The dimensions are absorbed too, so a smaller matrix is not a submatrix of a larger one.
+        assert_ne!(
+            AjtaiCommitmentScheme::<3, 8, GoldilocksRingNTT>::from_seed(&[7; 32]).matrix()[0],
+            matrix[0]
+        );
+
+        let entries: Vec<_> = matrix.iter().flatten().collect();
+        for (i, a) in entries.iter().enumerate() {
+            assert!(entries[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn test_rand_entries_are_independent() {
+        let scheme = AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::rand(&mut test_rng());
+
+        let entries: Vec<_> = scheme.matrix().iter().flatten().collect();
+        for (i, a) in entries.iter().enumerate() {
+            assert!(entries[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn test_serialization() -> Result<(), CommitmentError> {
+        let ajtai_data: AjtaiCommitmentScheme<4, 8, GoldilocksRingNTT> = generate_ajtai()?;
+        let witness: Vec<_> = (0..8).map(|i| (i as u128).into()).collect();
+        let committed = ajtai_data.commit_ntt(&witness)?;
+
+        let mut bytes = Vec::new();
+        ajtai_data.serialize_compressed(&mut bytes).unwrap();
+        let deserialized =
+            AjtaiCommitmentScheme::<4, 8, GoldilocksRingNTT>::deserialize_compressed(&bytes[..])
+                .unwrap();
+        assert_eq!(deserialized.commit_ntt(&witness)?, committed);
+        assert!(matches!(
+            AjtaiCommitmentScheme::<4, 16, GoldilocksRingNTT>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::InvalidData)
+        ));
+
+        let mut bytes = Vec::new();
+        committed.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            Commitment::<4, GoldilocksRingNTT>::deserialize_compressed(&bytes[..]).unwrap(),
+            committed
+        );
+        assert!(matches!(
+            Commitment::<3, GoldilocksRingNTT>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::InvalidData)
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,389 @@
+//! Ajtai commitments with dimensions chosen at runtime.
+//!
+//! [`AjtaiCommitmentScheme`] and [`Commitment`] fix their dimensions as const generics.
+//! [`DynAjtaiCommitmentScheme`] and [`DynCommitment`] are their counterparts for circuits
+//! whose size is only known at runtime, the dimensions are chosen at setup and validated on use.
+//! Both implement [`AjtaiCommitmentKey`], so the NIFS runs with either of them.
+//! A [`DynAjtaiCommitmentScheme`] also converts into the const generic type once the dimensions are fixed.
+
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use cyclotomic_rings::rings::SuitableRing;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use stark_rings::{balanced_decomposition::decompose_balanced_vec, OverField, Ring};
+
+use super::{
+    commitment_scheme::{expand_seed, mul_sparse_coeff},
+    AjtaiCommitmentKey, AjtaiCommitmentScheme, Commitment, CommitmentError,
+};
+use crate::{ark_base::*, decomposition_parameters::DecompositionParams};
+
+/// An Ajtai commitment with its length chosen at runtime.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DynCommitment<R: Ring> {
+    val: Vec<R>,
+}
+
+impl<R: Ring> DynCommitment<R> {
+    /// Returns the length of the commitment.
+    pub fn len(&self) -> usize {
+        self.val.len()
+    }
+
+    /// Returns `true` if the commitment has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.val.is_empty()
+    }
+}
+
+impl<R: Ring> From<Vec<R>> for DynCommitment<R> {
+    fn from(val: Vec<R>) -> Self {
+        Self { val }
+    }
+}
+
+impl<R: Ring> AsRef<[R]> for DynCommitment<R> {
+    fn as_ref(&self) -> &[R] {
+        &self.val
+    }
+}
+
+impl<const C: usize, R: Ring> From<Commitment<C, R>> for DynCommitment<R> {
+    fn from(cm: Commitment<C, R>) -> Self {
+        Self {
+            val: cm.as_ref().to_vec(),
+        }
+    }
+}
+
+impl<const C: usize, R: Ring> TryFrom<DynCommitment<R>> for Commitment<C, R> {
+    type Error = CommitmentError;
+
+    fn try_from(cm: DynCommitment<R>) -> Result<Self, Self::Error> {
+        Self::try_from(cm.val)
+    }
+}
+
+/// An Ajtai commitment scheme with its dimensions chosen at runtime.
+///
+/// Witnesses shorter than [`Self::witness_len`] are committed as if they were zero-padded,
+/// so a single key serves every circuit up to that size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynAjtaiCommitmentScheme<NTT: OverField> {
+    matrix: Vec<Vec<NTT>>,
+    witness_len: usize,
+}
+
+impl<NTT: OverField> DynAjtaiCommitmentScheme<NTT> {
+    /// Creates a scheme from the rows of an Ajtai matrix.
+    /// All rows must have the length `witness_len`.
+    pub fn new(matrix: Vec<Vec<NTT>>, witness_len: usize) -> Result<Self, CommitmentError> {
+        if let Some(row) = matrix.iter().find(|row| row.len() != witness_len) {
+            return Err(CommitmentError::WrongAjtaiMatrixDimensions(
+                matrix.len(),
+                row.len(),
+                matrix.len(),
+                witness_len,
+            ));
+        }
+
+        Ok(Self {
+            matrix,
+            witness_len,
+        })
+    }
+
+    /// Returns a random `rows x witness_len` Ajtai commitment matrix.
+    pub fn rand<Rng: rand::Rng + ?Sized>(rng: &mut Rng, rows: usize, witness_len: usize) -> Self {
+        Self {
+            matrix: (0..rows)
+                .map(|_| (0..witness_len).map(|_| NTT::rand(rng)).collect())
+                .collect(),
+            witness_len,
+        }
+    }
+
+    /// Returns the length of the commitments, i.e. the number of rows of the Ajtai matrix.
+    pub fn rows(&self) -> usize {
+        self.matrix.len()
+    }
+
+    /// Returns the maximal length of the witnesses, i.e. the number of columns of the Ajtai matrix.
+    pub fn witness_len(&self) -> usize {
+        self.witness_len
+    }
+
+    /// Commit to a witness in the NTT form, zero-padded to [`Self::witness_len`].
+    pub fn commit_ntt(&self, f: &[NTT]) -> Result<DynCommitment<NTT>, CommitmentError> {
+        if f.len() > self.witness_len {
+            return Err(CommitmentError::WrongWitnessLength(
+                f.len(),
+                self.witness_len,
+            ));
+        }
+
+        let commitment: Vec<NTT> = cfg_iter!(self.matrix)
+            .map(|row| {
+                row.iter()
+                    .zip(f.iter())
+                    .fold(NTT::zero(), |acc, (row_j, f_j)| acc + *row_j * f_j)
+            })
+            .collect();
+
+        Ok(DynCommitment::from(commitment))
+    }
+
+    /// Returns the scheme with the first `W` columns of the matrix.
+    ///
+    /// Committing to a witness of length `W` with the result is the same as
+    /// committing to it with `self`, hence a larger key can be shared by smaller circuits.
+    pub fn to_static<const C: usize, const W: usize>(
+        &self,
+    ) -> Result<AjtaiCommitmentScheme<C, W, NTT>, CommitmentError> {
+        if self.rows() != C || self.witness_len < W {
+            return Err(CommitmentError::WrongAjtaiMatrixDimensions(
+                self.rows(),
+                self.witness_len,
+                C,
+                W,
+            ));
+        }
+
+        AjtaiCommitmentScheme::try_from(
+            self.matrix
+                .iter()
+                .map(|row| row[..W].to_vec())
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<NTT: SuitableRing> DynAjtaiCommitmentScheme<NTT> {
+    /// Deterministically expands a public `seed` into a `rows x witness_len` Ajtai commitment matrix.
+    ///
+    /// The matrix is the same as of [`AjtaiCommitmentScheme::from_seed`] with `C = rows` and `W = witness_len`.
+    pub fn from_seed(seed: &[u8; 32], rows: usize, witness_len: usize) -> Self {
+        Self {
+            matrix: expand_seed::<NTT>(seed, rows, witness_len),
+            witness_len,
+        }
+    }
+
+    /// Commit to a witness in the coefficient form, zero-padded to [`Self::witness_len`].
+    pub fn commit_coeff(
+        &self,
+        f: Vec<NTT::CoefficientRepresentation>,
+    ) -> Result<DynCommitment<NTT>, CommitmentError> {
+        if f.len() > self.witness_len {
+            return Err(CommitmentError::WrongWitnessLength(
+                f.len(),
+                self.witness_len,
+            ));
+        }
+
+        Ok(DynCommitment::from(mul_sparse_coeff(&self.matrix, &f)))
+    }
+
+    /// Takes a coefficient form witness, decomposes it vertically in radix-B,
+    /// and Ajtai commits to the result zero-padded to [`Self::witness_len`].
+    pub fn decompose_and_commit_coeff<P: DecompositionParams>(
+        &self,
+        f: &[NTT::CoefficientRepresentation],
+    ) -> Result<DynCommitment<NTT>, CommitmentError> {
+        let f = decompose_balanced_vec(f, P::B, P::L)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        if f.len() > self.witness_len {
+            return Err(CommitmentError::WrongWitnessLength(
+                f.len(),
+                self.witness_len,
+            ));
+        }
+
+        Ok(DynCommitment::from(mul_sparse_coeff(&self.matrix, &f)))
+    }
+}
+
+/// Commits to witnesses of any length up to [`DynAjtaiCommitmentScheme::witness_len`],
+/// which lets the NIFS fold circuits smaller than the key.
+impl<const C: usize, NTT: SuitableRing> AjtaiCommitmentKey<C, NTT>
+    for DynAjtaiCommitmentScheme<NTT>
+{
+    fn witness_len(&self) -> usize {
+        self.witness_len
+    }
+
+    fn commit_ntt(&self, f: &[NTT]) -> Result<Commitment<C, NTT>, CommitmentError> {
+        if self.rows() != C {
+            return Err(CommitmentError::WrongAjtaiMatrixDimensions(
+                self.rows(),
+                self.witness_len,
+                C,
+                self.witness_len,
+            ));
+        }
+
+        Commitment::try_from(DynAjtaiCommitmentScheme::commit_ntt(self, f)?)
+    }
+}
+
+impl<NTT: OverField> CanonicalSerialize for DynAjtaiCommitmentScheme<NTT> {
+    fn serialize_with_mode<Wr: Write>(
+        &self,
+        mut writer: Wr,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.witness_len
+            .serialize_with_mode(&mut writer, compress)?;
+        self.matrix.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.witness_len.serialized_size(compress) + self.matrix.serialized_size(compress)
+    }
+}
+
+impl<NTT: OverField> Valid for DynAjtaiCommitmentScheme<NTT> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.matrix.iter().any(|row| row.len() != self.witness_len) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.matrix.check()
+    }
+}
+
+impl<NTT: OverField> CanonicalDeserialize for DynAjtaiCommitmentScheme<NTT> {
+    fn deserialize_with_mode<Rd: Read>(
+        mut reader: Rd,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let scheme = Self {
+            witness_len: usize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            matrix: Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+        };
+
+        if let Validate::Yes = validate {
+            scheme.check()?;
+        }
+
+        Ok(scheme)
+    }
+}
+
+impl<const C: usize, const W: usize, NTT: OverField> From<AjtaiCommitmentScheme<C, W, NTT>>
+    for DynAjtaiCommitmentScheme<NTT>
+{
+    fn from(scheme: AjtaiCommitmentScheme<C, W, NTT>) -> Self {
+        Self {
+            matrix: scheme.matrix().to_vec(),
+            witness_len: W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand};
+    use cyclotomic_rings::rings::{GoldilocksRingNTT, GoldilocksRingPoly};
+    use num_traits::Zero;
+    use stark_rings::cyclotomic_ring::ICRT;
+
+    use super::*;
+    use crate::decomposition_parameters::test_params::GoldilocksDP;
+
+    #[test]
+    fn test_dyn_matches_static() -> Result<(), CommitmentError> {
+        let mut rng = test_rng();
+        let dyn_scheme = DynAjtaiCommitmentScheme::<GoldilocksRingNTT>::from_seed(&[1; 32], 4, 16);
+        let scheme = AjtaiCommitmentScheme::<4, 16, GoldilocksRingNTT>::from_seed(&[1; 32]);
+        assert_eq!(dyn_scheme, DynAjtaiCommitmentScheme::from(scheme.clone()));
+
+        let witness: Vec<GoldilocksRingNTT> =
+            (0..16).map(|_| GoldilocksRingNTT::rand(&mut rng)).collect();
+        let committed = dyn_scheme.commit_ntt(&witness)?;
+        assert_eq!(committed.len(), 4);
+        assert_eq!(
+            Commitment::<4, _>::try_from(committed.clone())?,
+            scheme.commit_ntt(&witness)?
+        );
+        assert!(matches!(
+            Commitment::<3, _>::try_from(committed),
+            Err(CommitmentError::WrongCommitmentLength(4, 3))
+        ));
+
+        let witness: Vec<GoldilocksRingPoly> = (0..16 / GoldilocksDP::L)
+            .map(|_| GoldilocksRingPoly::rand(&mut rng))
+            .collect();
+        assert_eq!(
+            dyn_scheme.decompose_and_commit_coeff::<GoldilocksDP>(&witness)?,
+            DynCommitment::from(
+                dyn_scheme
+                    .to_static::<4, 15>()?
+                    .decompose_and_commit_coeff::<GoldilocksDP>(&witness)?
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dyn_zero_padding() -> Result<(), CommitmentError> {
+        let mut rng = test_rng();
+        let dyn_scheme = DynAjtaiCommitmentScheme::<GoldilocksRingNTT>::rand(&mut rng, 3, 16);
+
+        let witness: Vec<GoldilocksRingNTT> =
+            (0..10).map(|_| GoldilocksRingNTT::rand(&mut rng)).collect();
+        let mut padded = witness.clone();
+        padded.resize(16, GoldilocksRingNTT::zero());
+
+        let committed = dyn_scheme.commit_ntt(&witness)?;
+        assert_eq!(committed, dyn_scheme.commit_ntt(&padded)?);
+        assert_eq!(
+            DynCommitment::from(dyn_scheme.to_static::<3, 10>()?.commit_ntt(&witness)?),
+            committed
+        );
+        assert_eq!(
+            dyn_scheme.commit_coeff(ICRT::elementwise_icrt(witness))?,
+            committed
+        );
+
+        assert!(matches!(
+            dyn_scheme.commit_ntt(&[GoldilocksRingNTT::zero(); 17]),
+            Err(CommitmentError::WrongWitnessLength(17, 16))
+        ));
+        assert!(matches!(
+            dyn_scheme.to_static::<3, 17>(),
+            Err(CommitmentError::WrongAjtaiMatrixDimensions(3, 16, 3, 17))
+        ));
+        assert!(matches!(
+            dyn_scheme.to_static::<4, 16>(),
+            Err(CommitmentError::WrongAjtaiMatrixDimensions(3, 16, 4, 16))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dyn_serialization() {
+        let dyn_scheme = DynAjtaiCommitmentScheme::<GoldilocksRingNTT>::rand(&mut test_rng(), 2, 4);
+
+        let mut bytes = Vec::new();
+        dyn_scheme.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            DynAjtaiCommitmentScheme::deserialize_compressed(&bytes[..]).unwrap(),
+            dyn_scheme
+        );
+
+        assert!(matches!(
+            DynAjtaiCommitmentScheme::<GoldilocksRingNTT>::new(vec![vec![]], 1),
+            Err(CommitmentError::WrongAjtaiMatrixDimensions(1, 0, 1, 1))
+        ));
+    }
+}
@@ -1,4 +1,6 @@
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
 use ark_std::{
     ops::{Add, Mul, Sub},
     Zero,
@@ -15,11 +17,39 @@ use crate::{
 /// Enforced to have the length `C`.
 /// Since Ajtai commitment is bounded-additively homomorphic
 /// one can add commitments and multiply them by a scalar.
-#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize)]
 pub struct Commitment<const C: usize, R1: Ring> {
     val: Vec<R1>,
 }
 
+impl<const C: usize, R: Ring> Valid for Commitment<C, R> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.val.len() != C {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.val.check()
+    }
+}
+
+impl<const C: usize, R: Ring> CanonicalDeserialize for Commitment<C, R> {
+    fn deserialize_with_mode<Rd: Read>(
+        reader: Rd,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let commitment = Self {
+            val: Vec::deserialize_with_mode(reader, compress, Validate::No)?,
+        };
+
+        if let Validate::Yes = validate {
+            commitment.check()?;
+        }
+
+        Ok(commitment)
+    }
+}
+
 impl<const C: usize, R: Ring> Commitment<C, R> {
     pub(super) fn from_vec_raw(vec: Vec<R>) -> Self {
         Self { val: vec }
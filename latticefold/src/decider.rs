@@ -0,0 +1,458 @@
+//! The decider module provides a succinct argument that a final [`LCCCS`] accumulator is satisfiable.
+//!
+//! A witness $f$ satisfies an accumulator $(r, v, cm, \\{ u_j \\}, x_w, h)$ if
+//!  * $f$ opens the Ajtai commitment, i.e. $A f = cm$,
+//!  * $v$ is the evaluation of the MLEs $\hat{f}$ at $r$,
+//!  * $u_j$ is the evaluation of the MLE of $M_j z$ at $r$, where $z = x_w \\| h \\| G_B f$,
+//!  * $\\| f \\|_\infty < B$.
+//!
+//! The first three conditions are ring-linear in $f$, so the decider gathers them into a single relation
+//! $G f = y$ and halves its width in every round: the prover sends the cross terms $G_L f_R$ and $G_R f_L$,
+//! and, given a short challenge $c$, both parties continue with $f' = c f_L + f_R$, $G' = G_L + c G_R$
+//! and $y' = c y + G_L f_R + c^2 G_R f_L$. Finally, the prover reveals the folded witness and
+//! the verifier checks the folded relation along with a relaxed norm bound, that grows by a factor of
+//! $\\| c \\|_{op} + 1$ in every round.
+//!
+//! The relaxed relation is only meaningful as long as the Ajtai commitment stays binding for witnesses
+//! of norm up to the relaxed bound: two distinct openings of norm at most $\beta$ of the same (folded) commitment
+//! differ by a solution of norm at most $2 \beta$ to the MSIS problem of the $C \times W$ Ajtai matrix,
+//! since the folded key is a combination of its columns with the same coefficients as the folded witness.
+//! The relaxed bound is therefore capped at half of [`max_secure_norm_inf`] for $(C, W, q, d)$
+//! at [`DECIDER_SECURITY_LEVEL`] in the classical core-SVP model, which is well below $q / 2$ for practical parameters.
+//! The prover stops folding before the relaxed bound exceeds the cap and sends the remaining witness in full,
+//! thus the folded witness may consist of more than one ring element.
+//! The cap does not account for the slack of the knowledge extractor.
+//!
+//! If not even the first round fits under the cap, the proof would be the whole witness. Such configurations
+//! are rejected with [`DeciderError::NoFoldingRound`] by both the prover and the verifier. This happens whenever
+//! the commitment is too short to stay binding for $2 B$, e.g. for $C = 4$ over any of the provided rings,
+//! so the decider needs a longer commitment than the folding scheme itself.
+//!
+//! Both parties bind the transcript to the [`statement_digest`] of the CCS, the Ajtai matrix and `P` first.
+//!
+//! Only the proof is succinct. The verifier does not need the witness, but it folds the rows of $G$ itself
+//! and thus runs in time linear in `W`.
+//! The decider is not zero-knowledge: the folded witness leaks information about $f$.
+
+use ark_ff::{Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::marker::PhantomData;
+use cyclotomic_rings::{
+    norm::{centered_abs, vec_norm_inf},
+    rings::SuitableRing,
+};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use stark_rings::{
+    cyclotomic_ring::{CRT, ICRT},
+    OverField,
+};
+
+use self::error::DeciderError;
+use crate::{
+    arith::{Witness, CCS, LCCCS},
+    ark_base::*,
+    commitment::AjtaiCommitmentScheme,
+    decomposition_parameters::{half_modulus, DecompositionParams},
+    nifs::statement::{bind_statement, statement_digest},
+    transcript::{Transcript, TranscriptWithShortChallenges},
+    utils::{
+        security_check::{max_secure_norm_inf, CostModel, SecurityLevel},
+        sumcheck::utils::build_eq_x_r_vec,
+    },
+};
+
+/// The security level at which the Ajtai commitment has to stay binding for the relaxed norm bound.
+pub const DECIDER_SECURITY_LEVEL: SecurityLevel = SecurityLevel::Bits128;
+
+pub mod error;
+
+#[cfg(test)]
+mod tests;
+
+/// A proof that an [`LCCCS`] accumulator is satisfiable.
+///
+/// `NTT` is a cyclotomic ring in the NTT form.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DeciderProof<NTT: OverField> {
+    /// The linear combinations $F_t = \sum_k e_{k, t} f_k$, where $e_{k, t}$ is the $t$-th
+    /// base prime field coordinate of $\mathrm{eq}(r, k)$.
+    pub f_evals: Vec<NTT>,
+    /// The cross terms $G_L f_R$ of every folding round.
+    pub cross_terms_l: Vec<Vec<NTT>>,
+    /// The cross terms $G_R f_L$ of every folding round.
+    pub cross_terms_r: Vec<Vec<NTT>>,
+    /// The folded witness.
+    pub f: Vec<NTT>,
+}
+
+/// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
+/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
+/// `NTT` is a suitable cyclotomic ring.
+/// `P` is the decomposition parameters.
+/// `T` is the FS-transform transcript.
+pub struct DeciderProver<const C: usize, const W: usize, NTT, P, T> {
+    _r: PhantomData<NTT>,
+    _p: PhantomData<P>,
+    _t: PhantomData<T>,
+}
+
+impl<
+        const C: usize,
+        const W: usize,
+        NTT: SuitableRing,
+        P: DecompositionParams,
+        T: TranscriptWithShortChallenges<NTT> + Clone,
+    > DeciderProver<C, W, NTT, P, T>
+{
+    /// Proves that `w_acc` satisfies the accumulator `acc`.
+    ///
+    /// The prover does not check the witness, an unsatisfying witness yields a proof the verifier rejects.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeciderError::NoFoldingRound`] if the relaxed norm bound does not allow a single folding round.
+    pub fn prove(
+        acc: &LCCCS<C, NTT>,
+        w_acc: &Witness<NTT>,
+        transcript: &mut T,
+        ccs: &CCS<NTT>,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+    ) -> Result<DeciderProof<NTT>, DeciderError> {
+        if w_acc.f.len() != W {
+            return Err(DeciderError::IncorrectLength);
+        }
+
+        bind_statement(transcript, &statement_digest::<C, NTT, P>(ccs, scheme));
+
+        let (mut rows, _) = linear_claims::<C, W, NTT, P>(acc, ccs, scheme)?;
+
+        let mut f = w_acc.f.clone();
+        f.resize(W.next_power_of_two(), NTT::zero());
+
+        let f_evals: Vec<NTT> = rows[C + ccs.t..]
+            .iter()
+            .map(|row| inner_product(row, &f))
+            .collect();
+
+        absorb_accumulator(acc, &f_evals, transcript);
+
+        let max_bound = max_relaxed_bound::<C, W, NTT>();
+        let mut bound = BigUint::from(P::B);
+        let mut cross_terms_l = Vec::new();
+        let mut cross_terms_r = Vec::new();
+
+        while f.len() > 1 {
+            let (t_l, t_r) = cross_terms(&rows, &f);
+
+            // Only commit to the round if the relaxed bound stays meaningful.
+            let mut round_transcript = transcript.clone();
+            let c = absorb_round(&t_l, &t_r, &mut round_transcript);
+            let next_bound = &bound * (operator_norm::<NTT>(&c) + 1u32);
+            if next_bound > max_bound {
+                break;
+            }
+
+            *transcript = round_transcript;
+            bound = next_bound;
+
+            let c = c.crt();
+            fold(&mut f, &c);
+            rows.iter_mut().for_each(|row| fold(row, &c));
+
+            cross_terms_l.push(t_l);
+            cross_terms_r.push(t_r);
+        }
+
+        if cross_terms_l.is_empty() && f.len() > 1 {
+            return Err(DeciderError::NoFoldingRound);
+        }
+
+        Ok(DeciderProof {
+            f_evals,
+            cross_terms_l,
+            cross_terms_r,
+            f,
+        })
+    }
+}
+
+/// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
+/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
+/// `NTT` is a suitable cyclotomic ring.
+/// `P` is the decomposition parameters.
+/// `T` is the FS-transform transcript.
+pub struct DeciderVerifier<const C: usize, const W: usize, NTT, P, T> {
+    _r: PhantomData<NTT>,
+    _p: PhantomData<P>,
+    _t: PhantomData<T>,
+}
+
+impl<
+        const C: usize,
+        const W: usize,
+        NTT: SuitableRing,
+        P: DecompositionParams,
+        T: TranscriptWithShortChallenges<NTT>,
+    > DeciderVerifier<C, W, NTT, P, T>
+{
+    /// Verifies that the accumulator `acc` is satisfiable.
+    pub fn verify(
+        acc: &LCCCS<C, NTT>,
+        proof: &DeciderProof<NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+    ) -> Result<(), DeciderError> {
+        if proof.f_evals.len() != NTT::BaseRing::extension_degree() as usize
+            || proof.cross_terms_l.len() != proof.cross_terms_r.len()
+        {
+            return Err(DeciderError::IncorrectLength);
+        }
+
+        if proof.cross_terms_l.is_empty() && W.next_power_of_two() > 1 {
+            return Err(DeciderError::NoFoldingRound);
+        }
+
+        if promote_evaluations(&proof.f_evals)? != acc.v {
+            return Err(DeciderError::EvaluationMismatch);
+        }
+
+        bind_statement(transcript, &statement_digest::<C, NTT, P>(ccs, scheme));
+
+        let (mut rows, mut y) = linear_claims::<C, W, NTT, P>(acc, ccs, scheme)?;
+        y.extend_from_slice(&proof.f_evals);
+
+        absorb_accumulator(acc, &proof.f_evals, transcript);
+
+        let mut len = W.next_power_of_two();
+        let mut bound = BigUint::from(P::B);
+
+        for (t_l, t_r) in proof.cross_terms_l.iter().zip(&proof.cross_terms_r) {
+            if len == 1 || t_l.len() != rows.len() || t_r.len() != rows.len() {
+                return Err(DeciderError::IncorrectLength);
+            }
+
+            let c = absorb_round(t_l, t_r, transcript);
+            bound *= operator_norm::<NTT>(&c) + 1u32;
+
+            let c = c.crt();
+            let c_sq = c * c;
+            rows.iter_mut().for_each(|row| fold(row, &c));
+            y.iter_mut()
+                .zip(t_l.iter().zip(t_r))
+                .for_each(|(y_i, (t_l_i, t_r_i))| *y_i = c * *y_i + t_l_i + c_sq * t_r_i);
+            len /= 2;
+        }
+
+        if proof.f.len() != len {
+            return Err(DeciderError::IncorrectLength);
+        }
+
+        if rows
+            .iter()
+            .zip(&y)
+            .any(|(row, y_i)| inner_product(row, &proof.f) != *y_i)
+        {
+            return Err(DeciderError::LinearRelationMismatch);
+        }
+
+        if !proof.cross_terms_l.is_empty() && bound > max_relaxed_bound::<C, W, NTT>() {
+            return Err(DeciderError::RelaxedBoundTooLarge);
+        }
+
+        if vec_norm_inf::<NTT::CoefficientRepresentation>(&ICRT::elementwise_icrt(proof.f.clone()))
+            > bound
+        {
+            return Err(DeciderError::NormBoundExceeded);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the largest relaxed norm bound for which the Ajtai commitment is binding at [`DECIDER_SECURITY_LEVEL`],
+/// and that is below half of the modulus.
+fn max_relaxed_bound<const C: usize, const W: usize, NTT: SuitableRing>() -> BigUint {
+    let modulus: BigUint = <NTT::BaseRing as Field>::BasePrimeField::MODULUS.into();
+    let max_norm = max_secure_norm_inf(
+        NTT::CoefficientRepresentation::dimension(),
+        C,
+        &modulus,
+        W,
+        DECIDER_SECURITY_LEVEL,
+        CostModel::ClassicalCoreSvp,
+    );
+
+    (max_norm >> 1u32).min(half_modulus::<NTT>() - 1u32)
+}
+
+/// Builds the linear relation $G f = y$ on the witness of `acc`, with the columns of $G$ padded
+/// to the next power of two.
+///
+/// The rows of $G$ are, in this order, the rows of the Ajtai matrix, one row per CCS matrix
+/// and one row per base prime field coordinate of the extension field.
+/// The returned `y` lacks the targets of the last group of rows, which are sent by the prover.
+///
+/// The slots of $\hat{f}$ live in the extension field and $\mathrm{eq}(r, k)$ does not act
+/// on a ring element the same way it acts on its coefficients. Hence, the evaluation claim $v$ is split
+/// into the ring-linear claims $F_t = \sum_k e_{k, t} f_k$, which are checked against $v$ separately.
+fn linear_claims<const C: usize, const W: usize, NTT: SuitableRing, P: DecompositionParams>(
+    acc: &LCCCS<C, NTT>,
+    ccs: &CCS<NTT>,
+    scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+) -> Result<(Vec<Vec<NTT>>, Vec<NTT>), DeciderError> {
+    if W > 1 << acc.r.len() || acc.u.len() != ccs.t || acc.x_w.len() != ccs.l {
+        return Err(DeciderError::IncorrectLength);
+    }
+
+    let len = W.next_power_of_two();
+    let eq = build_eq_x_r_vec(&acc.r)?;
+
+    let mut rows = Vec::with_capacity(C + ccs.t + NTT::BaseRing::extension_degree() as usize);
+    let mut y = Vec::with_capacity(C + ccs.t);
+
+    // A f = cm
+    for (row, cm_i) in scheme.matrix().iter().zip(acc.cm.as_ref()) {
+        let mut row = row.clone();
+        row.resize(len, NTT::zero());
+        rows.push(row);
+        y.push(*cm_i);
+    }
+
+    // eq(r)^T M_j z = u_j, where w_i = \sum_l B^l f_{i * L + l}
+    let mut powers_of_b = Vec::with_capacity(P::L);
+    let mut power = NTT::one();
+    for _ in 0..P::L {
+        powers_of_b.push(power);
+        power *= NTT::from(P::B);
+    }
+
+    for (M, u_j) in ccs.M.iter().zip(&acc.u) {
+        let mut a = vec![NTT::zero(); ccs.n];
+        for (eq_i, row) in eq.iter().zip(&M.coeffs) {
+            for &(value, col) in row {
+                a[col] += *eq_i * value;
+            }
+        }
+
+        let mut row = vec![NTT::zero(); len];
+        for (k, entry) in row.iter_mut().take(W).enumerate() {
+            if let Some(a_i) = a.get(ccs.l + 1 + k / P::L) {
+                *entry = *a_i * powers_of_b[k % P::L];
+            }
+        }
+        rows.push(row);
+
+        let public: NTT = a[..ccs.l]
+            .iter()
+            .zip(&acc.x_w)
+            .map(|(a_i, x_i)| *a_i * x_i)
+            .sum();
+        y.push(*u_j - public - a[ccs.l] * acc.h);
+    }
+
+    // F_t = \sum_k e_{k, t} f_k
+    let mut eval_rows = vec![vec![NTT::zero(); len]; NTT::BaseRing::extension_degree() as usize];
+    for (k, eq_k) in eq.iter().take(W).enumerate() {
+        for (row, e_k_t) in eval_rows
+            .iter_mut()
+            .zip(eq_k.coeffs()[0].to_base_prime_field_elements())
+        {
+            row[k] = NTT::from(<NTT::BaseRing as Field>::from_base_prime_field(e_k_t));
+        }
+    }
+    rows.append(&mut eval_rows);
+
+    Ok((rows, y))
+}
+
+/// Recovers the evaluations of $\hat{f}$ at $r$ from the claims $F_t$.
+fn promote_evaluations<NTT: SuitableRing>(f_evals: &[NTT]) -> Result<Vec<NTT>, DeciderError> {
+    let f_evals: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(f_evals.to_vec());
+
+    let slots = (0..NTT::CoefficientRepresentation::dimension())
+        .map(|i| {
+            let coords: Vec<_> = f_evals.iter().map(|f_t| f_t.coeffs()[i]).collect();
+            <NTT::BaseRing as Field>::from_base_prime_field_elems(&coords)
+                .ok_or(DeciderError::IncorrectLength)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    NTT::promote_from_coeffs(slots).ok_or(DeciderError::IncorrectLength)
+}
+
+fn absorb_accumulator<const C: usize, NTT: SuitableRing>(
+    acc: &LCCCS<C, NTT>,
+    f_evals: &[NTT],
+    transcript: &mut impl Transcript<NTT>,
+) {
+    transcript.absorb_label(b"decider");
+
+    transcript.absorb_slice(&acc.r);
+    transcript.absorb_slice(&acc.v);
+    transcript.absorb_slice(acc.cm.as_ref());
+    transcript.absorb_slice(&acc.u);
+    transcript.absorb_slice(&acc.x_w);
+    transcript.absorb(&acc.h);
+    transcript.absorb_slice(f_evals);
+}
+
+fn absorb_round<NTT: SuitableRing>(
+    t_l: &[NTT],
+    t_r: &[NTT],
+    transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+) -> NTT::CoefficientRepresentation {
+    transcript.absorb_slice(t_l);
+    transcript.absorb_slice(t_r);
+    transcript.get_short_challenge()
+}
+
+/// Computes $(G_L f_R, G_R f_L)$.
+fn cross_terms<NTT: SuitableRing>(rows: &[Vec<NTT>], f: &[NTT]) -> (Vec<NTT>, Vec<NTT>) {
+    let (f_l, f_r) = f.split_at(f.len() / 2);
+
+    rows.iter()
+        .map(|row| {
+            let (row_l, row_r) = row.split_at(row.len() / 2);
+            (inner_product(row_l, f_r), inner_product(row_r, f_l))
+        })
+        .unzip()
+}
+
+/// Replaces `v` with $c v_L + v_R$.
+fn fold<NTT: SuitableRing>(v: &mut Vec<NTT>, c: &NTT) {
+    let half = v.len() / 2;
+    let (v_l, v_r) = v.split_at_mut(half);
+    v_l.iter_mut()
+        .zip(v_r.iter())
+        .for_each(|(v_l_i, v_r_i)| *v_l_i = *c * *v_l_i + v_r_i);
+    v.truncate(half);
+}
+
+fn inner_product<NTT: SuitableRing>(a: &[NTT], b: &[NTT]) -> NTT {
+    a.iter()
+        .zip(b)
+        .fold(NTT::zero(), |acc, (a_i, b_i)| acc + *a_i * b_i)
+}
+
+/// Returns the operator norm of multiplication by `c` with respect to $\\| \cdot \\|_\infty$,
+/// i.e. the largest row $\ell_1$-norm of the rotation matrix of `c`.
+fn operator_norm<NTT: SuitableRing>(c: &NTT::CoefficientRepresentation) -> BigUint {
+    let dimension = NTT::CoefficientRepresentation::dimension();
+
+    let mut x = NTT::CoefficientRepresentation::zero();
+    x.coeffs_mut()[1] = One::one();
+
+    let mut row_norms = vec![BigUint::zero(); dimension];
+    let mut c_x_j = *c;
+    for _ in 0..dimension {
+        row_norms
+            .iter_mut()
+            .zip(c_x_j.coeffs())
+            .for_each(|(norm, coeff)| *norm += centered_abs(coeff));
+        c_x_j *= x;
+    }
+
+    row_norms.into_iter().max().unwrap_or_default()
+}
@@ -0,0 +1,24 @@
+use stark_rings_poly::polynomials::ArithErrors;
+use thiserror::Error;
+
+use crate::commitment::CommitmentError;
+
+#[derive(Debug, Error)]
+pub enum DeciderError {
+    #[error("ajtai commitment error: {0}")]
+    CommitmentError(#[from] CommitmentError),
+    #[error("eq polynomial error: {0}")]
+    ArithError(#[from] ArithErrors),
+    #[error("input vectors have incorrect length")]
+    IncorrectLength,
+    #[error("f_hat evaluation claims do not match the accumulator")]
+    EvaluationMismatch,
+    #[error("folded witness does not satisfy the folded linear claims")]
+    LinearRelationMismatch,
+    #[error("folded witness exceeds the relaxed norm bound")]
+    NormBoundExceeded,
+    #[error("relaxed norm bound exceeds the largest norm for which the commitment is binding")]
+    RelaxedBoundTooLarge,
+    #[error("relaxed norm bound leaves no folding round, the proof would be the whole witness")]
+    NoFoldingRound,
+}
@@ -0,0 +1,252 @@
+use ark_std::test_rng;
+use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT};
+use num_traits::One;
+use rand::Rng;
+
+use super::*;
+use crate::{
+    arith::{r1cs::get_test_z_split, tests::get_test_ccs, Instance, CCCS},
+    decomposition_parameters::test_params::GoldilocksDP,
+    nifs::{
+        error::LinearizationError,
+        linearization::{LFLinearizationProver, LinearizationProver},
+        statement::statement_digest,
+        NIFSProver,
+    },
+    transcript::poseidon::PoseidonTranscript,
+    utils::mle_helpers::{calculate_Mz_mles, evaluate_mles},
+};
+
+type RqNTT = GoldilocksRingNTT;
+type CS = GoldilocksChallengeSet;
+type DP = GoldilocksDP;
+type T = PoseidonTranscript<RqNTT, CS>;
+
+// The commitment has to stay binding for the relaxed norm bound of at least one folding round.
+const C: usize = 64;
+const WIT_LEN: usize = 4;
+const W: usize = WIT_LEN * DP::L;
+
+fn setup_accumulator<const C: usize>() -> (
+    LCCCS<C, RqNTT>,
+    Witness<RqNTT>,
+    CCS<RqNTT>,
+    AjtaiCommitmentScheme<C, W, RqNTT>,
+) {
+    let ccs = get_test_ccs::<RqNTT>(W, DP::L);
+    let mut rng = test_rng();
+    let scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::rand(&mut rng);
+
+    let mut new_cccs = || {
+        let (_, x_ccs, w_ccs) = get_test_z_split::<RqNTT>(rng.gen_range(0..64));
+        let wit = Witness::from_w_ccs::<DP>(w_ccs);
+        let cm = CCCS {
+            cm: wit.commit::<C>(&scheme).unwrap(),
+            x_ccs,
+        };
+        (cm, wit)
+    };
+
+    let (cm_acc, w_acc) = new_cccs();
+    let (cm_i, w_i) = new_cccs();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+
+    let (acc, _) =
+        LFLinearizationProver::<_, T>::prove(&cm_acc, &w_acc, &mut T::default(), &ccs).unwrap();
+
+    let (acc, w_acc, _) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    (acc, w_acc, ccs, scheme)
+}
+
+fn prove_and_verify(
+    acc: &LCCCS<C, RqNTT>,
+    w_acc: &Witness<RqNTT>,
+    ccs: &CCS<RqNTT>,
+    scheme: &AjtaiCommitmentScheme<C, W, RqNTT>,
+) -> Result<(), DeciderError> {
+    let proof =
+        DeciderProver::<C, W, RqNTT, DP, T>::prove(acc, w_acc, &mut T::default(), ccs, scheme)?;
+
+    DeciderVerifier::<C, W, RqNTT, DP, T>::verify(acc, &proof, &mut T::default(), ccs, scheme)
+}
+
+#[test]
+fn test_decider_accepts_folded_accumulator() {
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    prove_and_verify(&acc, &w_acc, &ccs, &scheme).unwrap();
+}
+
+#[test]
+fn test_decider_proof_is_shorter_than_witness() {
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    let proof =
+        DeciderProver::<C, W, RqNTT, DP, T>::prove(&acc, &w_acc, &mut T::default(), &ccs, &scheme)
+            .unwrap();
+
+    assert!(!proof.cross_terms_l.is_empty());
+    assert!(proof.f.len() < W);
+}
+
+#[test]
+fn test_decider_rejects_configuration_without_rounds() {
+    // With 4 rows, the commitment is not binding at 128 bits for norms above B, so no round fits.
+    const C_SMALL: usize = 4;
+    assert!(max_relaxed_bound::<C_SMALL, W, RqNTT>() < BigUint::from(DP::B));
+
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C_SMALL>();
+
+    assert!(matches!(
+        DeciderProver::<C_SMALL, W, RqNTT, DP, T>::prove(
+            &acc,
+            &w_acc,
+            &mut T::default(),
+            &ccs,
+            &scheme,
+        ),
+        Err(DeciderError::NoFoldingRound)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_proof_without_rounds() {
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    let mut proof =
+        DeciderProver::<C, W, RqNTT, DP, T>::prove(&acc, &w_acc, &mut T::default(), &ccs, &scheme)
+            .unwrap();
+    proof.cross_terms_l.clear();
+    proof.cross_terms_r.clear();
+
+    assert!(matches!(
+        DeciderVerifier::<C, W, RqNTT, DP, T>::verify(
+            &acc,
+            &proof,
+            &mut T::default(),
+            &ccs,
+            &scheme
+        ),
+        Err(DeciderError::NoFoldingRound)
+    ));
+}
+
+#[test]
+fn test_decider_relaxed_bound_is_binding() {
+    let max_bound = max_relaxed_bound::<C, W, RqNTT>();
+
+    assert!(max_bound > BigUint::from(DP::B));
+    assert!(max_bound < half_modulus::<RqNTT>());
+}
+
+#[test]
+fn test_decider_rejects_wrong_v() {
+    let (mut acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    acc.v[0] += RqNTT::one();
+
+    assert!(matches!(
+        prove_and_verify(&acc, &w_acc, &ccs, &scheme),
+        Err(DeciderError::EvaluationMismatch)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_wrong_u() {
+    let (mut acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    acc.u[0] += RqNTT::one();
+
+    assert!(matches!(
+        prove_and_verify(&acc, &w_acc, &ccs, &scheme),
+        Err(DeciderError::LinearRelationMismatch)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_wrong_commitment() {
+    let (mut acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    let mut cm = acc.cm.as_ref().to_vec();
+    cm[0] += RqNTT::one();
+    acc.cm = cm.try_into().unwrap();
+
+    assert!(matches!(
+        prove_and_verify(&acc, &w_acc, &ccs, &scheme),
+        Err(DeciderError::LinearRelationMismatch)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_tampered_cross_terms() {
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    let mut proof =
+        DeciderProver::<C, W, RqNTT, DP, T>::prove(&acc, &w_acc, &mut T::default(), &ccs, &scheme)
+            .unwrap();
+    proof.cross_terms_l[0][0] += RqNTT::one();
+
+    assert!(matches!(
+        DeciderVerifier::<C, W, RqNTT, DP, T>::verify(
+            &acc,
+            &proof,
+            &mut T::default(),
+            &ccs,
+            &scheme
+        ),
+        Err(DeciderError::LinearRelationMismatch)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_wrong_shape() {
+    let (acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    let mut proof =
+        DeciderProver::<C, W, RqNTT, DP, T>::prove(&acc, &w_acc, &mut T::default(), &ccs, &scheme)
+            .unwrap();
+    proof.f.pop();
+
+    assert!(matches!(
+        DeciderVerifier::<C, W, RqNTT, DP, T>::verify(
+            &acc,
+            &proof,
+            &mut T::default(),
+            &ccs,
+            &scheme
+        ),
+        Err(DeciderError::IncorrectLength)
+    ));
+}
+
+#[test]
+fn test_decider_rejects_large_witness() {
+    let (mut acc, w_acc, ccs, scheme) = setup_accumulator::<C>();
+
+    // A witness that satisfies all linear claims of the accumulator but is far from short.
+    let mut f = w_acc.f.clone();
+    f[0] += RqNTT::from(1u128 << 62);
+    let w_acc = Witness::from_f::<DP>(f);
+
+    let z = acc.get_z_vector(&w_acc.w_ccs);
+    let mz_mles = calculate_Mz_mles::<RqNTT, LinearizationError<RqNTT>>(&ccs, &z).unwrap();
+    acc.cm = w_acc.commit::<C>(&scheme).unwrap();
+    acc.v = evaluate_mles::<RqNTT, _, _, LinearizationError<RqNTT>>(&w_acc.f_hat, &acc.r).unwrap();
+    acc.u = evaluate_mles::<RqNTT, _, _, LinearizationError<RqNTT>>(&mz_mles, &acc.r).unwrap();
+
+    assert!(matches!(
+        prove_and_verify(&acc, &w_acc, &ccs, &scheme),
+        Err(DeciderError::NormBoundExceeded)
+    ));
+}
@@ -3,7 +3,11 @@
 //! Decomposition parameters dictate how higher-bound witness are
 //! decomposed into lower-bound witnesses.
 
+use ark_ff::{Field, PrimeField};
 use ark_std::fmt::Display;
+use cyclotomic_rings::rings::SuitableRing;
+use num_bigint::BigUint;
+use thiserror::Error;
 
 /// Decomposition parameters.
 /// Convenient to enforce them compile-time.
@@ -17,28 +21,114 @@ pub trait DecompositionParams: Clone {
     const B_SMALL: usize;
     /// K = log_b B.
     const K: usize;
+
+    /// The parameters as a value, which is what the protocol works with.
+    const CONFIG: DecompositionConfig = DecompositionConfig {
+        b: Self::B,
+        l: Self::L,
+        b_small: Self::B_SMALL,
+        k: Self::K,
+    };
 }
 
-impl<P: DecompositionParams> From<P> for DecompositionParamData {
-    fn from(_: P) -> Self {
-        {
-            Self { b: P::B, l: P::L }
+/// Errors caused by inconsistent decomposition parameters.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DecompositionParamsError {
+    /// The bases of the decompositions must be at least 2.
+    #[error("decomposition bases must be at least 2, got B={0}, B_SMALL={1}")]
+    BaseTooSmall(u128, usize),
+    /// The decompositions must have at least one digit.
+    #[error("decompositions must have at least one digit, got L={0}, K={1}")]
+    NoDigits(usize, usize),
+    /// `B_SMALL^K` is not `B`, i.e. `K` is not $\log_{B\_SMALL} B$.
+    #[error("B_SMALL^K = {0}^{1} does not equal B = {2}")]
+    InconsistentK(usize, usize, u128),
+    /// `B^L` does not exceed half of the ring modulus,
+    /// i.e. some ring elements have no radix-`B` decomposition of length `L`.
+    #[error("B^L = {0}^{1} does not exceed half of the ring modulus")]
+    GadgetTooShort(u128, usize),
+}
+
+/// Decomposition parameters chosen at runtime, the value-level counterpart of [`DecompositionParams`].
+///
+/// The subprotocols of the NIFS take their parameters as a `DecompositionConfig`,
+/// so a config that passes [`DecompositionConfig::validate`] can be used in place of a [`DecompositionParams`] type,
+/// see e.g. [`NIFSProverWithConfig`](crate::nifs::NIFSProverWithConfig).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecompositionConfig {
+    /// The MSIS bound.
+    pub b: u128,
+    /// The ring modulus should be < B^L.
+    pub l: usize,
+    /// The small b from the decomposition step of LF.
+    pub b_small: usize,
+    /// K = log_b B.
+    pub k: usize,
+}
+
+impl DecompositionConfig {
+    /// Returns the parameters of `P`, i.e. [`DecompositionParams::CONFIG`].
+    pub fn of<P: DecompositionParams>() -> Self {
+        P::CONFIG
+    }
+
+    /// Checks that the parameters are consistent with each other and with the modulus of the ring `R`,
+    /// i.e. `B_SMALL^K = B` and `B^L > q / 2`.
+    pub fn validate<R: SuitableRing>(&self) -> Result<(), DecompositionParamsError> {
+        if self.b < 2 || self.b_small < 2 {
+            return Err(DecompositionParamsError::BaseTooSmall(self.b, self.b_small));
+        }
+
+        if self.l == 0 || self.k == 0 {
+            return Err(DecompositionParamsError::NoDigits(self.l, self.k));
+        }
+
+        // B fits in u128, so B_SMALL^K overflowing u128 or K overflowing u32 means B_SMALL^K != B.
+        let b_small_pow_k = u32::try_from(self.k)
+            .ok()
+            .and_then(|k| (self.b_small as u128).checked_pow(k));
+        if b_small_pow_k != Some(self.b) {
+            return Err(DecompositionParamsError::InconsistentK(
+                self.b_small,
+                self.k,
+                self.b,
+            ));
+        }
+
+        let half_q = half_modulus::<R>();
+        let gadget_too_short = match u32::try_from(self.l) {
+            // B >= 2, so B^L > q / 2 as soon as L exceeds the bit length of q / 2.
+            Ok(l) if u64::from(l) <= half_q.bits() => BigUint::from(self.b).pow(l) <= half_q,
+            _ => false,
+        };
+        if gadget_too_short {
+            return Err(DecompositionParamsError::GadgetTooShort(self.b, self.l));
         }
+
+        Ok(())
     }
 }
 
-// Nice representation of parameters for printing out in benchmarks.
-#[derive(Clone, Copy)]
-pub struct DecompositionParamData {
-    // The MSIS bound.
-    b: u128,
-    // The ring modulus should be < B^L.
-    l: usize,
+impl<P: DecompositionParams> From<P> for DecompositionConfig {
+    fn from(_: P) -> Self {
+        Self::of::<P>()
+    }
 }
 
-impl Display for DecompositionParamData {
+/// Returns $\lfloor q / 2 \rfloor$ for the modulus $q$ of the ring `R`.
+pub(crate) fn half_modulus<R: SuitableRing>() -> BigUint {
+    let modulus: BigUint = <R::BaseRing as Field>::BasePrimeField::MODULUS.into();
+    modulus >> 1
+}
+
+// Nice representation of parameters for printing out in benchmarks.
+impl Display for DecompositionConfig {
     fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
-        write!(f, "B={}, l={}", self.b, self.l,)
+        write!(
+            f,
+            "B={}, L={}, B_small={}, K={}",
+            self.b, self.l, self.b_small, self.k
+        )
     }
 }
 
@@ -109,6 +199,71 @@ pub mod test_params {
         const B: u128 = 1 << 8;
         const L: usize = 8;
         const B_SMALL: usize = 2;
-        const K: usize = 10;
+        const K: usize = 8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cyclotomic_rings::rings::{BabyBearRingNTT, FrogRingNTT, GoldilocksRingNTT, StarkRingNTT};
+
+    use super::{test_params::*, DecompositionConfig, DecompositionParamsError};
+
+    #[test]
+    fn test_test_params_are_valid() {
+        DecompositionConfig::of::<GoldilocksDP>()
+            .validate::<GoldilocksRingNTT>()
+            .unwrap();
+        DecompositionConfig::of::<BabyBearDP>()
+            .validate::<BabyBearRingNTT>()
+            .unwrap();
+        DecompositionConfig::of::<FrogDP>()
+            .validate::<FrogRingNTT>()
+            .unwrap();
+        DecompositionConfig::of::<StarkDP>()
+            .validate::<StarkRingNTT>()
+            .unwrap();
+        DecompositionConfig::of::<StarkFoldingDP>()
+            .validate::<StarkRingNTT>()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let config = DecompositionConfig::of::<GoldilocksDP>();
+
+        assert_eq!(
+            DecompositionConfig { k: 14, ..config }.validate::<GoldilocksRingNTT>(),
+            Err(DecompositionParamsError::InconsistentK(2, 14, 1 << 15))
+        );
+        assert_eq!(
+            DecompositionConfig { l: 4, ..config }.validate::<GoldilocksRingNTT>(),
+            Err(DecompositionParamsError::GadgetTooShort(1 << 15, 4))
+        );
+        assert_eq!(
+            DecompositionConfig {
+                b_small: 1,
+                ..config
+            }
+            .validate::<GoldilocksRingNTT>(),
+            Err(DecompositionParamsError::BaseTooSmall(1 << 15, 1))
+        );
+        assert_eq!(
+            DecompositionConfig { l: 0, ..config }.validate::<GoldilocksRingNTT>(),
+            Err(DecompositionParamsError::NoDigits(0, 15))
+        );
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_large_k_is_not_truncated() {
+        let config = DecompositionConfig::of::<GoldilocksDP>();
+
+        // K = 2^32 + 15 agrees with the valid K = 15 in its lower 32 bits.
+        let k = (1 << 32) + 15;
+        assert_eq!(
+            DecompositionConfig { k, ..config }.validate::<GoldilocksRingNTT>(),
+            Err(DecompositionParamsError::InconsistentK(2, k, 1 << 15))
+        );
     }
 }
@@ -0,0 +1,455 @@
+//! The folding log module folds the steps of an iterated computation with the
+//! [LatticeFold](https://eprint.iacr.org/2024/257.pdf) folding scheme and records every fold.
+//!
+//! A computation is described by a [`StepCircuit`] $F$ and an initial state $z_0$.
+//! Every call to [`FoldingLogProver::prove_step`] computes $z_{i+1} = F(z_i)$, commits to the step witness
+//! and folds the instance of the previous step into a running accumulator.
+//! The public input of every step instance holds a hash of the accumulator it was produced against.
+//!
+//! This is not incrementally verifiable computation. The augmented constraint system does not arithmetize
+//! the folding verifier, and no constraint touches the hash column, so a step instance alone attests to nothing
+//! about the steps before it. A [`FoldingLog`] therefore carries the instances of all the steps, the proofs of
+//! every fold and the final witnesses, and [`FoldingLogVerifier::verify`] recomputes every hash and replays
+//! every fold natively. The log, as well as the verification time, grow linearly with the number of steps.
+
+use ark_std::{log2, marker::PhantomData};
+use cyclotomic_rings::rings::SuitableRing;
+use stark_rings::Ring;
+
+use self::error::FoldingLogError;
+use crate::{
+    arith::{error::CSError, Arith, Instance, Witness, CCCS, CCS, LCCCS},
+    ark_base::*,
+    commitment::AjtaiCommitmentScheme,
+    decomposition_parameters::DecompositionParams,
+    nifs::{
+        linearization::{
+            LFLinearizationProver, LFLinearizationVerifier, LinearizationProof,
+            LinearizationProver, LinearizationVerifier,
+        },
+        statement::{bind_statement, statement_digest, VerifierKey},
+        LFProof, NIFSProver, NIFSVerifier,
+    },
+    transcript::{Transcript, TranscriptWithShortChallenges},
+};
+
+pub mod error;
+
+#[cfg(test)]
+mod tests;
+
+/// A single step $z_{i+1} = F(z_i)$ of an iterated computation.
+///
+/// # Type Parameters
+///  * `R: Ring` - the ring algebra over which the step constraint system operates
+pub trait StepCircuit<R: Ring> {
+    /// The number of ring elements in the state $z_i$.
+    fn arity(&self) -> usize;
+
+    /// Returns the constraint system of one step.
+    ///
+    /// The public input of the constraint system is the concatenation `z_i || z_{i+1}`,
+    /// i.e. its `l` is `2 * arity()`, and its `z`-vector is `z_i || z_{i+1} || 1 || w`.
+    fn step_ccs(&self) -> CCS<R>;
+
+    /// Computes the next state $z_{i+1} = F(z_i)$ together with the step witness `w`.
+    fn step(&self, i: usize, z_i: &[R]) -> Result<(Vec<R>, Vec<R>), CSError>;
+}
+
+/// Builds the augmented constraint system of a step circuit.
+///
+/// The augmented constraint system prepends a public input to the step constraint system,
+/// that holds the hash of the running accumulator and the state the step was proven against.
+/// No constraint touches this column, it is only checked by [`FoldingLogVerifier::verify`].
+/// Its matrices rows are then padded to max { `(n - l - 1) * L`, `m` } next power of 2.
+pub fn augmented_ccs<R: Ring, P: DecompositionParams>(step_ccs: CCS<R>) -> CCS<R> {
+    let mut ccs = step_ccs;
+
+    ccs.M.iter_mut().for_each(|mat| {
+        mat.n_cols += 1;
+        mat.coeffs
+            .iter_mut()
+            .flat_map(|row| row.iter_mut())
+            .for_each(|(_, col)| *col += 1);
+    });
+    ccs.l += 1;
+    ccs.n += 1;
+    ccs.s_prime = log2(ccs.n) as usize;
+
+    let len = usize::max((ccs.n - ccs.l - 1) * P::L, ccs.m).next_power_of_two();
+    ccs.pad_rows_to(len);
+
+    ccs
+}
+
+/// The record of an iterated computation after `i` steps.
+///
+/// `C` is the length of Ajtai commitment vectors.
+/// `NTT` is a cyclotomic ring in the NTT form.
+#[derive(Debug, Clone)]
+pub struct FoldingLog<const C: usize, NTT: SuitableRing> {
+    /// The number of steps performed.
+    pub i: usize,
+    /// The initial state.
+    pub z_0: Vec<NTT>,
+    /// The current state.
+    pub z_i: Vec<NTT>,
+    /// The instances of the first `i - 1` steps, which have been folded into the accumulator.
+    pub folded_steps: Vec<CCCS<C, NTT>>,
+    /// The proof of linearizing the instance of the first step into the first accumulator.
+    ///
+    /// It is `None` after the first step.
+    pub linearization_proof: Option<LinearizationProof<NTT>>,
+    /// The proofs of folding the instances of steps `2, ..., i - 1` into the accumulator.
+    pub folding_proofs: Vec<LFProof<C, NTT>>,
+    /// The running accumulator and its witness.
+    ///
+    /// It is `None` after the first step, since there is nothing to fold yet.
+    pub acc: Option<(LCCCS<C, NTT>, Witness<NTT>)>,
+    /// The instance of the last step.
+    pub u_i: CCCS<C, NTT>,
+    /// The witness of the last step.
+    pub w_i: Witness<NTT>,
+}
+
+/// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
+/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
+/// `NTT` is a suitable cyclotomic ring.
+/// `P` is the decomposition parameters.
+/// `T` is the FS-transform transcript.
+/// `SC` is the step circuit.
+pub struct FoldingLogProver<const C: usize, const W: usize, NTT: SuitableRing, P, T, SC> {
+    step_circuit: SC,
+    ccs: CCS<NTT>,
+    scheme: AjtaiCommitmentScheme<C, W, NTT>,
+    /// The digest of `ccs`, `scheme` and `P` every folding transcript is bound to.
+    statement: [u8; 32],
+    i: usize,
+    z_0: Vec<NTT>,
+    z_i: Vec<NTT>,
+    acc: Option<(LCCCS<C, NTT>, Witness<NTT>)>,
+    step: Option<(CCCS<C, NTT>, Witness<NTT>)>,
+    folded_steps: Vec<CCCS<C, NTT>>,
+    linearization_proof: Option<LinearizationProof<NTT>>,
+    folding_proofs: Vec<LFProof<C, NTT>>,
+    _p: PhantomData<P>,
+    _t: PhantomData<T>,
+}
+
+impl<
+        const C: usize,
+        const W: usize,
+        NTT: SuitableRing,
+        P: DecompositionParams,
+        T: TranscriptWithShortChallenges<NTT> + Default,
+        SC: StepCircuit<NTT>,
+    > FoldingLogProver<C, W, NTT, P, T, SC>
+{
+    /// Starts a new computation of `step_circuit` from the initial state `z_0`.
+    pub fn new(
+        step_circuit: SC,
+        scheme: AjtaiCommitmentScheme<C, W, NTT>,
+        z_0: Vec<NTT>,
+    ) -> Result<Self, FoldingLogError<NTT>> {
+        if z_0.len() != step_circuit.arity() {
+            return Err(FoldingLogError::IncorrectStateLength(
+                z_0.len(),
+                step_circuit.arity(),
+            ));
+        }
+
+        let ccs = augmented_ccs::<NTT, P>(step_circuit.step_ccs());
+        let statement = statement_digest::<C, NTT, P>(&ccs, &scheme);
+
+        Ok(Self {
+            step_circuit,
+            ccs,
+            scheme,
+            statement,
+            i: 0,
+            z_i: z_0.clone(),
+            z_0,
+            acc: None,
+            step: None,
+            folded_steps: Vec::new(),
+            linearization_proof: None,
+            folding_proofs: Vec::new(),
+            _p: PhantomData,
+            _t: PhantomData,
+        })
+    }
+
+    /// Returns the augmented constraint system of the computation.
+    pub fn ccs(&self) -> &CCS<NTT> {
+        &self.ccs
+    }
+
+    /// Returns the current state $z_i$.
+    pub fn state(&self) -> &[NTT] {
+        &self.z_i
+    }
+
+    /// Performs one step of the computation.
+    ///
+    /// The instance of the previous step is folded into the running accumulator
+    /// (or linearized into the first accumulator), and a new step instance is produced whose
+    /// public input is bound to the updated accumulator.
+    pub fn prove_step(&mut self) -> Result<(), FoldingLogError<NTT>> {
+        if let Some((u_i, w_i)) = self.step.take() {
+            let mut transcript = T::default();
+
+            self.acc = Some(match self.acc.take() {
+                None => {
+                    // The first step is only linearized, so it binds the statement itself.
+                    bind_statement(&mut transcript, &self.statement);
+                    let (acc, proof) = LFLinearizationProver::<_, T>::prove(
+                        &u_i,
+                        &w_i,
+                        &mut transcript,
+                        &self.ccs,
+                    )?;
+                    self.linearization_proof = Some(proof);
+                    (acc, w_i)
+                }
+                Some((acc, w_acc)) => {
+                    let (acc, w_acc, proof) = NIFSProver::<C, NTT, P, T>::prove(
+                        &acc,
+                        &w_acc,
+                        &u_i,
+                        &w_i,
+                        &mut transcript,
+                        &self.ccs,
+                        &self.scheme,
+                        &self.statement,
+                    )?;
+                    self.folding_proofs.push(proof);
+                    (acc, w_acc)
+                }
+            });
+            self.folded_steps.push(u_i);
+        }
+
+        let (z_next, w_ccs) = self.step_circuit.step(self.i, &self.z_i)?;
+        if z_next.len() != self.step_circuit.arity() {
+            return Err(FoldingLogError::IncorrectStateLength(
+                z_next.len(),
+                self.step_circuit.arity(),
+            ));
+        }
+
+        let hash = hash_step_state::<C, NTT, T>(
+            self.i,
+            &self.z_0,
+            &self.z_i,
+            self.acc.as_ref().map(|(acc, _)| acc),
+        );
+
+        let mut x_ccs = Vec::with_capacity(self.ccs.l);
+        x_ccs.push(hash);
+        x_ccs.extend_from_slice(&self.z_i);
+        x_ccs.extend_from_slice(&z_next);
+
+        let w_i = Witness::from_w_ccs::<P>(w_ccs);
+        let u_i = CCCS {
+            cm: w_i.commit::<C>(&self.scheme)?,
+            x_ccs,
+        };
+
+        self.ccs.check_relation(&u_i.get_z_vector(&w_i.w_ccs))?;
+
+        self.step = Some((u_i, w_i));
+        self.z_i = z_next;
+        self.i += 1;
+
+        Ok(())
+    }
+
+    /// Returns the proof of the computation performed so far.
+    pub fn folding_log(&self) -> Result<FoldingLog<C, NTT>, FoldingLogError<NTT>> {
+        let (u_i, w_i) = self.step.clone().ok_or(FoldingLogError::NoSteps)?;
+
+        Ok(FoldingLog {
+            i: self.i,
+            z_0: self.z_0.clone(),
+            z_i: self.z_i.clone(),
+            folded_steps: self.folded_steps.clone(),
+            linearization_proof: self.linearization_proof.clone(),
+            folding_proofs: self.folding_proofs.clone(),
+            acc: self.acc.clone(),
+            u_i,
+            w_i,
+        })
+    }
+}
+
+/// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
+/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
+/// `NTT` is a suitable cyclotomic ring.
+/// `P` is the decomposition parameters.
+/// `T` is the FS-transform transcript.
+pub struct FoldingLogVerifier<const C: usize, const W: usize, NTT, P, T> {
+    _r: PhantomData<NTT>,
+    _p: PhantomData<P>,
+    _t: PhantomData<T>,
+}
+
+impl<
+        const C: usize,
+        const W: usize,
+        NTT: SuitableRing,
+        P: DecompositionParams,
+        T: TranscriptWithShortChallenges<NTT> + Default,
+    > FoldingLogVerifier<C, W, NTT, P, T>
+{
+    /// Verifies that `proof.z_i` is the result of applying `step_circuit` `proof.i` times to `proof.z_0`.
+    ///
+    /// Replays the chain of folds: checks that the public input of every step instance is bound to
+    /// the state returned by the previous step and to the accumulator it was produced against,
+    /// and verifies every linearization and folding proof to recompute the accumulator.
+    /// Finally, checks that the recomputed accumulator and the last step instance are satisfied by their witnesses.
+    pub fn verify<SC: StepCircuit<NTT>>(
+        step_circuit: &SC,
+        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        proof: &FoldingLog<C, NTT>,
+    ) -> Result<(), FoldingLogError<NTT>> {
+        let arity = step_circuit.arity();
+        if proof.i == 0 {
+            return Err(FoldingLogError::NoSteps);
+        }
+        if proof.z_0.len() != arity {
+            return Err(FoldingLogError::IncorrectStateLength(
+                proof.z_0.len(),
+                arity,
+            ));
+        }
+        if proof.z_i.len() != arity {
+            return Err(FoldingLogError::IncorrectStateLength(
+                proof.z_i.len(),
+                arity,
+            ));
+        }
+        if proof.folded_steps.len() != proof.i - 1
+            || proof.folding_proofs.len() != proof.i.saturating_sub(2)
+            || proof.linearization_proof.is_some() != (proof.i > 1)
+        {
+            return Err(FoldingLogError::IncorrectStepCount(proof.i));
+        }
+
+        let ccs = augmented_ccs::<NTT, P>(step_circuit.step_ccs());
+        if ccs.l != 1 + 2 * arity {
+            return Err(FoldingLogError::StateMismatch);
+        }
+        let vk = VerifierKey::new::<P>(&ccs, scheme);
+
+        let mut acc: Option<LCCCS<C, NTT>> = None;
+        let mut z_prev = proof.z_0.as_slice();
+        let mut folding_proofs = proof.folding_proofs.iter();
+
+        for (j, u_j) in proof.folded_steps.iter().enumerate() {
+            z_prev = check_step_instance::<C, NTT, T>(u_j, j, &proof.z_0, z_prev, acc.as_ref())?;
+
+            let mut transcript = T::default();
+
+            acc = Some(match acc {
+                None => {
+                    bind_statement(&mut transcript, vk.digest());
+                    LFLinearizationVerifier::<_, T>::verify(
+                        u_j,
+                        proof
+                            .linearization_proof
+                            .as_ref()
+                            .ok_or(FoldingLogError::IncorrectStepCount(proof.i))?,
+                        &mut transcript,
+                        &ccs,
+                    )?
+                }
+                Some(acc) => NIFSVerifier::<C, NTT, P, T>::verify(
+                    &acc,
+                    u_j,
+                    folding_proofs
+                        .next()
+                        .ok_or(FoldingLogError::IncorrectStepCount(proof.i))?,
+                    &mut transcript,
+                    &vk,
+                )?,
+            });
+        }
+
+        let z_i = check_step_instance::<C, NTT, T>(
+            &proof.u_i,
+            proof.i - 1,
+            &proof.z_0,
+            z_prev,
+            acc.as_ref(),
+        )?;
+        if z_i != proof.z_i.as_slice() {
+            return Err(FoldingLogError::StateMismatch);
+        }
+
+        proof.u_i.check::<W, P>(&proof.w_i, &ccs, scheme, P::B)?;
+
+        match (acc, &proof.acc) {
+            (None, None) => {}
+            (Some(acc), Some((claimed_acc, w_acc))) if acc == *claimed_acc => {
+                acc.check::<W, P>(w_acc, &ccs, scheme, P::B)?;
+            }
+            _ => return Err(FoldingLogError::UnexpectedAccumulator(proof.i)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the public input of the step instance `u_j` of step `j` (counting from 0) is
+/// `hash || z_prev || z_next`, where `hash` binds the step index, the states and the accumulator `acc` the step was produced against.
+///
+/// Returns `z_next`.
+fn check_step_instance<'a, const C: usize, NTT: SuitableRing, T: Transcript<NTT> + Default>(
+    u_j: &'a CCCS<C, NTT>,
+    j: usize,
+    z_0: &[NTT],
+    z_prev: &[NTT],
+    acc: Option<&LCCCS<C, NTT>>,
+) -> Result<&'a [NTT], FoldingLogError<NTT>> {
+    let arity = z_0.len();
+    if u_j.x_ccs.len() != 1 + 2 * arity {
+        return Err(FoldingLogError::StateMismatch);
+    }
+
+    if &u_j.x_ccs[1..1 + arity] != z_prev {
+        return Err(FoldingLogError::StateMismatch);
+    }
+
+    if u_j.x_ccs[0] != hash_step_state::<C, NTT, T>(j, z_0, z_prev, acc) {
+        return Err(FoldingLogError::AccumulatorHashMismatch);
+    }
+
+    Ok(&u_j.x_ccs[1 + arity..])
+}
+
+/// Hashes the step index, the initial and the current state, and the running accumulator
+/// into a single ring element.
+fn hash_step_state<const C: usize, NTT: SuitableRing, T: Transcript<NTT> + Default>(
+    i: usize,
+    z_0: &[NTT],
+    z_i: &[NTT],
+    acc: Option<&LCCCS<C, NTT>>,
+) -> NTT {
+    let mut transcript = T::default();
+
+    transcript.absorb_label(b"folding_log");
+    transcript.absorb(&NTT::from(i as u64));
+    transcript.absorb_slice(z_0);
+    transcript.absorb_slice(z_i);
+
+    if let Some(acc) = acc {
+        transcript.absorb_slice(&acc.r);
+        transcript.absorb_slice(&acc.v);
+        transcript.absorb_slice(acc.cm.as_ref());
+        transcript.absorb_slice(&acc.u);
+        transcript.absorb_slice(&acc.x_w);
+        transcript.absorb(&acc.h);
+    }
+
+    NTT::from(transcript.get_challenge())
+}
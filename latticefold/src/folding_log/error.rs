@@ -0,0 +1,34 @@
+use stark_rings::Ring;
+use thiserror::Error;
+
+use crate::{
+    arith::error::{CSError, RelationError},
+    commitment::CommitmentError,
+    nifs::error::{LatticefoldError, LinearizationError},
+};
+
+#[derive(Debug, Error)]
+pub enum FoldingLogError<R: Ring> {
+    #[error("folding failed: {0}")]
+    FoldingError(#[from] LatticefoldError<R>),
+    #[error("linearization of the first step failed: {0}")]
+    LinearizationError(#[from] LinearizationError<R>),
+    #[error("ajtai commitment error: {0}")]
+    CommitmentError(#[from] CommitmentError),
+    #[error("constraint system related error: {0}")]
+    ConstraintSystemError(#[from] CSError),
+    #[error("step state has length {0}, expected {1}")]
+    IncorrectStateLength(usize, usize),
+    #[error("no step has been proven yet")]
+    NoSteps,
+    #[error("accumulator hash in the step instance does not match the step state")]
+    AccumulatorHashMismatch,
+    #[error("step instance public input does not match the step state")]
+    StateMismatch,
+    #[error("proof of {0} steps has the wrong number of step instances or folding proofs")]
+    IncorrectStepCount(usize),
+    #[error("accumulator is inconsistent with step {0}")]
+    UnexpectedAccumulator(usize),
+    #[error("instance is not satisfied by its witness: {0}")]
+    RelationError(#[from] RelationError),
+}
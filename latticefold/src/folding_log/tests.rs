@@ -0,0 +1,181 @@
+use ark_std::test_rng;
+use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT};
+use num_traits::{One, Zero};
+use stark_rings::Ring;
+
+use super::*;
+use crate::{
+    arith::r1cs::{to_F_matrix, R1CS},
+    decomposition_parameters::test_params::GoldilocksDP,
+    transcript::poseidon::PoseidonTranscript,
+};
+
+type RqNTT = GoldilocksRingNTT;
+type CS = GoldilocksChallengeSet;
+type DP = GoldilocksDP;
+type T = PoseidonTranscript<RqNTT, CS>;
+
+const C: usize = 4;
+const WIT_LEN: usize = 1;
+const W: usize = WIT_LEN * DP::L;
+
+/// $(a, b) \mapsto (b, a + b)$ with the witness $w = a + b$.
+struct FibonacciCircuit;
+
+impl<R: Ring> StepCircuit<R> for FibonacciCircuit {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn step_ccs(&self) -> CCS<R> {
+        // z = (a, b, a', b', 1, w)
+        let a = to_F_matrix::<R>(vec![
+            vec![1, 1, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 0, 1],
+            vec![0, 1, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 0, 0],
+        ]);
+        let b = to_F_matrix::<R>(vec![
+            vec![0, 0, 0, 0, 1, 0],
+            vec![0, 0, 0, 0, 1, 0],
+            vec![0, 0, 0, 0, 1, 0],
+            vec![0, 0, 0, 0, 0, 0],
+        ]);
+        let c = to_F_matrix::<R>(vec![
+            vec![0, 0, 0, 0, 0, 1],
+            vec![0, 0, 0, 1, 0, 0],
+            vec![0, 0, 1, 0, 0, 0],
+            vec![0, 0, 0, 0, 0, 0],
+        ]);
+
+        CCS::from_r1cs(
+            R1CS {
+                l: 4,
+                A: a,
+                B: b,
+                C: c,
+            },
+            4,
+        )
+    }
+
+    fn step(&self, _i: usize, z_i: &[R]) -> Result<(Vec<R>, Vec<R>), CSError> {
+        let sum = z_i[0] + z_i[1];
+        Ok((vec![z_i[1], sum], vec![sum]))
+    }
+}
+
+fn run_fibonacci(steps: usize) -> (AjtaiCommitmentScheme<C, W, RqNTT>, FoldingLog<C, RqNTT>) {
+    let scheme = AjtaiCommitmentScheme::rand(&mut test_rng());
+
+    let mut prover = FoldingLogProver::<C, W, RqNTT, DP, T, _>::new(
+        FibonacciCircuit,
+        scheme.clone(),
+        vec![RqNTT::zero(), RqNTT::one()],
+    )
+    .unwrap();
+
+    for _ in 0..steps {
+        prover.prove_step().unwrap();
+    }
+
+    (scheme, prover.folding_log().unwrap())
+}
+
+#[test]
+fn test_fibonacci() {
+    let (scheme, proof) = run_fibonacci(5);
+
+    assert_eq!(proof.i, 5);
+    assert_eq!(proof.z_i, vec![RqNTT::from(5u64), RqNTT::from(8u64)]);
+
+    FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof).unwrap();
+}
+
+#[test]
+fn test_fibonacci_single_step() {
+    let (scheme, proof) = run_fibonacci(1);
+
+    assert!(proof.acc.is_none());
+
+    FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof).unwrap();
+}
+
+#[test]
+fn test_folding_log_rejects_wrong_state() {
+    let (scheme, mut proof) = run_fibonacci(3);
+
+    proof.z_i[1] += RqNTT::one();
+
+    assert!(matches!(
+        FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof),
+        Err(FoldingLogError::StateMismatch)
+    ));
+}
+
+#[test]
+fn test_folding_log_rejects_wrong_accumulator() {
+    let (scheme, mut proof) = run_fibonacci(3);
+
+    let (acc, _) = proof.acc.as_mut().unwrap();
+    acc.v[0] += RqNTT::one();
+
+    assert!(matches!(
+        FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof),
+        Err(FoldingLogError::UnexpectedAccumulator(3))
+    ));
+}
+
+#[test]
+fn test_folding_log_rejects_forged_chain() {
+    let scheme = AjtaiCommitmentScheme::rand(&mut test_rng());
+    let mut prover = FoldingLogProver::<C, W, RqNTT, DP, T, _>::new(
+        FibonacciCircuit,
+        scheme.clone(),
+        vec![RqNTT::zero(), RqNTT::one()],
+    )
+    .unwrap();
+
+    prover.prove_step().unwrap();
+    prover.prove_step().unwrap();
+    // Jump to a state that is not reachable in two steps from `z_0`.
+    // The last step instance is still satisfied and bound to the accumulator.
+    prover.z_i = vec![RqNTT::from(5u64), RqNTT::from(8u64)];
+    prover.prove_step().unwrap();
+
+    let proof = prover.folding_log().unwrap();
+    assert_eq!(proof.z_i, vec![RqNTT::from(8u64), RqNTT::from(13u64)]);
+
+    assert!(matches!(
+        FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof),
+        Err(FoldingLogError::StateMismatch)
+    ));
+}
+
+#[test]
+fn test_folding_log_rejects_tampered_folding_proof() {
+    let (scheme, mut proof) = run_fibonacci(4);
+
+    proof.folding_proofs[0].folding_proof.theta_s[0][0] += RqNTT::one();
+
+    assert!(matches!(
+        FoldingLogVerifier::<C, W, RqNTT, DP, T>::verify(&FibonacciCircuit, &scheme, &proof),
+        Err(FoldingLogError::FoldingError(_))
+    ));
+}
+
+#[test]
+fn test_prove_step_rejects_wrong_initial_state() {
+    let scheme = AjtaiCommitmentScheme::rand(&mut test_rng());
+
+    let result = FoldingLogProver::<C, W, RqNTT, DP, T, _>::new(
+        FibonacciCircuit,
+        scheme,
+        vec![RqNTT::zero()],
+    );
+
+    assert!(matches!(
+        result,
+        Err(FoldingLogError::IncorrectStateLength(1, 2))
+    ));
+}
@@ -10,7 +10,9 @@ extern crate alloc;
 
 pub mod arith;
 pub mod commitment;
+pub mod decider;
 pub mod decomposition_parameters;
+pub mod folding_log;
 pub mod nifs;
 pub mod transcript;
 pub mod utils;
@@ -2,31 +2,40 @@
 //!
 //! NIFS = Non Interactive Folding Scheme
 
-use ark_ff::{Field, PrimeField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{marker::PhantomData, vec::Vec};
+use ark_std::{cfg_iter, marker::PhantomData, slice, vec::Vec};
 use cyclotomic_rings::rings::SuitableRing;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use stark_rings::OverField;
 
-use self::{decomposition::*, error::LatticefoldError, folding::*, linearization::*};
+use self::{
+    decomposition::*,
+    error::{BatchVerificationError, LatticefoldError},
+    folding::*,
+    linearization::*,
+    statement::{bind_statement, VerifierKey},
+};
 use crate::{
     arith::{error::CSError, Witness, CCCS, CCS, LCCCS},
-    commitment::AjtaiCommitmentScheme,
-    decomposition_parameters::DecompositionParams,
-    transcript::{Transcript, TranscriptWithShortChallenges},
+    commitment::AjtaiCommitmentKey,
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
+    transcript::{ProtocolStep, Transcript, TranscriptWithShortChallenges},
 };
 
+pub mod container;
 pub mod decomposition;
 pub mod error;
 pub mod folding;
 pub mod linearization;
+pub mod statement;
 
 #[cfg(test)]
 mod tests;
 
 /// `C` is the length of Ajtai commitment vectors.
 /// `NTT` is a cyclotomic ring in the NTT form.
-#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct LFProof<const C: usize, NTT: OverField> {
     pub linearization_proof: LinearizationProof<NTT>,
     pub decomposition_proof_l: DecompositionProof<C, NTT>,
@@ -34,12 +43,88 @@ pub struct LFProof<const C: usize, NTT: OverField> {
     pub folding_proof: FoldingProof<NTT>,
 }
 
+/// A proof of folding several accumulators and several CCCS instances at once.
+///
+/// `C` is the length of Ajtai commitment vectors.
+/// `NTT` is a cyclotomic ring in the NTT form.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LFMultiProof<const C: usize, NTT: OverField> {
+    /// One linearization proof per folded CCCS instance.
+    pub linearization_proofs: Vec<LinearizationProof<NTT>>,
+    /// One decomposition proof per folded accumulator.
+    pub decomposition_proofs_l: Vec<DecompositionProof<C, NTT>>,
+    /// One decomposition proof per linearized CCCS instance.
+    pub decomposition_proofs_r: Vec<DecompositionProof<C, NTT>>,
+    pub folding_proof: FoldingProof<NTT>,
+}
+
+impl<const C: usize, NTT: SuitableRing> LFProof<C, NTT> {
+    /// Checks that every component of the proof has the shape expected for `ccs` and the decomposition parameters `P`.
+    ///
+    /// [`NIFSVerifier::verify`] performs this check itself before touching the proof.
+    pub fn validate<P: DecompositionParams>(
+        &self,
+        ccs: &CCS<NTT>,
+    ) -> Result<(), LatticefoldError<NTT>> {
+        self.validate_with_config(ccs, &P::CONFIG)
+    }
+
+    /// Checks the shape of the proof as [`LFProof::validate`] does, for the decomposition parameters `dp`.
+    pub fn validate_with_config(
+        &self,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<(), LatticefoldError<NTT>> {
+        validate_parts(
+            slice::from_ref(&self.linearization_proof),
+            slice::from_ref(&self.decomposition_proof_l),
+            slice::from_ref(&self.decomposition_proof_r),
+            &self.folding_proof,
+            ccs,
+            dp,
+        )
+    }
+}
+
+impl<const C: usize, NTT: SuitableRing> LFMultiProof<C, NTT> {
+    /// Checks that every component of the proof has the shape expected for `ccs` and the decomposition parameters `P`.
+    ///
+    /// [`NIFSVerifier::verify_multi`] performs this check itself before touching the proof.
+    pub fn validate<P: DecompositionParams>(
+        &self,
+        ccs: &CCS<NTT>,
+    ) -> Result<(), LatticefoldError<NTT>> {
+        self.validate_with_config(ccs, &P::CONFIG)
+    }
+
+    /// Checks the shape of the proof as [`LFMultiProof::validate`] does, for the decomposition parameters `dp`.
+    pub fn validate_with_config(
+        &self,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<(), LatticefoldError<NTT>> {
+        validate_parts(
+            &self.linearization_proofs,
+            &self.decomposition_proofs_l,
+            &self.decomposition_proofs_r,
+            &self.folding_proof,
+            ccs,
+            dp,
+        )
+    }
+}
+
 /// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
-/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
 /// `NTT` is a suitable cyclotomic ring.
 /// `P` is the decomposition parameters.
 /// `T` is the FS-transform transcript.
-pub struct NIFSProver<const C: usize, const W: usize, NTT, P, T> {
+///
+/// The witnesses are committed with any [`AjtaiCommitmentKey`], e.g. with an [`AjtaiCommitmentScheme`](crate::commitment::AjtaiCommitmentScheme)
+/// or with a [`DynAjtaiCommitmentScheme`](crate::commitment::DynAjtaiCommitmentScheme) at least as wide as the witnesses.
+///
+/// Every proof is bound to a `statement`, the digest returned by [`statement::statement_digest`]
+/// for the CCS, the commitment key and the decomposition parameters, which is absorbed before the public input.
+pub struct NIFSProver<const C: usize, NTT, P, T> {
     _r: PhantomData<NTT>,
     _p: PhantomData<P>,
     _t: PhantomData<T>,
@@ -47,12 +132,12 @@ pub struct NIFSProver<const C: usize, const W: usize, NTT, P, T> {
 
 impl<
         const C: usize,
-        const W: usize,
         NTT: SuitableRing,
         P: DecompositionParams,
         T: TranscriptWithShortChallenges<NTT>,
-    > NIFSProver<C, W, NTT, P, T>
+    > NIFSProver<C, NTT, P, T>
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn prove(
         acc: &LCCCS<C, NTT>,
         w_acc: &Witness<NTT>,
@@ -60,50 +145,207 @@ impl<
         w_i: &Witness<NTT>,
         transcript: &mut impl TranscriptWithShortChallenges<NTT>,
         ccs: &CCS<NTT>,
-        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        statement: &[u8; 32],
     ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, LFProof<C, NTT>), LatticefoldError<NTT>> {
-        sanity_check::<NTT, P>(ccs)?;
+        NIFSProverWithConfig::<C, NTT, T>::prove(
+            acc,
+            w_acc,
+            cm_i,
+            w_i,
+            transcript,
+            ccs,
+            scheme,
+            statement,
+            &P::CONFIG,
+        )
+    }
+
+    /// Folds `acc_s.len()` accumulators and `cm_i_s.len()` CCCS instances into a single accumulator.
+    ///
+    /// Every accumulator and every linearized CCCS instance is decomposed into `P::K` instances
+    /// and all of them are folded together in one run of the folding subprotocol.
+    /// Folding a single accumulator with a single CCCS instance produces the same transcript as [`NIFSProver::prove`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_multi(
+        acc_s: &[LCCCS<C, NTT>],
+        w_acc_s: &[Witness<NTT>],
+        cm_i_s: &[CCCS<C, NTT>],
+        w_i_s: &[Witness<NTT>],
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        statement: &[u8; 32],
+    ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, LFMultiProof<C, NTT>), LatticefoldError<NTT>> {
+        NIFSProverWithConfig::<C, NTT, T>::prove_multi(
+            acc_s,
+            w_acc_s,
+            cm_i_s,
+            w_i_s,
+            transcript,
+            ccs,
+            scheme,
+            statement,
+            &P::CONFIG,
+        )
+    }
+}
 
-        absorb_public_input::<NTT, C>(acc, cm_i, transcript);
+/// The NIFS prover with the decomposition parameters chosen at runtime.
+///
+/// `C`, `NTT` and `T` are as in [`NIFSProver`].
+/// The decomposition parameters are passed to every call as a [`DecompositionConfig`],
+/// which has to pass [`DecompositionConfig::validate`] for the ring `NTT`.
+pub struct NIFSProverWithConfig<const C: usize, NTT, T> {
+    _r: PhantomData<NTT>,
+    _t: PhantomData<T>,
+}
 
-        let (linearized_cm_i, linearization_proof) =
-            LFLinearizationProver::<_, T>::prove(cm_i, w_i, transcript, ccs)?;
-        let (mz_mles_l, decomposed_lcccs_l, decomposed_wit_l, decomposition_proof_l) =
-            LFDecompositionProver::<_, T>::prove::<W, C, P>(acc, w_acc, transcript, ccs, scheme)?;
-        let (mz_mles_r, decomposed_lcccs_r, decomposed_wit_r, decomposition_proof_r) =
-            LFDecompositionProver::<_, T>::prove::<W, C, P>(
-                &linearized_cm_i,
+impl<const C: usize, NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>>
+    NIFSProverWithConfig<C, NTT, T>
+{
+    /// Runs [`NIFSProver::prove`] with the decomposition parameters `dp`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove(
+        acc: &LCCCS<C, NTT>,
+        w_acc: &Witness<NTT>,
+        cm_i: &CCCS<C, NTT>,
+        w_i: &Witness<NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        statement: &[u8; 32],
+        dp: &DecompositionConfig,
+    ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, LFProof<C, NTT>), LatticefoldError<NTT>> {
+        let (folded_lcccs, wit, proof) = Self::prove_multi(
+            slice::from_ref(acc),
+            slice::from_ref(w_acc),
+            slice::from_ref(cm_i),
+            slice::from_ref(w_i),
+            transcript,
+            ccs,
+            scheme,
+            statement,
+            dp,
+        )?;
+
+        let LFMultiProof {
+            mut linearization_proofs,
+            mut decomposition_proofs_l,
+            mut decomposition_proofs_r,
+            folding_proof,
+        } = proof;
+
+        Ok((
+            folded_lcccs,
+            wit,
+            LFProof {
+                linearization_proof: linearization_proofs
+                    .pop()
+                    .ok_or(LatticefoldError::IncorrectLength)?,
+                decomposition_proof_l: decomposition_proofs_l
+                    .pop()
+                    .ok_or(LatticefoldError::IncorrectLength)?,
+                decomposition_proof_r: decomposition_proofs_r
+                    .pop()
+                    .ok_or(LatticefoldError::IncorrectLength)?,
+                folding_proof,
+            },
+        ))
+    }
+
+    /// Runs [`NIFSProver::prove_multi`] with the decomposition parameters `dp`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_multi(
+        acc_s: &[LCCCS<C, NTT>],
+        w_acc_s: &[Witness<NTT>],
+        cm_i_s: &[CCCS<C, NTT>],
+        w_i_s: &[Witness<NTT>],
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        statement: &[u8; 32],
+        dp: &DecompositionConfig,
+    ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, LFMultiProof<C, NTT>), LatticefoldError<NTT>> {
+        sanity_check(ccs, dp)?;
+
+        if acc_s.len() != w_acc_s.len()
+            || cm_i_s.len() != w_i_s.len()
+            || acc_s.len() + cm_i_s.len() == 0
+        {
+            return Err(LatticefoldError::IncorrectLength);
+        }
+
+        transcript.begin_step(ProtocolStep::PublicInput);
+        bind_statement(transcript, statement);
+        absorb_public_input::<NTT, C>(acc_s, cm_i_s, transcript);
+
+        transcript.begin_step(ProtocolStep::Linearization);
+        let (linearized_cm_i_s, linearization_proofs): (Vec<_>, Vec<_>) = cm_i_s
+            .iter()
+            .zip(w_i_s)
+            .map(|(cm_i, w_i)| LFLinearizationProver::<_, T>::prove(cm_i, w_i, transcript, ccs))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        let num_instances = acc_s.len() + cm_i_s.len();
+        let mut mz_mles = Vec::with_capacity(num_instances * dp.k);
+        let mut lcccs = Vec::with_capacity(num_instances * dp.k);
+        let mut wit_s = Vec::with_capacity(num_instances * dp.k);
+
+        transcript.begin_step(ProtocolStep::Decomposition);
+        let mut decomposition_proofs_l = Vec::with_capacity(acc_s.len());
+        for (acc, w_acc) in acc_s.iter().zip(w_acc_s) {
+            let (
+                mut mz_mles_l,
+                mut decomposed_lcccs_l,
+                mut decomposed_wit_l,
+                decomposition_proof_l,
+            ) = LFDecompositionProver::<_, T>::prove_with_config(
+                acc, w_acc, transcript, ccs, scheme, dp,
+            )?;
+
+            mz_mles.append(&mut mz_mles_l);
+            lcccs.append(&mut decomposed_lcccs_l);
+            wit_s.append(&mut decomposed_wit_l);
+            decomposition_proofs_l.push(decomposition_proof_l);
+        }
+
+        let mut decomposition_proofs_r = Vec::with_capacity(cm_i_s.len());
+        for (linearized_cm_i, w_i) in linearized_cm_i_s.iter().zip(w_i_s) {
+            let (
+                mut mz_mles_r,
+                mut decomposed_lcccs_r,
+                mut decomposed_wit_r,
+                decomposition_proof_r,
+            ) = LFDecompositionProver::<_, T>::prove_with_config(
+                linearized_cm_i,
                 w_i,
                 transcript,
                 ccs,
                 scheme,
+                dp,
             )?;
 
-        let (mz_mles, lcccs, wit_s) = {
-            let mut lcccs = decomposed_lcccs_l;
-            let mut lcccs_r = decomposed_lcccs_r;
-            lcccs.append(&mut lcccs_r);
-
-            let mut wit_s = decomposed_wit_l;
-            let mut wit_s_r = decomposed_wit_r;
-            wit_s.append(&mut wit_s_r);
-
-            let mut mz_mles = mz_mles_l;
-            let mut mz_mles_r = mz_mles_r;
             mz_mles.append(&mut mz_mles_r);
-            (mz_mles, lcccs, wit_s)
-        };
+            lcccs.append(&mut decomposed_lcccs_r);
+            wit_s.append(&mut decomposed_wit_r);
+            decomposition_proofs_r.push(decomposition_proof_r);
+        }
 
-        let (folded_lcccs, wit, folding_proof) =
-            LFFoldingProver::<_, T>::prove::<C, P>(&lcccs, wit_s, transcript, ccs, &mz_mles)?;
+        transcript.begin_step(ProtocolStep::Folding);
+        let (folded_lcccs, wit, folding_proof) = LFFoldingProver::<_, T>::prove_with_config(
+            &lcccs, wit_s, transcript, ccs, &mz_mles, dp,
+        )?;
 
         Ok((
             folded_lcccs,
             wit,
-            LFProof {
-                linearization_proof,
-                decomposition_proof_l,
-                decomposition_proof_r,
+            LFMultiProof {
+                linearization_proofs,
+                decomposition_proofs_l,
+                decomposition_proofs_r,
                 folding_proof,
             },
         ))
@@ -111,10 +353,12 @@ impl<
 }
 
 /// `C` is the length of commitment vectors or, equivalently, the number of rows of the Ajtai matrix.
-/// `W` is the length of witness vectors or, equivalently, the number of columns of the Ajtai matrix.
 /// `NTT` is a suitable cyclotomic ring.
 /// `P` is the decomposition parameters.
 /// `T` is the FS-transform transcript.
+///
+/// Proofs are verified against a [`VerifierKey`] built for `P`, and only accepted
+/// if they were produced for the [`statement::statement_digest`] of the key, see [`NIFSProver`].
 pub struct NIFSVerifier<const C: usize, NTT, P, T> {
     _r: PhantomData<NTT>,
     _p: PhantomData<P>,
@@ -133,79 +377,319 @@ impl<
         cm_i: &CCCS<C, NTT>,
         proof: &LFProof<C, NTT>,
         transcript: &mut impl TranscriptWithShortChallenges<NTT>,
-        ccs: &CCS<NTT>,
+        vk: &VerifierKey<C, NTT>,
     ) -> Result<LCCCS<C, NTT>, LatticefoldError<NTT>> {
-        sanity_check::<NTT, P>(ccs)?;
+        check_verifier_key::<C, NTT, P>(vk)?;
 
-        absorb_public_input::<NTT, C>(acc, cm_i, transcript);
+        NIFSVerifierWithConfig::<C, NTT, T>::verify(acc, cm_i, proof, transcript, vk)
+    }
 
-        let linearized_cm_i = LFLinearizationVerifier::<_, T>::verify(
-            cm_i,
-            &proof.linearization_proof,
-            transcript,
-            ccs,
-        )?;
-        let decomposed_acc = LFDecompositionVerifier::<_, T>::verify::<C, P>(
-            acc,
-            &proof.decomposition_proof_l,
+    /// Verifies a proof produced by [`NIFSProver::prove_multi`].
+    pub fn verify_multi(
+        acc_s: &[LCCCS<C, NTT>],
+        cm_i_s: &[CCCS<C, NTT>],
+        proof: &LFMultiProof<C, NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        vk: &VerifierKey<C, NTT>,
+    ) -> Result<LCCCS<C, NTT>, LatticefoldError<NTT>> {
+        check_verifier_key::<C, NTT, P>(vk)?;
+
+        NIFSVerifierWithConfig::<C, NTT, T>::verify_multi(acc_s, cm_i_s, proof, transcript, vk)
+    }
+
+    /// Verifies a batch of independent proofs produced for the same CCS.
+    ///
+    /// Every instance is verified as by [`NIFSVerifier::verify`] with its own copy of `transcript`,
+    /// so an instance is accepted here if and only if it is accepted by [`NIFSVerifier::verify`].
+    /// With the `parallel` feature enabled, the instances are verified in parallel.
+    ///
+    /// The checks of the CCS against `P` and the powers of `P::B_SMALL` used by the decomposition verifier
+    /// are computed once for the whole batch, and the statement digest once for `vk`.
+    /// This is all the verifier precomputes per CCS:
+    /// unlike the prover, it never builds MLEs of the CCS matrices, and it only evaluates $\mathrm{eq}$
+    /// at points derived from the challenges of each instance, so there are no tables to share.
+    ///
+    /// On success, returns the folded accumulators in the order of `instances`.
+    /// Otherwise, returns the indices of all rejected instances along with the reasons.
+    pub fn verify_batch(
+        instances: &[(LCCCS<C, NTT>, CCCS<C, NTT>, LFProof<C, NTT>)],
+        transcript: &T,
+        vk: &VerifierKey<C, NTT>,
+    ) -> Result<Vec<LCCCS<C, NTT>>, BatchVerificationError<NTT>>
+    where
+        T: Clone + Sync,
+    {
+        check_verifier_key::<C, NTT, P>(vk)?;
+
+        NIFSVerifierWithConfig::<C, NTT, T>::verify_batch(instances, transcript, vk)
+    }
+}
+
+/// The NIFS verifier with the decomposition parameters chosen at runtime.
+///
+/// `C`, `NTT` and `T` are as in [`NIFSVerifier`].
+/// The decomposition parameters are the ones the [`VerifierKey`] was built for,
+/// see [`VerifierKey::with_config`], and have to match the ones the proof was produced with.
+pub struct NIFSVerifierWithConfig<const C: usize, NTT, T> {
+    _r: PhantomData<NTT>,
+    _t: PhantomData<T>,
+}
+
+impl<const C: usize, NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>>
+    NIFSVerifierWithConfig<C, NTT, T>
+{
+    /// Runs [`NIFSVerifier::verify`] with the decomposition parameters of `vk`.
+    pub fn verify(
+        acc: &LCCCS<C, NTT>,
+        cm_i: &CCCS<C, NTT>,
+        proof: &LFProof<C, NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        vk: &VerifierKey<C, NTT>,
+    ) -> Result<LCCCS<C, NTT>, LatticefoldError<NTT>> {
+        sanity_check(vk.ccs(), vk.config())?;
+
+        Self::verify_parts(
+            slice::from_ref(acc),
+            slice::from_ref(cm_i),
+            slice::from_ref(&proof.linearization_proof),
+            slice::from_ref(&proof.decomposition_proof_l),
+            slice::from_ref(&proof.decomposition_proof_r),
+            &proof.folding_proof,
             transcript,
-            ccs,
-        )?;
-        let decomposed_cm_i = LFDecompositionVerifier::<_, T>::verify::<C, P>(
-            &linearized_cm_i,
-            &proof.decomposition_proof_r,
+            vk,
+            &LFDecompositionVerifier::<NTT, T>::calculate_b_s(vk.config()),
+        )
+    }
+
+    /// Runs [`NIFSVerifier::verify_multi`] with the decomposition parameters of `vk`.
+    pub fn verify_multi(
+        acc_s: &[LCCCS<C, NTT>],
+        cm_i_s: &[CCCS<C, NTT>],
+        proof: &LFMultiProof<C, NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        vk: &VerifierKey<C, NTT>,
+    ) -> Result<LCCCS<C, NTT>, LatticefoldError<NTT>> {
+        sanity_check(vk.ccs(), vk.config())?;
+
+        Self::verify_parts(
+            acc_s,
+            cm_i_s,
+            &proof.linearization_proofs,
+            &proof.decomposition_proofs_l,
+            &proof.decomposition_proofs_r,
+            &proof.folding_proof,
             transcript,
+            vk,
+            &LFDecompositionVerifier::<NTT, T>::calculate_b_s(vk.config()),
+        )
+    }
+
+    /// Runs [`NIFSVerifier::verify_batch`] with the decomposition parameters of `vk`.
+    pub fn verify_batch(
+        instances: &[(LCCCS<C, NTT>, CCCS<C, NTT>, LFProof<C, NTT>)],
+        transcript: &T,
+        vk: &VerifierKey<C, NTT>,
+    ) -> Result<Vec<LCCCS<C, NTT>>, BatchVerificationError<NTT>>
+    where
+        T: Clone + Sync,
+    {
+        sanity_check(vk.ccs(), vk.config())?;
+        let b_s = LFDecompositionVerifier::<NTT, T>::calculate_b_s(vk.config());
+
+        let results: Vec<_> = cfg_iter!(instances)
+            .map(|(acc, cm_i, proof)| {
+                Self::verify_parts(
+                    slice::from_ref(acc),
+                    slice::from_ref(cm_i),
+                    slice::from_ref(&proof.linearization_proof),
+                    slice::from_ref(&proof.decomposition_proof_l),
+                    slice::from_ref(&proof.decomposition_proof_r),
+                    &proof.folding_proof,
+                    &mut transcript.clone(),
+                    vk,
+                    &b_s,
+                )
+            })
+            .collect();
+
+        let mut folded_accs = Vec::with_capacity(results.len());
+        let mut rejected = Vec::new();
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(folded_acc) => folded_accs.push(folded_acc),
+                Err(err) => rejected.push((i, err)),
+            }
+        }
+
+        if rejected.is_empty() {
+            Ok(folded_accs)
+        } else {
+            Err(BatchVerificationError::Rejected(rejected))
+        }
+    }
+
+    /// Verifies the parts of a proof, assuming the CCS of `vk` has already been sanity-checked.
+    ///
+    /// `b_s` are the powers of `vk.config().b_small` returned by [`LFDecompositionVerifier::calculate_b_s`].
+    #[allow(clippy::too_many_arguments)]
+    fn verify_parts(
+        acc_s: &[LCCCS<C, NTT>],
+        cm_i_s: &[CCCS<C, NTT>],
+        linearization_proofs: &[LinearizationProof<NTT>],
+        decomposition_proofs_l: &[DecompositionProof<C, NTT>],
+        decomposition_proofs_r: &[DecompositionProof<C, NTT>],
+        folding_proof: &FoldingProof<NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        vk: &VerifierKey<C, NTT>,
+        b_s: &[NTT],
+    ) -> Result<LCCCS<C, NTT>, LatticefoldError<NTT>> {
+        let (ccs, dp) = (vk.ccs(), vk.config());
+
+        if acc_s.len() + cm_i_s.len() == 0
+            || linearization_proofs.len() != cm_i_s.len()
+            || decomposition_proofs_l.len() != acc_s.len()
+            || decomposition_proofs_r.len() != cm_i_s.len()
+        {
+            return Err(LatticefoldError::IncorrectLength);
+        }
+
+        validate_parts(
+            linearization_proofs,
+            decomposition_proofs_l,
+            decomposition_proofs_r,
+            folding_proof,
             ccs,
+            dp,
         )?;
 
-        let lcccs_s = {
-            let mut decomposed_acc = decomposed_acc;
-            let mut decomposed_cm_i = decomposed_cm_i;
+        transcript.begin_step(ProtocolStep::PublicInput);
+        bind_statement(transcript, vk.digest());
+        absorb_public_input::<NTT, C>(acc_s, cm_i_s, transcript);
 
-            decomposed_acc.append(&mut decomposed_cm_i);
+        transcript.begin_step(ProtocolStep::Linearization);
+        let linearized_cm_i_s = cm_i_s
+            .iter()
+            .zip(linearization_proofs)
+            .map(|(cm_i, linearization_proof)| {
+                LFLinearizationVerifier::<_, T>::verify(cm_i, linearization_proof, transcript, ccs)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        transcript.begin_step(ProtocolStep::Decomposition);
+        let mut lcccs_s = Vec::with_capacity((acc_s.len() + cm_i_s.len()) * dp.k);
+
+        for (acc, decomposition_proof_l) in acc_s.iter().zip(decomposition_proofs_l) {
+            let mut decomposed_acc = LFDecompositionVerifier::<_, T>::verify_with_b_s(
+                acc,
+                decomposition_proof_l,
+                transcript,
+                ccs,
+                dp,
+                b_s,
+            )?;
+            lcccs_s.append(&mut decomposed_acc);
+        }
 
-            decomposed_acc
-        };
+        for (linearized_cm_i, decomposition_proof_r) in
+            linearized_cm_i_s.iter().zip(decomposition_proofs_r)
+        {
+            let mut decomposed_cm_i = LFDecompositionVerifier::<_, T>::verify_with_b_s(
+                linearized_cm_i,
+                decomposition_proof_r,
+                transcript,
+                ccs,
+                dp,
+                b_s,
+            )?;
+            lcccs_s.append(&mut decomposed_cm_i);
+        }
 
-        Ok(LFFoldingVerifier::<NTT, T>::verify::<C, P>(
+        transcript.begin_step(ProtocolStep::Folding);
+        Ok(LFFoldingVerifier::<NTT, T>::verify_with_config(
             &lcccs_s,
-            &proof.folding_proof,
+            folding_proof,
             transcript,
             ccs,
+            dp,
         )?)
     }
 }
 
-fn sanity_check<NTT: SuitableRing, DP: DecompositionParams>(
+/// Checks that `vk` was built for the decomposition parameters `P`.
+fn check_verifier_key<const C: usize, NTT: SuitableRing, P: DecompositionParams>(
+    vk: &VerifierKey<C, NTT>,
+) -> Result<(), LatticefoldError<NTT>> {
+    if *vk.config() != P::CONFIG {
+        return Err(LatticefoldError::VerifierKeyMismatch(*vk.config()));
+    }
+
+    Ok(())
+}
+
+fn sanity_check<NTT: SuitableRing>(
+    ccs: &CCS<NTT>,
+    dp: &DecompositionConfig,
+) -> Result<(), LatticefoldError<NTT>> {
+    dp.validate::<NTT>()?;
+
+    if ccs.m != usize::max((ccs.n - ccs.l - 1) * dp.l, ccs.m).next_power_of_two() {
+        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, dp.l).into());
+    }
+
+    Ok(())
+}
+
+/// Checks the shape of the parts of a proof folding `decomposition_proofs_l.len()` accumulators
+/// and `decomposition_proofs_r.len()` CCCS instances.
+fn validate_parts<const C: usize, NTT: SuitableRing>(
+    linearization_proofs: &[LinearizationProof<NTT>],
+    decomposition_proofs_l: &[DecompositionProof<C, NTT>],
+    decomposition_proofs_r: &[DecompositionProof<C, NTT>],
+    folding_proof: &FoldingProof<NTT>,
     ccs: &CCS<NTT>,
+    dp: &DecompositionConfig,
 ) -> Result<(), LatticefoldError<NTT>> {
-    if ccs.m != usize::max((ccs.n - ccs.l - 1) * DP::L, ccs.m).next_power_of_two() {
-        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, DP::L).into());
+    if linearization_proofs.len() != decomposition_proofs_r.len() {
+        return Err(LatticefoldError::IncorrectLength);
     }
 
+    for linearization_proof in linearization_proofs {
+        linearization_proof.validate(ccs)?;
+    }
+
+    for decomposition_proof in decomposition_proofs_l.iter().chain(decomposition_proofs_r) {
+        decomposition_proof.validate(ccs, dp)?;
+    }
+
+    folding_proof.validate(
+        (decomposition_proofs_l.len() + decomposition_proofs_r.len()) * dp.k,
+        ccs,
+        dp,
+    )?;
+
     Ok(())
 }
 
 fn absorb_public_input<NTT: SuitableRing, const C: usize>(
-    acc: &LCCCS<C, NTT>,
-    cm_i: &CCCS<C, NTT>,
+    acc_s: &[LCCCS<C, NTT>],
+    cm_i_s: &[CCCS<C, NTT>],
     transcript: &mut impl Transcript<NTT>,
 ) {
-    transcript.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-        <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"acc"),
-    ));
-
-    transcript.absorb_slice(&acc.r);
-    transcript.absorb_slice(&acc.v);
-    transcript.absorb_slice(acc.cm.as_ref());
-    transcript.absorb_slice(&acc.u);
-    transcript.absorb_slice(&acc.x_w);
-    transcript.absorb(&acc.h);
-
-    transcript.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-        <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"cm_i"),
-    ));
-
-    transcript.absorb_slice(cm_i.cm.as_ref());
-    transcript.absorb_slice(&cm_i.x_ccs);
+    for acc in acc_s {
+        transcript.absorb_label(b"acc");
+
+        transcript.absorb_slice(&acc.r);
+        transcript.absorb_slice(&acc.v);
+        transcript.absorb_slice(acc.cm.as_ref());
+        transcript.absorb_slice(&acc.u);
+        transcript.absorb_slice(&acc.x_w);
+        transcript.absorb(&acc.h);
+    }
+
+    for cm_i in cm_i_s {
+        transcript.absorb_label(b"cm_i");
+
+        transcript.absorb_slice(cm_i.cm.as_ref());
+        transcript.absorb_slice(&cm_i.x_ccs);
+    }
 }
@@ -0,0 +1,429 @@
+//! A versioned, self-describing file format for [`LFProof`]s.
+//!
+//! A proof file consists of
+//! - the magic bytes [`MAGIC`] and the format [`VERSION`],
+//! - a [`ProofHeader`] describing the ring, the decomposition parameters,
+//!   the Ajtai matrix dimensions and the CCS the proof was produced for,
+//! - the length of the payload followed by the payload, i.e. the compressed canonical serialization of the proof,
+//! - a SHA-256 checksum of everything above.
+//!
+//! All integers are little-endian. [`LFProof::read_from`] checks the checksum and the header
+//! before it deserializes the payload, so a proof produced in a different setting
+//! is rejected with a [`ProofFileError`] instead of being deserialized into garbage.
+
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write};
+use cyclotomic_rings::rings::SuitableRing;
+use sha2::{Digest, Sha256};
+use stark_rings::PolyRing;
+
+use super::{error::ProofFileError, LFProof};
+use crate::{arith::CCS, ark_base::*, decomposition_parameters::DecompositionParams};
+
+/// The magic bytes every proof file starts with.
+pub const MAGIC: [u8; 8] = *b"LFPROOF\0";
+
+/// The current version of the proof file format.
+pub const VERSION: u16 = 1;
+
+/// Length-prefixed fields are read in chunks of this size,
+/// so a corrupted length does not cause a huge allocation up front.
+const READ_CHUNK_SIZE: usize = 4096;
+
+/// Identifies a cyclotomic ring by its base prime field and its dimensions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingId {
+    /// Little-endian bytes of the modulus of the base prime field.
+    pub modulus: Vec<u8>,
+    /// The degree of the base ring of the NTT form over the base prime field.
+    pub extension_degree: u64,
+    /// The number of coefficients of a ring element in the coefficient form.
+    pub coeff_dimension: usize,
+    /// The number of NTT coefficients of a ring element in the NTT form.
+    pub ntt_dimension: usize,
+}
+
+impl RingId {
+    pub fn of<NTT: SuitableRing>() -> Self {
+        Self {
+            modulus: <<NTT::BaseRing as Field>::BasePrimeField as PrimeField>::MODULUS
+                .to_bytes_le(),
+            extension_degree: <NTT::BaseRing as Field>::extension_degree(),
+            coeff_dimension: NTT::CoefficientRepresentation::dimension(),
+            ntt_dimension: NTT::dimension(),
+        }
+    }
+}
+
+/// The setting a proof was produced in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofHeader {
+    pub ring: RingId,
+    /// [`DecompositionParams::B`].
+    pub b: u128,
+    /// [`DecompositionParams::L`].
+    pub l: usize,
+    /// [`DecompositionParams::B_SMALL`].
+    pub b_small: usize,
+    /// [`DecompositionParams::K`].
+    pub k: usize,
+    /// The number of rows of the Ajtai matrix.
+    pub c: usize,
+    /// The number of columns of the Ajtai matrix.
+    pub w: usize,
+    /// SHA-256 digest of the compressed canonical serialization of the CCS.
+    pub ccs_digest: [u8; 32],
+}
+
+impl ProofHeader {
+    pub fn new<const C: usize, const W: usize, NTT: SuitableRing, P: DecompositionParams>(
+        ccs: &CCS<NTT>,
+    ) -> Result<Self, SerializationError> {
+        let mut ccs_bytes = Vec::with_capacity(ccs.compressed_size());
+        ccs.serialize_compressed(&mut ccs_bytes)?;
+
+        Ok(Self {
+            ring: RingId::of::<NTT>(),
+            b: P::B,
+            l: P::L,
+            b_small: P::B_SMALL,
+            k: P::K,
+            c: C,
+            w: W,
+            ccs_digest: Sha256::digest(&ccs_bytes).into(),
+        })
+    }
+
+    /// Checks that a proof with this header can be read in the setting described by `expected`.
+    pub fn check(&self, expected: &Self) -> Result<(), ProofFileError> {
+        if self.ring != expected.ring {
+            return Err(ProofFileError::RingMismatch);
+        }
+
+        for (name, found, expected) in [
+            ("B", self.b, expected.b),
+            ("L", self.l as u128, expected.l as u128),
+            ("B_SMALL", self.b_small as u128, expected.b_small as u128),
+            ("K", self.k as u128, expected.k as u128),
+            ("C", self.c as u128, expected.c as u128),
+            ("W", self.w as u128, expected.w as u128),
+        ] {
+            if found != expected {
+                return Err(ProofFileError::ParameterMismatch {
+                    name,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        if self.ccs_digest != expected.ccs_digest {
+            return Err(ProofFileError::CCSMismatch);
+        }
+
+        Ok(())
+    }
+
+    fn write<Wr: Write>(&self, mut writer: Wr) -> Result<(), SerializationError> {
+        let ring = &self.ring;
+        ring.modulus.len().serialize_uncompressed(&mut writer)?;
+        writer.write_all(&ring.modulus)?;
+        ring.extension_degree.serialize_uncompressed(&mut writer)?;
+        ring.coeff_dimension.serialize_uncompressed(&mut writer)?;
+        ring.ntt_dimension.serialize_uncompressed(&mut writer)?;
+
+        writer.write_all(&self.b.to_le_bytes())?;
+        for param in [self.l, self.b_small, self.k, self.c, self.w] {
+            param.serialize_uncompressed(&mut writer)?;
+        }
+
+        Ok(writer.write_all(&self.ccs_digest)?)
+    }
+
+    fn read<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let modulus_len = usize::deserialize_uncompressed(&mut reader)?;
+        let ring = RingId {
+            modulus: read_bytes(&mut reader, modulus_len)?,
+            extension_degree: u64::deserialize_uncompressed(&mut reader)?,
+            coeff_dimension: usize::deserialize_uncompressed(&mut reader)?,
+            ntt_dimension: usize::deserialize_uncompressed(&mut reader)?,
+        };
+
+        let b = u128::from_le_bytes(read_array(&mut reader)?);
+        let mut params = [0usize; 5];
+        for param in params.iter_mut() {
+            *param = usize::deserialize_uncompressed(&mut reader)?;
+        }
+        let [l, b_small, k, c, w] = params;
+
+        Ok(Self {
+            ring,
+            b,
+            l,
+            b_small,
+            k,
+            c,
+            w,
+            ccs_digest: read_array(&mut reader)?,
+        })
+    }
+}
+
+fn read_array<const N: usize, R: Read>(mut reader: R) -> Result<[u8; N], SerializationError> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_bytes<R: Read>(mut reader: R, len: usize) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = Vec::new();
+    while bytes.len() < len {
+        let chunk_len = READ_CHUNK_SIZE.min(len - bytes.len());
+        let start = bytes.len();
+        bytes.resize(start + chunk_len, 0);
+        reader.read_exact(&mut bytes[start..])?;
+    }
+    Ok(bytes)
+}
+
+/// Hashes everything that is read through it.
+struct ChecksumReader<R> {
+    reader: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> ark_std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<const C: usize, NTT: SuitableRing> LFProof<C, NTT> {
+    /// Writes the proof to `writer` in the proof file format,
+    /// recording that it was produced for `ccs` with an Ajtai matrix of `W` columns and the decomposition parameters `P`.
+    pub fn write_to<const W: usize, P: DecompositionParams, Wr: Write>(
+        &self,
+        mut writer: Wr,
+        ccs: &CCS<NTT>,
+    ) -> Result<(), ProofFileError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        VERSION.serialize_uncompressed(&mut bytes)?;
+        ProofHeader::new::<C, W, NTT, P>(ccs)?.write(&mut bytes)?;
+        self.compressed_size().serialize_uncompressed(&mut bytes)?;
+        self.serialize_compressed(&mut bytes)?;
+
+        let checksum: [u8; 32] = Sha256::digest(&bytes).into();
+        bytes.extend_from_slice(&checksum);
+
+        writer.write_all(&bytes).map_err(SerializationError::from)?;
+
+        Ok(())
+    }
+
+    /// Reads a proof in the proof file format from `reader`.
+    ///
+    /// Fails if the file is corrupted or if the proof was not produced for `ccs`,
+    /// an Ajtai matrix of `C` rows and `W` columns, the ring `NTT` and the decomposition parameters `P`.
+    pub fn read_from<const W: usize, P: DecompositionParams, R: Read>(
+        reader: R,
+        ccs: &CCS<NTT>,
+    ) -> Result<Self, ProofFileError> {
+        let mut reader = ChecksumReader {
+            reader,
+            hasher: Sha256::new(),
+        };
+
+        if read_array(&mut reader)? != MAGIC {
+            return Err(ProofFileError::BadMagic);
+        }
+
+        let version = u16::deserialize_uncompressed(&mut reader)?;
+        if version != VERSION {
+            return Err(ProofFileError::UnsupportedVersion(version));
+        }
+
+        let header = ProofHeader::read(&mut reader)?;
+        let payload_len = usize::deserialize_uncompressed(&mut reader)?;
+        let payload = read_bytes(&mut reader, payload_len)?;
+
+        let ChecksumReader { mut reader, hasher } = reader;
+        let checksum: [u8; 32] = hasher.finalize().into();
+        if read_array(&mut reader)? != checksum {
+            return Err(ProofFileError::ChecksumMismatch);
+        }
+
+        header.check(&ProofHeader::new::<C, W, NTT, P>(ccs)?)?;
+
+        Ok(Self::deserialize_compressed(payload.as_slice())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::test_rng;
+    use cyclotomic_rings::rings::{BabyBearRingNTT, GoldilocksChallengeSet, GoldilocksRingNTT};
+    use num_traits::One;
+    use rand::Rng;
+
+    use super::*;
+    use crate::{
+        arith::{r1cs::get_test_z_split, tests::get_test_ccs, Witness, CCCS},
+        commitment::AjtaiCommitmentScheme,
+        decomposition_parameters::test_params::{BabyBearDP, GoldilocksDP, DP},
+        nifs::{
+            linearization::{LFLinearizationProver, LinearizationProver},
+            statement::{statement_digest, VerifierKey},
+            NIFSProver, NIFSVerifier,
+        },
+        transcript::poseidon::PoseidonTranscript,
+    };
+
+    type RqNTT = GoldilocksRingNTT;
+    type CS = GoldilocksChallengeSet;
+    type T = PoseidonTranscript<RqNTT, CS>;
+
+    const C: usize = 4;
+    const WIT_LEN: usize = 4;
+    const W: usize = WIT_LEN * GoldilocksDP::L;
+
+    fn setup() -> (Vec<u8>, CCS<RqNTT>) {
+        let ccs = get_test_ccs::<RqNTT>(W, GoldilocksDP::L);
+        let mut rng = test_rng();
+        let scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::rand(&mut rng);
+
+        let (_, x_ccs, w_ccs) = get_test_z_split::<RqNTT>(rng.gen_range(0..64));
+        let w_i = Witness::from_w_ccs::<GoldilocksDP>(w_ccs);
+        let cm_i = CCCS {
+            cm: w_i.commit::<C>(&scheme).unwrap(),
+            x_ccs,
+        };
+        let statement = statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &scheme);
+        let vk = VerifierKey::new::<GoldilocksDP>(&ccs, &scheme);
+        let (acc, _) =
+            LFLinearizationProver::<_, T>::prove(&cm_i, &w_i, &mut T::default(), &ccs).unwrap();
+
+        let (_, _, proof) = NIFSProver::<C, RqNTT, GoldilocksDP, T>::prove(
+            &acc,
+            &w_i,
+            &cm_i,
+            &w_i,
+            &mut T::default(),
+            &ccs,
+            &scheme,
+            &statement,
+        )
+        .unwrap();
+        NIFSVerifier::<C, RqNTT, GoldilocksDP, T>::verify(
+            &acc,
+            &cm_i,
+            &proof,
+            &mut T::default(),
+            &vk,
+        )
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        proof
+            .write_to::<W, GoldilocksDP, _>(&mut bytes, &ccs)
+            .unwrap();
+
+        (bytes, ccs)
+    }
+
+    #[test]
+    fn test_proof_file_round_trip() {
+        let (bytes, ccs) = setup();
+
+        let proof =
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(bytes.as_slice(), &ccs).unwrap();
+
+        let mut rewritten = Vec::new();
+        proof
+            .write_to::<W, GoldilocksDP, _>(&mut rewritten, &ccs)
+            .unwrap();
+        assert_eq!(rewritten, bytes);
+    }
+
+    #[test]
+    fn test_proof_file_rejects_bad_magic() {
+        let (mut bytes, ccs) = setup();
+        bytes[0] ^= 1;
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::BadMagic)
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_unsupported_version() {
+        let (mut bytes, ccs) = setup();
+        bytes[MAGIC.len()] += 1;
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_corrupted_payload() {
+        let (mut bytes, ccs) = setup();
+        let len = bytes.len();
+        bytes[len - 64] ^= 1;
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::ChecksumMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_truncated_file() {
+        let (bytes, ccs) = setup();
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(&bytes[..bytes.len() - 1], &ccs),
+            Err(ProofFileError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_other_ring() {
+        let (bytes, _) = setup();
+        let ccs = get_test_ccs::<BabyBearRingNTT>(W, GoldilocksDP::L);
+
+        assert!(matches!(
+            LFProof::<C, BabyBearRingNTT>::read_from::<W, BabyBearDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::RingMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_other_parameters() {
+        let (bytes, ccs) = setup();
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, DP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::ParameterMismatch { name: "B", .. })
+        ));
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<{ W + 1 }, GoldilocksDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::ParameterMismatch { name: "W", .. })
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_rejects_other_ccs() {
+        let (bytes, mut ccs) = setup();
+        ccs.c[0] += RqNTT::one();
+
+        assert!(matches!(
+            LFProof::<C, RqNTT>::read_from::<W, GoldilocksDP, _>(bytes.as_slice(), &ccs),
+            Err(ProofFileError::CCSMismatch)
+        ));
+    }
+}
@@ -5,7 +5,7 @@ use cyclotomic_rings::rings::SuitableRing;
 use num_traits::Zero;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use stark_rings::OverField;
+use stark_rings::{OverField, PolyRing};
 use stark_rings_linalg::SparseMatrix;
 use stark_rings_poly::polynomials::DenseMultilinearExtension;
 
@@ -14,8 +14,8 @@ use self::utils::{decompose_B_vec_into_k_vec, decompose_big_vec_into_k_vec_and_c
 use crate::{
     arith::{error::CSError, utils::mat_vec_mul, Witness, CCS, LCCCS},
     ark_base::*,
-    commitment::{AjtaiCommitmentScheme, Commitment, CommitmentError},
-    decomposition_parameters::DecompositionParams,
+    commitment::{AjtaiCommitmentKey, Commitment, CommitmentError},
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
     nifs::error::DecompositionError,
     transcript::Transcript,
     utils::mle_helpers::{evaluate_mles, to_mles_err},
@@ -31,12 +31,12 @@ mod utils;
 impl<NTT: SuitableRing, T: Transcript<NTT>> DecompositionProver<NTT, T>
     for LFDecompositionProver<NTT, T>
 {
-    fn prove<const W: usize, const C: usize, P: DecompositionParams>(
+    fn prove<const C: usize, P: DecompositionParams>(
         cm_i: &LCCCS<C, NTT>,
         wit: &Witness<NTT>,
         transcript: &mut impl Transcript<NTT>,
         ccs: &CCS<NTT>,
-        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
     ) -> Result<
         (
             Vec<Vec<DenseMultilinearExtension<NTT>>>,
@@ -46,14 +46,36 @@ impl<NTT: SuitableRing, T: Transcript<NTT>> DecompositionProver<NTT, T>
         ),
         DecompositionError,
     > {
-        sanity_check::<NTT, P>(ccs)?;
+        Self::prove_with_config(cm_i, wit, transcript, ccs, scheme, &P::CONFIG)
+    }
+}
+
+impl<NTT: SuitableRing, T: Transcript<NTT>> LFDecompositionProver<NTT, T> {
+    /// Runs [`DecompositionProver::prove`] with the decomposition parameters `dp` chosen at runtime.
+    pub(crate) fn prove_with_config<const C: usize>(
+        cm_i: &LCCCS<C, NTT>,
+        wit: &Witness<NTT>,
+        transcript: &mut impl Transcript<NTT>,
+        ccs: &CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<
+        (
+            Vec<Vec<DenseMultilinearExtension<NTT>>>,
+            Vec<LCCCS<C, NTT>>,
+            Vec<Witness<NTT>>,
+            DecompositionProof<C, NTT>,
+        ),
+        DecompositionError,
+    > {
+        sanity_check(ccs, dp)?;
         let log_m = ccs.s;
 
-        let wit_s: Vec<Witness<NTT>> = Self::decompose_witness::<P>(wit);
+        let wit_s: Vec<Witness<NTT>> = Self::decompose_witness(wit, dp);
 
-        let x_s = Self::compute_x_s::<P>(cm_i.x_w.clone(), cm_i.h);
+        let x_s = Self::compute_x_s(cm_i.x_w.clone(), cm_i.h, dp);
 
-        let y_s: Vec<Commitment<C, NTT>> = Self::commit_witnesses::<C, W, P>(&wit_s, scheme, cm_i)?;
+        let y_s: Vec<Commitment<C, NTT>> = Self::commit_witnesses(&wit_s, scheme, cm_i, dp)?;
 
         let v_s: Vec<Vec<NTT>> = Self::compute_v_s(&wit_s, &cm_i.r)?;
 
@@ -61,7 +83,7 @@ impl<NTT: SuitableRing, T: Transcript<NTT>> DecompositionProver<NTT, T>
 
         let u_s = Self::compute_u_s(&mz_mles, &cm_i.r)?;
 
-        let mut lcccs_s = Vec::with_capacity(P::K);
+        let mut lcccs_s = Vec::with_capacity(dp.k);
 
         for (((x, y), u), v) in x_s.iter().zip(&y_s).zip(&u_s).zip(&v_s) {
             transcript.absorb_slice(x);
@@ -89,16 +111,45 @@ impl<NTT: SuitableRing, T: Transcript<NTT>> DecompositionProver<NTT, T>
     }
 }
 
-impl<NTT: OverField, T: Transcript<NTT>> DecompositionVerifier<NTT, T>
+impl<NTT: SuitableRing, T: Transcript<NTT>> DecompositionVerifier<NTT, T>
     for LFDecompositionVerifier<NTT, T>
 {
     fn verify<const C: usize, P: DecompositionParams>(
         cm_i: &LCCCS<C, NTT>,
         proof: &DecompositionProof<C, NTT>,
         transcript: &mut impl Transcript<NTT>,
-        _ccs: &CCS<NTT>,
+        ccs: &CCS<NTT>,
     ) -> Result<Vec<LCCCS<C, NTT>>, DecompositionError> {
-        let mut lcccs_s = Vec::<LCCCS<C, NTT>>::with_capacity(P::K);
+        Self::verify_with_b_s(
+            cm_i,
+            proof,
+            transcript,
+            ccs,
+            &P::CONFIG,
+            &Self::calculate_b_s(&P::CONFIG),
+        )
+    }
+}
+
+impl<NTT: SuitableRing, T: Transcript<NTT>> LFDecompositionVerifier<NTT, T> {
+    /// Verifies `proof` as [`DecompositionVerifier::verify`] does, with the decomposition parameters `dp`
+    /// and the powers $1, b, \ldots, b^{k-1}$ returned by [`LFDecompositionVerifier::calculate_b_s`]
+    /// computed once by the caller.
+    pub(crate) fn verify_with_b_s<const C: usize>(
+        cm_i: &LCCCS<C, NTT>,
+        proof: &DecompositionProof<C, NTT>,
+        transcript: &mut impl Transcript<NTT>,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+        b_s: &[NTT],
+    ) -> Result<Vec<LCCCS<C, NTT>>, DecompositionError> {
+        proof.validate(ccs, dp)?;
+
+        if cm_i.v.len() != NTT::CoefficientRepresentation::dimension() / NTT::dimension() {
+            return Err(DecompositionError::IncorrectLength);
+        }
+
+        let mut lcccs_s = Vec::<LCCCS<C, NTT>>::with_capacity(dp.k);
 
         for (((x, y), u), v) in proof
             .x_s
@@ -126,29 +177,27 @@ impl<NTT: OverField, T: Transcript<NTT>> DecompositionVerifier<NTT, T>
             });
         }
 
-        let b_s: Vec<_> = Self::calculate_b_s::<P>();
-
-        let should_equal_y0 = Self::recompose_commitment::<C>(&proof.y_s, &b_s)?;
+        let should_equal_y0 = Self::recompose_commitment::<C>(&proof.y_s, b_s)?;
 
         if should_equal_y0 != cm_i.cm {
             return Err(DecompositionError::RecomposedError);
         }
 
-        let should_equal_u0: Vec<NTT> = Self::recompose_u(&proof.u_s, &b_s)?;
+        let should_equal_u0: Vec<NTT> = Self::recompose_u(&proof.u_s, b_s)?;
 
         if should_equal_u0 != cm_i.u {
             return Err(DecompositionError::RecomposedError);
         }
 
         for (row, &cm_i_value) in cm_i.v.iter().enumerate() {
-            let should_equal_v0: NTT = Self::recompose_v(&proof.v_s, &b_s, row);
+            let should_equal_v0: NTT = Self::recompose_v(&proof.v_s, b_s, row);
 
             if should_equal_v0 != cm_i_value {
                 return Err(DecompositionError::RecomposedError);
             }
         }
 
-        let (should_equal_xw, should_equal_h) = Self::recompose_xw_and_h(&proof.x_s, &b_s)?;
+        let (should_equal_xw, should_equal_h) = Self::recompose_xw_and_h(&proof.x_s, b_s)?;
 
         if should_equal_h != cm_i.h {
             return Err(DecompositionError::RecomposedError);
@@ -163,34 +212,35 @@ impl<NTT: OverField, T: Transcript<NTT>> DecompositionVerifier<NTT, T>
 }
 
 impl<NTT: SuitableRing, T: Transcript<NTT>> LFDecompositionProver<NTT, T> {
-    /// Decomposes a witness `wit` into `P::K` vectors norm `< P::B_SMALL` such that
-    /// $$ \text{wit} = \sum\limits_{i=0}^{\text{P::K} - 1} \text{P::B\\_SMALL}^i \cdot \text{wit}_i.$$
+    /// Decomposes a witness `wit` into `dp.k` vectors norm `< dp.b_small` such that
+    /// $$ \text{wit} = \sum\limits_{i=0}^{\text{dp.k} - 1} \text{dp.b\\_small}^i \cdot \text{wit}_i.$$
     ///
-    fn decompose_witness<P: DecompositionParams>(wit: &Witness<NTT>) -> Vec<Witness<NTT>> {
-        let f_s = decompose_B_vec_into_k_vec::<NTT, P>(&wit.f_coeff);
+    fn decompose_witness(wit: &Witness<NTT>, dp: &DecompositionConfig) -> Vec<Witness<NTT>> {
+        let f_s = decompose_B_vec_into_k_vec::<NTT>(&wit.f_coeff, dp);
         cfg_into_iter!(f_s)
-            .map(|f| Witness::from_f_coeff::<P>(f))
+            .map(|f| Witness::from_f_coeff_with_config(f, dp))
             .collect()
     }
 
     /// Takes the concatenation `x_w || h`, performs gadget decomposition of it,
-    /// decomposes the resulting `P::B`-short vector into `P::K` `P::B_SMALL`-vectors
-    /// and gadget-composes each of the vectors back to obtain `P::K` vectors in their NTT form.
-    fn compute_x_s<P: DecompositionParams>(mut x_w: Vec<NTT>, h: NTT) -> Vec<Vec<NTT>> {
+    /// decomposes the resulting `dp.b`-short vector into `dp.k` `dp.b_small`-vectors
+    /// and gadget-composes each of the vectors back to obtain `dp.k` vectors in their NTT form.
+    fn compute_x_s(mut x_w: Vec<NTT>, h: NTT, dp: &DecompositionConfig) -> Vec<Vec<NTT>> {
         x_w.push(h);
-        decompose_big_vec_into_k_vec_and_compose_back::<NTT, P>(x_w)
+        decompose_big_vec_into_k_vec_and_compose_back::<NTT>(x_w, dp)
     }
 
     /// Ajtai commits to witnesses `wit_s` using Ajtai commitment scheme `scheme`.
-    fn commit_witnesses<const C: usize, const W: usize, P: DecompositionParams>(
+    fn commit_witnesses<const C: usize>(
         wit_s: &[Witness<NTT>],
-        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
         cm_i: &LCCCS<C, NTT>,
+        dp: &DecompositionConfig,
     ) -> Result<Vec<Commitment<C, NTT>>, CommitmentError> {
-        let b = NTT::from(P::B_SMALL as u128);
+        let b = NTT::from(dp.b_small as u128);
 
         let commitments_k1: Vec<_> = cfg_iter!(wit_s[1..])
-            .map(|wit| wit.commit::<C, W, P>(scheme))
+            .map(|wit| scheme.commit_ntt(&wit.f))
             .collect::<Result<_, _>>()?;
 
         let b_sum = commitments_k1
@@ -321,18 +371,20 @@ impl<NTT: OverField, T: Transcript<NTT>> LFDecompositionVerifier<NTT, T> {
         Ok((should_equal_xw, should_equal_h))
     }
 
-    fn calculate_b_s<P: DecompositionParams>() -> Vec<NTT> {
-        (0..P::K)
-            .map(|i| NTT::from((P::B_SMALL as u128).pow(i as u32)))
+    /// Returns the powers $1, b, \ldots, b^{k-1}$ of the small decomposition base.
+    pub(crate) fn calculate_b_s(dp: &DecompositionConfig) -> Vec<NTT> {
+        (0..dp.k)
+            .map(|i| NTT::from((dp.b_small as u128).pow(i as u32)))
             .collect()
     }
 }
 
-fn sanity_check<NTT: SuitableRing, DP: DecompositionParams>(
+fn sanity_check<NTT: SuitableRing>(
     ccs: &CCS<NTT>,
+    dp: &DecompositionConfig,
 ) -> Result<(), DecompositionError> {
-    if ccs.m != usize::max((ccs.n - ccs.l - 1) * DP::L, ccs.m).next_power_of_two() {
-        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, DP::L).into());
+    if ccs.m != usize::max((ccs.n - ccs.l - 1) * dp.l, ccs.m).next_power_of_two() {
+        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, dp.l).into());
     }
 
     Ok(())
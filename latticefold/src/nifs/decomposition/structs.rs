@@ -2,14 +2,14 @@
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::marker::PhantomData;
 use cyclotomic_rings::rings::SuitableRing;
-use stark_rings::{OverField, Ring};
+use stark_rings::{OverField, PolyRing, Ring};
 use stark_rings_poly::mle::DenseMultilinearExtension;
 
 use crate::{
     arith::{Witness, CCS, LCCCS},
     ark_base::*,
-    commitment::{AjtaiCommitmentScheme, Commitment},
-    decomposition_parameters::DecompositionParams,
+    commitment::{AjtaiCommitmentKey, Commitment},
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
     nifs::error::DecompositionError,
     transcript::Transcript,
 };
@@ -45,13 +45,37 @@ pub struct DecompositionProof<const C: usize, NTT: Ring> {
     pub y_s: Vec<Commitment<C, NTT>>,
 }
 
+impl<const C: usize, NTT: SuitableRing> DecompositionProof<C, NTT> {
+    /// Checks that the proof consists of `dp.k` decomposed statements of the shape given by `ccs`,
+    /// so that a malformed proof is rejected before it is used.
+    pub fn validate(
+        &self,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<(), DecompositionError> {
+        let tau = NTT::CoefficientRepresentation::dimension() / NTT::dimension();
+        let is_k_by =
+            |m: &[Vec<NTT>], len: usize| m.len() == dp.k && m.iter().all(|row| row.len() == len);
+
+        if !is_k_by(&self.u_s, ccs.t)
+            || !is_k_by(&self.v_s, tau)
+            || !is_k_by(&self.x_s, ccs.l + 1)
+            || self.y_s.len() != dp.k
+        {
+            return Err(DecompositionError::IncorrectLength);
+        }
+
+        Ok(())
+    }
+}
+
 pub trait DecompositionProver<NTT: SuitableRing, T: Transcript<NTT>> {
-    fn prove<const W: usize, const C: usize, P: DecompositionParams>(
+    fn prove<const C: usize, P: DecompositionParams>(
         cm_i: &LCCCS<C, NTT>,
         wit: &Witness<NTT>,
         transcript: &mut impl Transcript<NTT>,
         ccs: &CCS<NTT>,
-        scheme: &AjtaiCommitmentScheme<C, W, NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
     ) -> Result<
         (
             Vec<Vec<DenseMultilinearExtension<NTT>>>,
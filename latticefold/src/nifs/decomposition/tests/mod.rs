@@ -109,7 +109,7 @@ where
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
     let (_, _, _, decomposition_proof) =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::prove::<W, 4, DP>(
+        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::prove::<4, DP>(
             &lcccs,
             &wit,
             &mut prover_transcript,
@@ -137,11 +137,13 @@ fn test_decompose_witness() {
 
     let (_, _, _, _, wit, _) = generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let wit_vec =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness::<DP>(&wit);
+    let wit_vec = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness(
+        &wit,
+        &DP::CONFIG,
+    );
 
     // Compute expected result
-    let f_s = decompose_B_vec_into_k_vec::<RqNTT, DP>(&wit.f_coeff);
+    let f_s = decompose_B_vec_into_k_vec::<RqNTT>(&wit.f_coeff, &DP::CONFIG);
     let expected_wit_vec: Vec<Witness<RqNTT>> = cfg_into_iter!(f_s)
         .map(Witness::from_f_coeff::<DP>)
         .collect();
@@ -171,15 +173,17 @@ fn test_compute_x_s() {
     const W: usize = WIT_LEN * DP::L;
 
     let (lcccs, _, _, _, _, _) = generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
-    let x_s = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_x_s::<DP>(
+    let x_s = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_x_s(
         lcccs.x_w.clone(),
         lcccs.h,
+        &DP::CONFIG,
     );
 
     // Compute expected result
     let mut x_w_clone = lcccs.x_w.clone();
     x_w_clone.push(lcccs.h);
-    let expected_x_s = decompose_big_vec_into_k_vec_and_compose_back::<RqNTT, DP>(x_w_clone);
+    let expected_x_s =
+        decompose_big_vec_into_k_vec_and_compose_back::<RqNTT>(x_w_clone, &DP::CONFIG);
 
     // Validate
     assert!(!x_s.is_empty(), "X_s vector should not be empty");
@@ -205,17 +209,22 @@ fn test_commit_witnesses() {
 
     let (cm_i, _, _, _, wit, scheme) = generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let wit_vec =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness::<DP>(&wit);
+    let wit_vec = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness(
+        &wit,
+        &DP::CONFIG,
+    );
     let y_s: Vec<Commitment<C, RqNTT>> =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::commit_witnesses::<C, W, DP>(
-            &wit_vec, &scheme, &cm_i,
+        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::commit_witnesses::<C>(
+            &wit_vec,
+            &scheme,
+            &cm_i,
+            &DP::CONFIG,
         )
         .unwrap();
 
     // Compute expected result
     let expected_y_s: Vec<Commitment<C, RqNTT>> = cfg_iter!(wit_vec)
-        .map(|wit| wit.commit::<C, W, DP>(&scheme))
+        .map(|wit| wit.commit::<C>(&scheme))
         .collect::<Result<Vec<_>, _>>()
         .unwrap();
 
@@ -237,8 +246,10 @@ fn test_compute_v_s() {
     const W: usize = WIT_LEN * DP::L;
 
     let (lcccs, _, _, _, wit, _) = generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
-    let wit_vec =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness::<DP>(&wit);
+    let wit_vec = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness(
+        &wit,
+        &DP::CONFIG,
+    );
     let v_s =
         LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_v_s(&wit_vec, &lcccs.r)
             .unwrap();
@@ -266,11 +277,14 @@ fn test_compute_u_s() {
     const W: usize = WIT_LEN * DP::L;
 
     let (lcccs, _, _, ccs, wit, _) = generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
-    let wit_vec =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness::<DP>(&wit);
-    let x_s = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_x_s::<DP>(
+    let wit_vec = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::decompose_witness(
+        &wit,
+        &DP::CONFIG,
+    );
+    let x_s = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_x_s(
         lcccs.x_w.clone(),
         lcccs.h,
+        &DP::CONFIG,
     );
     let mz_mles = LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::compute_mz_mles(
         &wit_vec, &ccs.M, &x_s, ccs.s,
@@ -341,7 +355,7 @@ fn test_recompose_commitment() {
     let (lcccs, _, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -350,7 +364,7 @@ fn test_recompose_commitment() {
     )
     .unwrap();
 
-    let b_s = Verifier::calculate_b_s::<DP>();
+    let b_s = Verifier::calculate_b_s(&DP::CONFIG);
 
     let should_equal_y0 =
         Verifier::recompose_commitment::<C>(&proof.y_s, &b_s).expect("Recomposing proof failed");
@@ -371,7 +385,7 @@ fn test_recompose_u() {
 
     let (lcccs, _, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
-    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -380,7 +394,7 @@ fn test_recompose_u() {
     )
     .unwrap();
 
-    let b_s = Verifier::calculate_b_s::<DP>();
+    let b_s = Verifier::calculate_b_s(&DP::CONFIG);
 
     let should_equal_u0 =
         Verifier::recompose_u(&proof.u_s, &b_s).expect("Recomposing proof failed");
@@ -402,7 +416,7 @@ fn test_recompose_v() {
     let (lcccs, _, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -411,7 +425,7 @@ fn test_recompose_v() {
     )
     .unwrap();
 
-    let b_s = Verifier::calculate_b_s::<DP>();
+    let b_s = Verifier::calculate_b_s(&DP::CONFIG);
 
     for (row, &cm_i_value) in lcccs.v.iter().enumerate() {
         let should_equal_v0 = Verifier::recompose_v(&proof.v_s, &b_s, row);
@@ -434,7 +448,7 @@ fn test_recompose_xw_and_h() {
     let (lcccs, _, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -443,7 +457,7 @@ fn test_recompose_xw_and_h() {
     )
     .unwrap();
 
-    let b_s = Verifier::calculate_b_s::<DP>();
+    let b_s = Verifier::calculate_b_s(&DP::CONFIG);
 
     let (should_equal_xw, should_equal_h) =
         Verifier::recompose_xw_and_h(&proof.x_s, &b_s).expect("Recomposing proof failed");
@@ -466,7 +480,7 @@ fn test_verify_full() {
     let (lcccs, mut verifier_transcript, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -493,7 +507,7 @@ fn test_verify_invalid_proof() {
     let (lcccs, mut verifier_transcript, mut prover_transcript, ccs, wit, scheme) =
         generate_decomposition_args::<RqNTT, CS, DP, WIT_LEN, W>();
 
-    let (_, _, _, mut proof) = LFDecompositionProver::<_, T>::prove::<W, C, DP>(
+    let (_, _, _, mut proof) = LFDecompositionProver::<_, T>::prove::<C, DP>(
         &lcccs,
         &wit,
         &mut prover_transcript,
@@ -5,27 +5,25 @@ use stark_rings::{
 };
 use stark_rings_linalg::ops::Transpose;
 
-use crate::{ark_base::*, decomposition_parameters::DecompositionParams};
+use crate::{ark_base::*, decomposition_parameters::DecompositionConfig};
 
-/// Decompose a vector of arbitrary norm in its NTT form into DP::K vectors
+/// Decompose a vector of arbitrary norm in its NTT form into dp.k vectors
 /// and applies the gadget-B matrix again.
-pub(super) fn decompose_big_vec_into_k_vec_and_compose_back<
-    NTT: SuitableRing,
-    DP: DecompositionParams,
->(
+pub(super) fn decompose_big_vec_into_k_vec_and_compose_back<NTT: SuitableRing>(
     x: Vec<NTT>,
+    dp: &DecompositionConfig,
 ) -> Vec<Vec<NTT>> {
     // Allow x to have length m
     let coeff_repr: Vec<NTT::CoefficientRepresentation> = ICRT::elementwise_icrt(x);
 
     // radix-B
     let decomposed_in_B: Vec<NTT::CoefficientRepresentation> =
-        gadget_decompose(&coeff_repr, DP::B, DP::L);
+        gadget_decompose(&coeff_repr, dp.b, dp.l);
 
     // We now have a m * l length vector
     // Each element from original vector is mapped to l-length chunk
 
-    decompose_balanced_vec(&decomposed_in_B, DP::B_SMALL as u128, DP::K)
+    decompose_balanced_vec(&decomposed_in_B, dp.b_small as u128, dp.k)
         // We have a k by (m*l) matrix
         .transpose()
         // We have a (m*l) by k matrix
@@ -33,18 +31,19 @@ pub(super) fn decompose_big_vec_into_k_vec_and_compose_back<
         // We recompose to a m * k matrix
         // Where could recompose basis b horizontally to recreate the original vector
         .map(|vec| {
-            vec.chunks(DP::L)
-                .map(|chunk| recompose(chunk, DP::B).crt())
+            vec.chunks(dp.l)
+                .map(|chunk| recompose(chunk, dp.b).crt())
                 .collect()
         })
         .collect()
 }
 
-/// Decompose a vector of norm B in its coefficient form into DP::K small vectors.
-pub(super) fn decompose_B_vec_into_k_vec<NTT: SuitableRing, DP: DecompositionParams>(
+/// Decompose a vector of norm B in its coefficient form into dp.k small vectors.
+pub(super) fn decompose_B_vec_into_k_vec<NTT: SuitableRing>(
     x: &[NTT::CoefficientRepresentation],
+    dp: &DecompositionConfig,
 ) -> Vec<Vec<NTT::CoefficientRepresentation>> {
-    decompose_balanced_vec(x, DP::B_SMALL as u128, DP::K).transpose()
+    decompose_balanced_vec(x, dp.b_small as u128, dp.k).transpose()
 }
 
 #[cfg(test)]
@@ -93,7 +92,7 @@ mod tests {
             .collect();
 
         // Call the function
-        let decomposed = decompose_B_vec_into_k_vec::<RqNTT, DP>(&test_vector);
+        let decomposed = decompose_B_vec_into_k_vec::<RqNTT>(&test_vector, &DP::CONFIG);
 
         // Check that we get K vectors back from the decomposition
         assert_eq!(
@@ -166,8 +165,10 @@ mod tests {
         let test_vector: Vec<RqNTT> = (0..N)
             .map(|_| draw_ring_below_bound::<RqPoly, { DP::B }>(&mut rng).crt())
             .collect();
-        let decomposed_and_composed_back =
-            decompose_big_vec_into_k_vec_and_compose_back::<RqNTT, DP>(test_vector.clone());
+        let decomposed_and_composed_back = decompose_big_vec_into_k_vec_and_compose_back::<RqNTT>(
+            test_vector.clone(),
+            &DP::CONFIG,
+        );
         let restore_decomposed =
             recompose_from_k_vec_to_big_vec::<RqNTT>(&decomposed_and_composed_back);
 
@@ -1,3 +1,4 @@
+use ark_serialize::SerializationError;
 use stark_rings::Ring;
 use stark_rings_poly::polynomials::ArithErrors;
 use thiserror::Error;
@@ -6,6 +7,7 @@ use crate::{
     arith::error::CSError,
     ark_base::*,
     commitment::CommitmentError,
+    decomposition_parameters::{DecompositionConfig, DecompositionParamsError},
     utils::{mle_helpers::MleEvaluationError, sumcheck::SumCheckError},
 };
 
@@ -19,6 +21,30 @@ pub enum LatticefoldError<R: Ring> {
     FoldingError(#[from] FoldingError<R>),
     #[error("constraint system related error: {0}")]
     ConstraintSystemError(#[from] CSError),
+    #[error("invalid decomposition parameters: {0}")]
+    DecompositionParamsError(#[from] DecompositionParamsError),
+    #[error("input vectors have incorrect length")]
+    IncorrectLength,
+    #[error("the verifier key was built for other decomposition parameters: {0}")]
+    VerifierKeyMismatch(DecompositionConfig),
+}
+
+#[derive(Debug, Error)]
+pub enum BatchVerificationError<R: Ring> {
+    #[error("all proofs rejected: {0}")]
+    LatticefoldError(#[from] LatticefoldError<R>),
+    #[error("{} of the proofs rejected", .0.len())]
+    Rejected(Vec<(usize, LatticefoldError<R>)>),
+}
+
+impl<R: Ring> BatchVerificationError<R> {
+    /// Returns the indices of the rejected instances in a batch of `batch_len` instances.
+    pub fn rejected_indices(&self, batch_len: usize) -> Vec<usize> {
+        match self {
+            Self::LatticefoldError(_) => (0..batch_len).collect(),
+            Self::Rejected(rejected) => rejected.iter().map(|(i, _)| *i).collect(),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -33,6 +59,8 @@ pub enum LinearizationError<R: Ring> {
     ArithmeticError(#[from] ArithErrors),
     #[error("mle evaluation failed: {0}")]
     EvaluationError(#[from] MleEvaluationError),
+    #[error("input vectors have incorrect length")]
+    IncorrectLength,
 }
 
 #[derive(Debug, Error)]
@@ -64,3 +92,31 @@ pub enum FoldingError<R: Ring> {
     #[error("sumcheck challenge point were not generate correctly")]
     SumcheckChallengeError,
 }
+
+#[derive(Debug, Error)]
+pub enum ProofFileError {
+    #[error("not a LatticeFold proof file")]
+    BadMagic,
+    #[error("unsupported proof file version {0}")]
+    UnsupportedVersion(u16),
+    #[error("proof file checksum mismatch")]
+    ChecksumMismatch,
+    #[error("proof was produced over a different ring")]
+    RingMismatch,
+    #[error("proof was produced for {name} = {found}, expected {expected}")]
+    ParameterMismatch {
+        name: &'static str,
+        expected: u128,
+        found: u128,
+    },
+    #[error("proof was produced for a different CCS")]
+    CCSMismatch,
+    #[error("proof file serialization error: {0}")]
+    SerializationError(SerializationError),
+}
+
+impl From<SerializationError> for ProofFileError {
+    fn from(err: SerializationError) -> Self {
+        Self::SerializationError(err)
+    }
+}
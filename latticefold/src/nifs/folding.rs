@@ -18,7 +18,7 @@ use crate::{
     arith::{error::CSError, Witness, CCS, LCCCS},
     ark_base::*,
     commitment::Commitment,
-    decomposition_parameters::DecompositionParams,
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
     transcript::TranscriptWithShortChallenges,
     utils::{
         mle_helpers::evaluate_mles,
@@ -40,13 +40,27 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingProver<NTT
     for LFFoldingProver<NTT, T>
 {
     fn prove<const C: usize, P: DecompositionParams>(
+        cm_i_s: &[LCCCS<C, NTT>],
+        w_s: Vec<Witness<NTT>>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        mz_mles: &[Vec<DenseMultilinearExtension<NTT>>],
+    ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, FoldingProof<NTT>), FoldingError<NTT>> {
+        Self::prove_with_config(cm_i_s, w_s, transcript, ccs, mz_mles, &P::CONFIG)
+    }
+}
+
+impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingProver<NTT, T> {
+    /// Runs [`FoldingProver::prove`] with the decomposition parameters `dp` chosen at runtime.
+    pub(crate) fn prove_with_config<const C: usize>(
         cm_i_s: &[LCCCS<C, NTT>],
         mut w_s: Vec<Witness<NTT>>,
         transcript: &mut impl TranscriptWithShortChallenges<NTT>,
         ccs: &CCS<NTT>,
         mz_mles: &[Vec<DenseMultilinearExtension<NTT>>],
+        dp: &DecompositionConfig,
     ) -> Result<(LCCCS<C, NTT>, Witness<NTT>, FoldingProof<NTT>), FoldingError<NTT>> {
-        sanity_check::<NTT, P>(ccs)?;
+        sanity_check(ccs, dp)?;
 
         // Free some unneeded vars/memory
         w_s.iter_mut().for_each(|w_i| {
@@ -56,14 +70,20 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingProver<NTT
             w_i.w_ccs.shrink_to_fit();
         });
 
-        if cm_i_s.len() != 2 * P::K {
+        if cm_i_s.is_empty()
+            || cm_i_s.len() % dp.k != 0
+            || w_s.len() != cm_i_s.len()
+            || mz_mles.len() != cm_i_s.len()
+        {
             return Err(FoldingError::IncorrectLength);
         }
 
         let log_m = ccs.s;
+        let num_instances = cm_i_s.len() / dp.k;
 
         // Step 1: Generate alpha, zeta, mu, beta challenges
-        let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<P>(log_m);
+        let (alpha_s, beta_s, zeta_s, mu_s) =
+            transcript.squeeze_alpha_beta_zeta_mu(log_m, num_instances, dp);
 
         // Step 2: Compute g polynomial and sumcheck on it
         // Setup f_hat_mle for later evaluation of thetas
@@ -71,22 +91,25 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingProver<NTT
 
         let ris = Self::get_ris(cm_i_s);
 
-        let prechallenged_Ms_1 =
-            Self::calculate_challenged_mz_mle(&mz_mles[0..P::K], &zeta_s[0..P::K])?;
-        let prechallenged_Ms_2 =
-            Self::calculate_challenged_mz_mle(&mz_mles[P::K..2 * P::K], &zeta_s[P::K..2 * P::K])?;
-        let (g_mles, g_degree) = create_sumcheck_polynomial::<_, P>(
+        let prechallenged_Ms = mz_mles
+            .chunks(dp.k)
+            .zip(zeta_s.chunks(dp.k))
+            .map(|(mz_mles_group, zeta_group)| {
+                Self::calculate_challenged_mz_mle(mz_mles_group, zeta_group)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let (g_mles, g_degree) = create_sumcheck_polynomial(
             log_m,
             f_hat_mles.clone(),
             &alpha_s,
-            &prechallenged_Ms_1,
-            &prechallenged_Ms_2,
+            &prechallenged_Ms,
             &ris,
             &beta_s,
             &mu_s,
+            dp,
         )?;
 
-        let comb_fn = |vals: &[NTT]| -> NTT { sumcheck_polynomial_comb_fn::<NTT, P>(vals, &mu_s) };
+        let comb_fn = |vals: &[NTT]| -> NTT { sumcheck_polynomial_comb_fn(vals, &mu_s, dp) };
 
         // Step 5: Run sum check prover
         let (sum_check_proof, prover_state) =
@@ -105,7 +128,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingProver<NTT
         eta_s.iter().for_each(|etas| transcript.absorb_slice(etas));
 
         // Step 5 get rho challenges
-        let (rho_s_coeff, rho_s) = get_rhos::<_, _, P>(transcript);
+        let (rho_s_coeff, rho_s) = get_rhos(transcript, num_instances, dp);
 
         let f_0: Vec<NTT> = Self::compute_f_0(&rho_s, &w_s);
 
@@ -118,7 +141,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingProver<NTT
 
         let lcccs = prepare_public_output(r_0, v_0, cm_0, u_0, x_0, h);
 
-        let w_0 = Witness::from_f::<P>(f_0);
+        let w_0 = Witness::from_f_with_config(f_0, dp);
 
         let folding_proof = FoldingProof {
             pointshift_sumcheck_proof: sum_check_proof,
@@ -139,23 +162,45 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingVerifier<N
         transcript: &mut impl TranscriptWithShortChallenges<NTT>,
         ccs: &CCS<NTT>,
     ) -> Result<LCCCS<C, NTT>, FoldingError<NTT>> {
-        sanity_check::<NTT, P>(ccs)?;
+        Self::verify_with_config(cm_i_s, proof, transcript, ccs, &P::CONFIG)
+    }
+}
+
+impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier<NTT, T> {
+    /// Runs [`FoldingVerifier::verify`] with the decomposition parameters `dp` chosen at runtime.
+    pub(crate) fn verify_with_config<const C: usize>(
+        cm_i_s: &[LCCCS<C, NTT>],
+        proof: &FoldingProof<NTT>,
+        transcript: &mut impl TranscriptWithShortChallenges<NTT>,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<LCCCS<C, NTT>, FoldingError<NTT>> {
+        sanity_check(ccs, dp)?;
+
+        if cm_i_s.is_empty() || cm_i_s.len() % dp.k != 0 {
+            return Err(FoldingError::IncorrectLength);
+        }
+
+        proof.validate(cm_i_s.len(), ccs, dp)?;
+
+        let num_instances = cm_i_s.len() / dp.k;
 
         // Step 1: Generate alpha, zeta, mu, beta challenges and validate input
-        let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<P>(ccs.s);
+        let (alpha_s, beta_s, zeta_s, mu_s) =
+            transcript.squeeze_alpha_beta_zeta_mu(ccs.s, num_instances, dp);
 
         // Calculate claims for sumcheck verification
         let (claim_g1, claim_g3) = Self::calculate_claims(&alpha_s, &zeta_s, cm_i_s);
 
         let nvars = ccs.s;
-        let degree = 2 * P::B_SMALL;
+        let degree = 2 * dp.b_small;
 
         //Step 2: The sumcheck.
         let (r_0, expected_evaluation) =
             Self::verify_sumcheck_proof(transcript, nvars, degree, claim_g1 + claim_g3, proof)?;
 
         // Verify evaluation claim
-        Self::verify_evaluation::<C, P>(
+        Self::verify_evaluation(
             &alpha_s,
             &beta_s,
             &mu_s,
@@ -164,6 +209,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingVerifier<N
             expected_evaluation,
             proof,
             cm_i_s,
+            dp,
         )?;
 
         // Step 5
@@ -175,7 +221,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> FoldingVerifier<N
             .eta_s
             .iter()
             .for_each(|etas| transcript.absorb_slice(etas));
-        let (rho_s_coeff, rho_s) = get_rhos::<_, _, P>(transcript);
+        let (rho_s_coeff, rho_s) = get_rhos(transcript, num_instances, dp);
 
         // Step 6
         let (v_0, cm_0, u_0, x_0) = compute_v0_u0_x0_cm_0(
@@ -270,7 +316,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingProver<N
 
 impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier<NTT, T> {
     #[allow(clippy::too_many_arguments)]
-    fn verify_evaluation<const C: usize, P: DecompositionParams>(
+    fn verify_evaluation<const C: usize>(
         alpha_s: &[NTT],
         beta_s: &[NTT],
         mu_s: &[NTT],
@@ -279,6 +325,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier
         expected_evaluation: NTT,
         proof: &FoldingProof<NTT>,
         cm_i_s: &[LCCCS<C, NTT>],
+        dp: &DecompositionConfig,
     ) -> Result<(), FoldingError<NTT>> {
         let ris = cm_i_s.iter().map(|cm_i| cm_i.r.clone()).collect::<Vec<_>>();
 
@@ -288,7 +335,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier
             .map(|r_i: &Vec<NTT>| eq_eval(r_i, r_0))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let should_equal_s: NTT = compute_sumcheck_claim_expected_value::<NTT, P>(
+        let should_equal_s: NTT = compute_sumcheck_claim_expected_value(
             alpha_s,
             mu_s,
             &proof.theta_s,
@@ -296,6 +343,7 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier
             &e_s,
             zeta_s,
             &proof.eta_s,
+            dp,
         );
 
         if should_equal_s != expected_evaluation {
@@ -373,11 +421,12 @@ impl<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> LFFoldingVerifier
     }
 }
 
-fn sanity_check<NTT: SuitableRing, DP: DecompositionParams>(
+fn sanity_check<NTT: SuitableRing>(
     ccs: &CCS<NTT>,
+    dp: &DecompositionConfig,
 ) -> Result<(), FoldingError<NTT>> {
-    if ccs.m != usize::max((ccs.n - ccs.l - 1) * DP::L, ccs.m).next_power_of_two() {
-        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, DP::L).into());
+    if ccs.m != usize::max((ccs.n - ccs.l - 1) * dp.l, ccs.m).next_power_of_two() {
+        return Err(CSError::InvalidSizeBounds(ccs.m, ccs.n, dp.l).into());
     }
 
     Ok(())
@@ -1,13 +1,13 @@
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::marker::PhantomData;
 use cyclotomic_rings::rings::SuitableRing;
-use stark_rings::OverField;
+use stark_rings::{OverField, PolyRing};
 use stark_rings_poly::mle::DenseMultilinearExtension;
 
 use crate::{
     arith::{Witness, CCS, LCCCS},
     ark_base::Vec,
-    decomposition_parameters::DecompositionParams,
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
     nifs::error::FoldingError,
     transcript::TranscriptWithShortChallenges,
     utils::sumcheck,
@@ -39,6 +39,32 @@ pub struct FoldingProof<NTT: OverField> {
     pub eta_s: Vec<Vec<NTT>>,
 }
 
+impl<NTT: SuitableRing> FoldingProof<NTT> {
+    /// Checks that the proof has the shape the folding verifier expects
+    /// when folding `num_lcccs` decomposed statements for `ccs` with the decomposition parameters `dp`,
+    /// so that a malformed proof is rejected before it is used.
+    pub fn validate(
+        &self,
+        num_lcccs: usize,
+        ccs: &CCS<NTT>,
+        dp: &DecompositionConfig,
+    ) -> Result<(), FoldingError<NTT>> {
+        self.pointshift_sumcheck_proof
+            .check_shape(ccs.s, 2 * dp.b_small)?;
+
+        let tau = NTT::CoefficientRepresentation::dimension() / NTT::dimension();
+        if self.theta_s.len() != num_lcccs
+            || self.eta_s.len() != num_lcccs
+            || self.theta_s.iter().any(|theta| theta.len() != tau)
+            || self.eta_s.iter().any(|eta| eta.len() != ccs.t)
+        {
+            return Err(FoldingError::IncorrectLength);
+        }
+
+        Ok(())
+    }
+}
+
 /// Prover for the folding subprotocol
 pub trait FoldingProver<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>> {
     /// Generates a folded witness and its linearized commitment, along with proof to the correctness of the folding.
@@ -46,6 +72,7 @@ pub trait FoldingProver<NTT: SuitableRing, T: TranscriptWithShortChallenges<NTT>
     /// # Arguments
     ///
     /// * `cm_i_s` - A reference to `[LCCCS<C, NTT>]`, representing decomposed linearized commitments to be folded together.
+    ///   Its length must be a non-zero multiple of `P::K`, every consecutive group of `P::K` commitments sharing the same challenge point `r`.
     /// * `w_s` - A vector of decomposed witnesses to be folded together.
     /// * `transcript` - A mutable reference to a sponge for generating NI challenges.
     /// * `ccs` -  A reference to a Customizable Constraint System instance used in the protocol.
@@ -76,6 +103,7 @@ pub trait FoldingVerifier<NTT: SuitableRing, T: TranscriptWithShortChallenges<NT
     /// # Arguments
     ///
     /// * `cm_i` - A reference to a vector of `CCCS<C, NTT>`, which represents decomposed LCCS statements and commitments to witnesses.
+    ///   Its length must be a non-zero multiple of `P::K`.
     /// * `proof` - A reference to a `FoldingProof<NTT>` containing the folding proof.
     /// * `transcript` - A mutable reference to a sponge for generating NI challenges.
     /// * `ccs` - A reference to a Customizable Constraint System instance used in the protocol.
@@ -82,7 +82,7 @@ where
 
     let wit = Witness::from_w_ccs::<DP>(w_ccs);
     let cm_i = CCCS {
-        cm: wit.commit::<C, W, DP>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
     let mut prover_transcript = PoseidonTranscript::<RqNTT, CS>::default();
@@ -106,7 +106,7 @@ where
     .unwrap();
 
     let (mz_mles, _, wit_vec, decomposition_proof) =
-        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::prove::<W, C, DP>(
+        LFDecompositionProver::<_, PoseidonTranscript<RqNTT, CS>>::prove::<C, DP>(
             &lcccs,
             &wit,
             &mut prover_transcript,
@@ -246,38 +246,39 @@ fn test_get_sumcheck_randomness() {
 
     let (lccs, mut wit_s, mut transcript, ccs, _, mz_mles) =
         setup_test_environment::<RqNTT, CS, DP, C, W>(false);
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
     let f_hat_mles =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::setup_f_hat_mles(&mut wit_s);
 
     let ris = LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::get_ris(&lccs);
 
-    let prechallenged_Ms_1 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[0..DP::K],
-            &zeta_s[0..DP::K],
-        )
-        .unwrap();
-    let prechallenged_Ms_2 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[DP::K..2 * DP::K],
-            &zeta_s[DP::K..2 * DP::K],
-        )
-        .unwrap();
-    let (g_mles, g_degree) = create_sumcheck_polynomial::<_, DP>(
+    let prechallenged_Ms = mz_mles
+        .chunks(DP::K)
+        .zip(zeta_s.chunks(DP::K))
+        .map(|(mz_mles_group, zeta_group)| {
+            LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
+                mz_mles_group,
+                zeta_group,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (g_mles, g_degree) = create_sumcheck_polynomial(
         ccs.s,
         f_hat_mles,
         &alpha_s,
-        &prechallenged_Ms_1,
-        &prechallenged_Ms_2,
+        &prechallenged_Ms,
         &ris,
         &beta_s,
         &mu_s,
+        &DP::CONFIG,
     )
     .unwrap();
 
-    let comb_fn =
-        |vals: &[RqNTT]| -> RqNTT { sumcheck_polynomial_comb_fn::<RqNTT, DP>(vals, &mu_s) };
+    let comb_fn = |vals: &[RqNTT]| -> RqNTT {
+        sumcheck_polynomial_comb_fn::<RqNTT>(vals, &mu_s, &DP::CONFIG)
+    };
 
     // Compute sumcheck proof
     let (_, prover_state) =
@@ -301,38 +302,39 @@ fn test_get_thetas() {
 
     let (lccs, mut wit_s, mut transcript, ccs, _, mz_mles) =
         setup_test_environment::<RqNTT, CS, DP, C, W>(false);
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
     let f_hat_mles =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::setup_f_hat_mles(&mut wit_s);
 
     let ris = LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::get_ris(&lccs);
 
-    let prechallenged_Ms_1 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[0..DP::K],
-            &zeta_s[0..DP::K],
-        )
-        .unwrap();
-    let prechallenged_Ms_2 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[DP::K..2 * DP::K],
-            &zeta_s[DP::K..2 * DP::K],
-        )
-        .unwrap();
-    let (g_mles, g_degree) = create_sumcheck_polynomial::<_, DP>(
+    let prechallenged_Ms = mz_mles
+        .chunks(DP::K)
+        .zip(zeta_s.chunks(DP::K))
+        .map(|(mz_mles_group, zeta_group)| {
+            LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
+                mz_mles_group,
+                zeta_group,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (g_mles, g_degree) = create_sumcheck_polynomial(
         ccs.s,
         f_hat_mles.clone(),
         &alpha_s,
-        &prechallenged_Ms_1,
-        &prechallenged_Ms_2,
+        &prechallenged_Ms,
         &ris,
         &beta_s,
         &mu_s,
+        &DP::CONFIG,
     )
     .unwrap();
 
-    let comb_fn =
-        |vals: &[RqNTT]| -> RqNTT { sumcheck_polynomial_comb_fn::<RqNTT, DP>(vals, &mu_s) };
+    let comb_fn = |vals: &[RqNTT]| -> RqNTT {
+        sumcheck_polynomial_comb_fn::<RqNTT>(vals, &mu_s, &DP::CONFIG)
+    };
 
     let (_, prover_state) =
         MLSumcheck::prove_as_subprotocol(&mut transcript, g_mles, ccs.s, g_degree, comb_fn);
@@ -373,38 +375,39 @@ fn test_get_etas() {
 
     let (lccs, mut wit_s, mut transcript, ccs, _, mz_mles) =
         setup_test_environment::<RqNTT, CS, DP, C, W>(false);
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
     let f_hat_mles =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::setup_f_hat_mles(&mut wit_s);
 
     let ris = LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::get_ris(&lccs);
 
-    let prechallenged_Ms_1 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[0..DP::K],
-            &zeta_s[0..DP::K],
-        )
-        .unwrap();
-    let prechallenged_Ms_2 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[DP::K..2 * DP::K],
-            &zeta_s[DP::K..2 * DP::K],
-        )
-        .unwrap();
-    let (g_mles, g_degree) = create_sumcheck_polynomial::<_, DP>(
+    let prechallenged_Ms = mz_mles
+        .chunks(DP::K)
+        .zip(zeta_s.chunks(DP::K))
+        .map(|(mz_mles_group, zeta_group)| {
+            LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
+                mz_mles_group,
+                zeta_group,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (g_mles, g_degree) = create_sumcheck_polynomial(
         ccs.s,
         f_hat_mles,
         &alpha_s,
-        &prechallenged_Ms_1,
-        &prechallenged_Ms_2,
+        &prechallenged_Ms,
         &ris,
         &beta_s,
         &mu_s,
+        &DP::CONFIG,
     )
     .unwrap();
 
-    let comb_fn =
-        |vals: &[RqNTT]| -> RqNTT { sumcheck_polynomial_comb_fn::<RqNTT, DP>(vals, &mu_s) };
+    let comb_fn = |vals: &[RqNTT]| -> RqNTT {
+        sumcheck_polynomial_comb_fn::<RqNTT>(vals, &mu_s, &DP::CONFIG)
+    };
 
     let (_, prover_state) =
         MLSumcheck::prove_as_subprotocol(&mut transcript, g_mles, ccs.s, g_degree, comb_fn);
@@ -445,7 +448,7 @@ fn test_get_rhos() {
     let (_, _, mut transcript, _, _, _) = setup_test_environment::<RqNTT, CS, DP, C, W>(false);
     let mut transcript_clone = transcript.clone();
 
-    let (rho_s_coeff, rho_s) = get_rhos::<_, _, DP>(&mut transcript);
+    let (rho_s_coeff, rho_s) = get_rhos(&mut transcript, 2, &DP::CONFIG);
 
     // Compute expected result
     transcript_clone.absorb_field_element(&<_>::from_base_prime_field(
@@ -473,38 +476,39 @@ fn test_prepare_public_output() {
 
     let (lccs, mut wit_s, mut transcript, ccs, _, mz_mles) =
         setup_test_environment::<RqNTT, CS, DP, C, W>(false);
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
     let f_hat_mles =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::setup_f_hat_mles(&mut wit_s);
 
     let ris = LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::get_ris(&lccs);
 
-    let prechallenged_Ms_1 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[0..DP::K],
-            &zeta_s[0..DP::K],
-        )
-        .unwrap();
-    let prechallenged_Ms_2 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &mz_mles[DP::K..2 * DP::K],
-            &zeta_s[DP::K..2 * DP::K],
-        )
-        .unwrap();
-    let (g_mles, g_degree) = create_sumcheck_polynomial::<_, DP>(
+    let prechallenged_Ms = mz_mles
+        .chunks(DP::K)
+        .zip(zeta_s.chunks(DP::K))
+        .map(|(mz_mles_group, zeta_group)| {
+            LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
+                mz_mles_group,
+                zeta_group,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (g_mles, g_degree) = create_sumcheck_polynomial(
         ccs.s,
         f_hat_mles.clone(),
         &alpha_s,
-        &prechallenged_Ms_1,
-        &prechallenged_Ms_2,
+        &prechallenged_Ms,
         &ris,
         &beta_s,
         &mu_s,
+        &DP::CONFIG,
     )
     .unwrap();
 
-    let comb_fn =
-        |vals: &[RqNTT]| -> RqNTT { sumcheck_polynomial_comb_fn::<RqNTT, DP>(vals, &mu_s) };
+    let comb_fn = |vals: &[RqNTT]| -> RqNTT {
+        sumcheck_polynomial_comb_fn::<RqNTT>(vals, &mu_s, &DP::CONFIG)
+    };
 
     let (_, prover_state) =
         MLSumcheck::prove_as_subprotocol(&mut transcript, g_mles, ccs.s, g_degree, comb_fn);
@@ -523,7 +527,7 @@ fn test_prepare_public_output() {
         .for_each(|thetas| transcript.absorb_slice(thetas));
     eta_s.iter().for_each(|etas| transcript.absorb_slice(etas));
 
-    let (rho_s_coeff, rho_s) = get_rhos::<_, _, DP>(&mut transcript);
+    let (rho_s_coeff, rho_s) = get_rhos(&mut transcript, 2, &DP::CONFIG);
     let (v_0, cm_0, u_0, x_0) =
         compute_v0_u0_x0_cm_0(&rho_s_coeff, &rho_s, &theta_s, &lccs, &eta_s, &ccs);
     let expected_x_0 = x_0[0..x_0.len() - 1].to_vec();
@@ -546,6 +550,63 @@ fn test_prepare_public_output() {
     assert_eq!(lcccs.h, h, "Wrong h in LCCCS");
 }
 
+#[test]
+fn test_compute_v0_u0_x0_cm_0() {
+    type RqNTT = StarkRqNTT;
+    type CS = StarkChallengeSet;
+    type DP = StarkFoldingDP;
+
+    const W: usize = WIT_LEN * DP::L;
+
+    let (lccs, _, mut transcript, ccs, _, _) = setup_test_environment::<RqNTT, CS, DP, C, W>(false);
+    // The test CCS has more matrices than public inputs and more columns than `l + 1`,
+    // so truncating `u_0` to `l` entries or padding `x_0` to `n` entries would be caught.
+    assert_ne!(ccs.t, ccs.l);
+    assert_ne!(ccs.n, ccs.l + 1);
+
+    let (rho_s_coeff, rho_s) = get_rhos(&mut transcript, 2, &DP::CONFIG);
+    let theta_s = vec![vec![RqNTT::one()]; rho_s.len()];
+    let eta_s: Vec<Vec<RqNTT>> = (0..rho_s.len())
+        .map(|i| {
+            (0..ccs.t)
+                .map(|j| RqNTT::from((i * ccs.t + j) as u64))
+                .collect()
+        })
+        .collect();
+
+    let (_, _, u_0, x_0) =
+        compute_v0_u0_x0_cm_0(&rho_s_coeff, &rho_s, &theta_s, &lccs, &eta_s, &ccs);
+
+    let expected_u_0: Vec<RqNTT> = (0..ccs.t)
+        .map(|j| {
+            rho_s
+                .iter()
+                .zip(&eta_s)
+                .map(|(&rho_i, eta_i)| rho_i * eta_i[j])
+                .sum()
+        })
+        .collect();
+    assert_eq!(u_0, expected_u_0, "u_0 must fold all t evaluations");
+
+    let expected_x_0: Vec<RqNTT> = (0..ccs.l)
+        .map(|j| {
+            rho_s
+                .iter()
+                .zip(&lccs)
+                .map(|(&rho_i, cm_i)| rho_i * cm_i.x_w[j])
+                .sum()
+        })
+        .chain(Some(
+            rho_s
+                .iter()
+                .zip(&lccs)
+                .map(|(&rho_i, cm_i)| rho_i * cm_i.h)
+                .sum(),
+        ))
+        .collect();
+    assert_eq!(x_0, expected_x_0, "x_0 must be the folded x_w || h");
+}
+
 #[test]
 fn test_compute_f_0() {
     type RqNTT = StarkRqNTT;
@@ -556,37 +617,38 @@ fn test_compute_f_0() {
 
     let (lccs, mut wit_s, mut transcript, ccs, _, Mz_mles) =
         setup_test_environment::<RqNTT, CS, DP, C, W>(false);
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
     let f_hat_mles =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::setup_f_hat_mles(&mut wit_s);
 
     let ris = LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::get_ris(&lccs);
-    let prechallenged_Ms_1 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &Mz_mles[0..DP::K],
-            &zeta_s[0..DP::K],
-        )
-        .unwrap();
-    let prechallenged_Ms_2 =
-        LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
-            &Mz_mles[DP::K..2 * DP::K],
-            &zeta_s[DP::K..2 * DP::K],
-        )
-        .unwrap();
-    let (g_mles, g_degree) = create_sumcheck_polynomial::<_, DP>(
+    let prechallenged_Ms = Mz_mles
+        .chunks(DP::K)
+        .zip(zeta_s.chunks(DP::K))
+        .map(|(mz_mles_group, zeta_group)| {
+            LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::calculate_challenged_mz_mle(
+                mz_mles_group,
+                zeta_group,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (g_mles, g_degree) = create_sumcheck_polynomial(
         ccs.s,
         f_hat_mles.clone(),
         &alpha_s,
-        &prechallenged_Ms_1,
-        &prechallenged_Ms_2,
+        &prechallenged_Ms,
         &ris,
         &beta_s,
         &mu_s,
+        &DP::CONFIG,
     )
     .unwrap();
 
-    let comb_fn =
-        |vals: &[RqNTT]| -> RqNTT { sumcheck_polynomial_comb_fn::<RqNTT, DP>(vals, &mu_s) };
+    let comb_fn = |vals: &[RqNTT]| -> RqNTT {
+        sumcheck_polynomial_comb_fn::<RqNTT>(vals, &mu_s, &DP::CONFIG)
+    };
 
     let (_, prover_state) =
         MLSumcheck::prove_as_subprotocol(&mut transcript, g_mles, ccs.s, g_degree, comb_fn);
@@ -604,7 +666,7 @@ fn test_compute_f_0() {
         .for_each(|thetas| transcript.absorb_slice(thetas));
     eta_s.iter().for_each(|etas| transcript.absorb_slice(etas));
 
-    let (_, rho_s) = get_rhos::<_, _, DP>(&mut transcript);
+    let (_, rho_s) = get_rhos(&mut transcript, 2, &DP::CONFIG);
 
     let f_0: Vec<RqNTT> =
         LFFoldingProver::<RqNTT, PoseidonTranscript<RqNTT, CS>>::compute_f_0(&rho_s, &wit_s);
@@ -685,7 +747,8 @@ fn test_verify_evaluation() {
         setup_test_environment::<RqNTT, CS, DP, C, W>(true);
     let proof = proof.unwrap();
 
-    let (alpha_s, beta_s, zeta_s, mu_s) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, beta_s, zeta_s, mu_s) =
+        transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
 
     let nvars = ccs.s;
     let degree = 2 * DP::B_SMALL;
@@ -705,17 +768,17 @@ fn test_verify_evaluation() {
         )
         .unwrap();
 
-    let result =
-        LFFoldingVerifier::<RqNTT, PoseidonTranscript<RqNTT, CS>>::verify_evaluation::<C, DP>(
-            &alpha_s,
-            &beta_s,
-            &mu_s,
-            &zeta_s,
-            &r_0,
-            expected_evaluation,
-            &proof,
-            &lccs_vec,
-        );
+    let result = LFFoldingVerifier::<RqNTT, PoseidonTranscript<RqNTT, CS>>::verify_evaluation::<C>(
+        &alpha_s,
+        &beta_s,
+        &mu_s,
+        &zeta_s,
+        &r_0,
+        expected_evaluation,
+        &proof,
+        &lccs_vec,
+        &DP::CONFIG,
+    );
 
     assert!(result.is_ok());
 }
@@ -766,7 +829,7 @@ fn test_verify_sumcheck_proof() {
         setup_test_environment::<RqNTT, CS, DP, C, W>(true);
     let proof = proof.unwrap();
 
-    let (alpha_s, _, zeta_s, _) = transcript.squeeze_alpha_beta_zeta_mu::<DP>(ccs.s);
+    let (alpha_s, _, zeta_s, _) = transcript.squeeze_alpha_beta_zeta_mu(ccs.s, 2, &DP::CONFIG);
 
     let nvars = ccs.s;
     let degree = 2 * DP::B_SMALL;
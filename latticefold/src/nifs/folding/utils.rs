@@ -1,6 +1,6 @@
 #![allow(non_snake_case)]
 
-use ark_ff::{Field, PrimeField, Zero};
+use ark_ff::Zero;
 use ark_std::{iter::successors, iterable::Iterable};
 use cyclotomic_rings::{rings::SuitableRing, rotation::rot_lin_combination};
 use stark_rings::{cyclotomic_ring::CRT, OverField, PolyRing, Ring};
@@ -10,7 +10,7 @@ use crate::{
     arith::{CCS, LCCCS},
     ark_base::*,
     commitment::Commitment,
-    decomposition_parameters::DecompositionParams,
+    decomposition_parameters::DecompositionConfig,
     nifs::error::FoldingError,
     transcript::{Transcript, TranscriptWithShortChallenges},
     utils::sumcheck::utils::build_eq_x_r,
@@ -28,60 +28,58 @@ pub(crate) trait SqueezeAlphaBetaZetaMu<NTT: SuitableRing> {
     ///
     /// ### Arguments
     /// - `log_m`: The length of the $\beta$ challenge vector.
-    ///
-    /// ### Type Parameters
-    /// - `P`: The decomposition parameters of the protocol.
+    /// - `num_instances`: The number of linearized instances that were decomposed
+    ///   into the $k$-groups being folded.
+    /// - `dp`: The decomposition parameters of the protocol.
     ///
     /// ### Returns
     /// - `(Vec<NTT>, Vec<NTT>, Vec<NTT>, Vec<NTT>)`: A tuple containing four challenge vectors:
-    ///   - `alpha`: A challenge vector of length $2 \cdot k$, where $k$ is defined in the decomposition parameters.
+    ///   - `alpha`: A challenge vector of length $n \cdot k$, where $n$ is `num_instances` and $k$ is defined in the decomposition parameters.
     ///   - `beta`: A challenge vector of length `log_m`.
-    ///   - `zeta`: A challenge vector of length $2 \cdot k$, where $k$ is defined in the decomposition parameters.
-    ///   - `mu`: A challenge vector of length $2 \cdot k$, where $k$ is defined in the decomposition parameters.
+    ///   - `zeta`: A challenge vector of length $n \cdot k$, where $n$ is `num_instances` and $k$ is defined in the decomposition parameters.
+    ///   - `mu`: A challenge vector of length $n \cdot k$, where $n$ is `num_instances` and $k$ is defined in the decomposition parameters.
     ///
-    fn squeeze_alpha_beta_zeta_mu<P: DecompositionParams>(
+    fn squeeze_alpha_beta_zeta_mu(
         &mut self,
         log_m: usize,
+        num_instances: usize,
+        dp: &DecompositionConfig,
     ) -> (Vec<NTT>, Vec<NTT>, Vec<NTT>, Vec<NTT>);
 }
 
 impl<NTT: SuitableRing, T: Transcript<NTT>> SqueezeAlphaBetaZetaMu<NTT> for T {
-    fn squeeze_alpha_beta_zeta_mu<P: DecompositionParams>(
+    fn squeeze_alpha_beta_zeta_mu(
         &mut self,
         log_m: usize,
+        num_instances: usize,
+        dp: &DecompositionConfig,
     ) -> (Vec<NTT>, Vec<NTT>, Vec<NTT>, Vec<NTT>) {
-        self.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-            <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"alpha_s"),
-        ));
+        let len = num_instances * dp.k;
+
+        self.absorb_label(b"alpha_s");
         let alpha_s = self
-            .get_challenges(2 * P::K)
+            .get_challenges(len)
             .into_iter()
             .map(|x| NTT::from(x))
             .collect::<Vec<_>>();
 
-        self.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-            <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"zeta_s"),
-        ));
+        self.absorb_label(b"zeta_s");
         let zeta_s = self
-            .get_challenges(2 * P::K)
+            .get_challenges(len)
             .into_iter()
             .map(|x| NTT::from(x))
             .collect::<Vec<_>>();
 
-        self.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-            <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"mu_s"),
-        ));
+        self.absorb_label(b"mu_s");
         let mut mu_s = self
-            .get_challenges((2 * P::K) - 1)
+            .get_challenges(len - 1)
             .into_iter()
             .map(|x| NTT::from(x))
             .collect::<Vec<_>>(); // Note is one challenge less
 
         mu_s.push(NTT::ONE);
 
-        self.absorb_field_element(&<NTT::BaseRing as Field>::from_base_prime_field(
-            <NTT::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"beta_s"),
-        ));
+        self.absorb_label(b"beta_s");
         let beta_s = self
             .get_challenges(log_m)
             .into_iter()
@@ -100,28 +98,25 @@ impl<NTT: SuitableRing, T: Transcript<NTT>> SqueezeAlphaBetaZetaMu<NTT> for T {
 /// # Type Parameters
 /// - `R`: A ring suitable to be used in the LatticeFold protocol.
 /// - `T`: A type implementing a cryptographic sponge construction.
-/// - `P`: The decomposition parameters of the protocol.
 ///
 /// # Arguments
 /// - `transcript`: A mutable reference to the transcript `T` from which we squeeze the challenges.
+/// - `num_instances`: The number of linearized instances that were decomposed into the $k$-groups being folded.
+/// - `dp`: The decomposition parameters of the protocol.
 ///
 /// # Returns
 /// - `(Vec<R::CoefficientRepresentation>, Vec<R>)`:
 ///   - The first element is a vector of challenges in coefficient form.
 ///   - The second element is the same vector of challenges in NTT form.
 ///
-pub(super) fn get_rhos<
-    R: SuitableRing,
-    T: TranscriptWithShortChallenges<R>,
-    P: DecompositionParams,
->(
+pub(super) fn get_rhos<R: SuitableRing, T: TranscriptWithShortChallenges<R>>(
     transcript: &mut T,
+    num_instances: usize,
+    dp: &DecompositionConfig,
 ) -> (Vec<R::CoefficientRepresentation>, Vec<R>) {
-    transcript.absorb_field_element(&<R::BaseRing as Field>::from_base_prime_field(
-        <R::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"rho_s"),
-    ));
+    transcript.absorb_label(b"rho_s");
 
-    let mut rhos_coeff = transcript.get_small_challenges((2 * P::K) - 1); // Note that we are missing the first element
+    let mut rhos_coeff = transcript.get_small_challenges(num_instances * dp.k - 1); // Note that we are missing the first element
     rhos_coeff.push(R::CoefficientRepresentation::ONE);
     let rhos = CRT::elementwise_crt(rhos_coeff.clone());
     (rhos_coeff, rhos)
@@ -130,10 +125,10 @@ pub(super) fn get_rhos<
 /// Creates sumcheck polynomial
 ///
 /// $$
-/// g(\vec{x}) := \sum_{i=1}^{2k} \left[\alpha_i g_{1,i}(\vec{x}) + \mu_i g_{2,i}(\vec{x}) + \zeta_i g_{3,i}(\vec{x})\right]
+/// g(\vec{x}) := \sum_{i=1}^{nk} \left[\alpha_i g_{1,i}(\vec{x}) + \mu_i g_{2,i}(\vec{x}) + \zeta_i g_{3,i}(\vec{x})\right]
 /// $$
 ///
-/// where, for all $i \in \[2k\]$,
+/// where $n$ is the number of $k$-groups of decomposed instances and, for all $i \in \[nk\]$,
 ///
 /// $$
 /// g_{1,i}(\vec{x}) := \sum\_{j=0}^{\tau - 1} \alpha_i^j \cdot \left( eq(\vec{r}_i, \vec{x}) \cdot \mathrm{mle} \[\hat{f}\_{ij}\](\vec{x}) \right),
@@ -166,14 +161,11 @@ pub(super) fn get_rhos<
 /// - `alpha_s: &[NTT]`  
 ///   A slice containing the $\alpha$ challenges.
 ///
-/// - `challenged_Ms_1: &DenseMultilinearExtension<NTT>`  
-///   A reference to the M matrices multiplied by the first $k$ decomposed vectors, and then taken a linear combination of.
-///
-/// - `challenged_Ms_2: &DenseMultilinearExtension<NTT>`  
-///    A reference to the M matrices multiplied by the second $k$ decomposed vectors, and then taken a linear combination of.
+/// - `challenged_Ms: &[DenseMultilinearExtension<NTT>]`  
+///   For every group of $k$ decomposed vectors, the M matrices multiplied by the vectors of the group, and then taken a linear combination of.
 ///
 /// - `r_s: &[Vec<NTT>]`  
-///    The linearization challenge vectors
+///    The linearization challenge vectors. All vectors of a $k$-group are expected to share the same challenge point.
 ///
 /// - `beta_s: &[NTT]`  
 ///   The $\beta$ challenges
@@ -181,6 +173,9 @@ pub(super) fn get_rhos<
 /// - `mu_s: &[NTT]`  
 ///   The $\mu$ challenges
 ///
+/// - `dp: &DecompositionConfig`  
+///   The decomposition parameters of the protocol.
+///
 /// # Returns
 ///
 /// - `Result<(Vec<RefCounter<DenseMultilinearExtension<NTT>>>, usize), FoldingError<NTT>>`  
@@ -194,63 +189,58 @@ pub(super) fn get_rhos<
 ///
 /// $$
 #[allow(clippy::too_many_arguments)]
-pub(super) fn create_sumcheck_polynomial<NTT: OverField, DP: DecompositionParams>(
+pub(super) fn create_sumcheck_polynomial<NTT: OverField>(
     log_m: usize,
     f_hat_mles: Vec<Vec<DenseMultilinearExtension<NTT>>>,
     alpha_s: &[NTT],
-    challenged_Ms_1: &DenseMultilinearExtension<NTT>,
-    challenged_Ms_2: &DenseMultilinearExtension<NTT>,
+    challenged_Ms: &[DenseMultilinearExtension<NTT>],
     r_s: &[Vec<NTT>],
     beta_s: &[NTT],
     mu_s: &[NTT],
+    dp: &DecompositionConfig,
 ) -> Result<(Vec<DenseMultilinearExtension<NTT>>, usize), FoldingError<NTT>> {
-    if alpha_s.len() != 2 * DP::K
-        || f_hat_mles.len() != 2 * DP::K
-        || r_s.len() != 2 * DP::K
+    let len = challenged_Ms.len() * dp.k;
+    if challenged_Ms.is_empty()
+        || alpha_s.len() != len
+        || f_hat_mles.len() != len
+        || r_s.len() != len
         || beta_s.len() != log_m
-        || mu_s.len() != 2 * DP::K
+        || mu_s.len() != len
     {
         return Err(FoldingError::IncorrectLength);
     }
 
     #[cfg(test)]
     {
-        if r_s[..DP::K].iter().any(|r| r != &r_s[0])
-            || r_s[DP::K..].iter().any(|r| r != &r_s[DP::K])
+        if r_s
+            .chunks(dp.k)
+            .any(|r_group| r_group.iter().any(|r| r != &r_group[0]))
         {
             return Err(FoldingError::SumcheckChallengeError);
         }
     }
 
-    let len = 2 + 2 + // g1 + g3
+    let mles_len = 2 * challenged_Ms.len() + // g1 + g3
         1 + f_hat_mles.len() * f_hat_mles[0].len(); // g2
-    let mut mles = Vec::with_capacity(len);
+    let mut mles = Vec::with_capacity(mles_len);
 
     // We assume here that decomposition subprotocol puts the same r challenge point
-    // into all decomposed linearized commitments
-    let r_i_eq = build_eq_x_r(&r_s[0])?;
-    prepare_g1_and_3_k_mles_list(
-        &mut mles,
-        r_i_eq.clone(),
-        &f_hat_mles[0..DP::K],
-        &alpha_s[0..DP::K],
-        challenged_Ms_1,
-    );
-
-    let r_i_eq = build_eq_x_r(&r_s[DP::K])?;
-    prepare_g1_and_3_k_mles_list(
-        &mut mles,
-        r_i_eq,
-        &f_hat_mles[DP::K..2 * DP::K],
-        &alpha_s[DP::K..2 * DP::K],
-        challenged_Ms_2,
-    );
+    // into all decomposed linearized commitments of a group
+    for (((r_group, f_hat_group), alpha_group), challenged_Ms_i) in r_s
+        .chunks(dp.k)
+        .zip(f_hat_mles.chunks(dp.k))
+        .zip(alpha_s.chunks(dp.k))
+        .zip(challenged_Ms)
+    {
+        let r_i_eq = build_eq_x_r(&r_group[0])?;
+        prepare_g1_and_3_k_mles_list(&mut mles, r_i_eq, f_hat_group, alpha_group, challenged_Ms_i);
+    }
 
     // g2
     let beta_eq_x = build_eq_x_r(beta_s)?;
     prepare_g2_i_mle_list(&mut mles, beta_eq_x, f_hat_mles);
 
-    let degree = 2 * DP::B_SMALL;
+    let degree = 2 * dp.b_small;
 
     Ok((mles, degree))
 }
@@ -263,33 +253,39 @@ pub(super) fn create_sumcheck_polynomial<NTT: OverField, DP: DecompositionParams
 ///     The evaluations of the multilinear extensions produced by the `create_sumcheck_polynomial` function
 /// - `mu_s: &[NTT]`
 ///     The $\mu$ challenges
+/// - `dp: &DecompositionConfig`
+///     The decomposition parameters of the protocol
 ///
 ///  # Returns
 ///  - NTT:
 ///     The value of the same evaluation point evaluated by the folding sumcheck polynomial
-pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing, P: DecompositionParams>(
+pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing>(
     vals: &[NTT],
     mu_s: &[NTT],
+    dp: &DecompositionConfig,
 ) -> NTT {
     let extension_degree = NTT::CoefficientRepresentation::dimension() / <NTT>::dimension();
+    let num_groups = mu_s.len() / dp.k;
 
-    // Add eq_r * g1 * g3 for first k
-    let mut result = vals[0] * vals[1];
+    // Add eq_r * g1 * g3 for every group of k
+    let mut result = (0..num_groups)
+        .map(|i| vals[2 * i] * vals[2 * i + 1])
+        .sum::<NTT>();
 
-    // Add eq_r * g1 * g3 for second k
-    result += vals[2] * vals[3];
+    // The eq_beta evaluation follows the g1 and g3 pairs
+    let eq_beta_index = 2 * num_groups;
 
     // We have k * extension degree mles of b
     // each one consists of (2 * small_b) -1 extensions
-    // We start at index 5
+    // We start right after the eq_beta evaluation
     // Multiply each group of (2 * small_b) -1 extensions
-    // Then multiply by the eq_beta evaluation at index 4
+    // Then multiply by the eq_beta evaluation
     for (k, mu) in mu_s.iter().enumerate() {
         let mut inter_result = NTT::zero();
         for d in (0..extension_degree).rev() {
             let i = k * extension_degree + d;
 
-            let f_i = vals[5 + i];
+            let f_i = vals[eq_beta_index + 1 + i];
 
             if f_i.is_zero() {
                 if !inter_result.is_zero() {
@@ -299,11 +295,11 @@ pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing, P: DecompositionPar
             }
 
             // start with eq_b
-            let mut eval = vals[4];
+            let mut eval = vals[eq_beta_index];
 
             let f_i_squared = f_i * f_i;
 
-            for b in 1..<P>::B_SMALL {
+            for b in 1..dp.b_small {
                 let multiplicand = f_i_squared - NTT::from(b as u128 * b as u128);
                 if multiplicand.is_zero() {
                     eval = NTT::zero();
@@ -333,7 +329,7 @@ pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing, P: DecompositionPar
 ///
 /// - `theta_s: &[Vec<NTT>]`  
 ///     $$
-///     \left[\theta\_{i} := \text{mle}\[\hat{f}\_i\](\vec{r}_o) \right]\_{i=1}^{2k},
+///     \left[\theta\_{i} := \text{mle}\[\hat{f}\_i\](\vec{r}_o) \right]\_{i=1}^{nk},
 ///     $$
 ///
 /// - `e_asterisk: NTT`  
@@ -343,7 +339,7 @@ pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing, P: DecompositionPar
 ///
 /// - `e_s: &[NTT]`  
 ///     $$
-///     \left[ e_i := eq(\vec{r}\_i, \vec{r}\_o) \right]\_{i=1}^{2k}
+///     \left[ e_i := eq(\vec{r}\_i, \vec{r}\_o) \right]\_{i=1}^{nk}
 ///     $$
 /// - `zeta_s: &[NTT]`  
 ///
@@ -358,12 +354,16 @@ pub(crate) fn sumcheck_polynomial_comb_fn<NTT: SuitableRing, P: DecompositionPar
 ///     \text{mle}\[M_1\]\(\vec{r}\_o, \vec{b}\) \cdot \text{mle}\[z_i\]\(\vec{b}\)
 ///     $$
 ///
+/// - `dp: &DecompositionConfig`  
+///     The decomposition parameters of the protocol.
+///
 /// # Returns
 ///
 /// - `NTT`  
 ///     Returns the expected value of the sumcheck claim.
 ///
-pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring, P: DecompositionParams>(
+#[allow(clippy::too_many_arguments)]
+pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring>(
     alpha_s: &[NTT],
     mu_s: &[NTT],
     theta_s: &[Vec<NTT>],
@@ -371,8 +371,9 @@ pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring, P: DecompositionP
     e_s: &[NTT],
     zeta_s: &[NTT],
     eta_s: &[Vec<NTT>],
+    dp: &DecompositionConfig,
 ) -> NTT {
-    (0..(2 * P::K))
+    (0..alpha_s.len())
         .map(|i| {
             // Evaluation claims about f hats.
             let mut s_summand: NTT = successors(Some(alpha_s[i]), |alpha_power| {
@@ -389,7 +390,7 @@ pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring, P: DecompositionP
                     .map(|(mu_power, &theta)| {
                         mu_power
                             * theta
-                            * (1..P::B_SMALL)
+                            * (1..dp.b_small)
                                 .map(|x| NTT::from(x as u128))
                                 .map(|j_hat| (theta - j_hat) * (theta + j_hat))
                                 .product::<NTT>()
@@ -423,7 +424,7 @@ pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring, P: DecompositionP
 ///
 /// - `theta_s: &[Vec<NTT>]`
 ///     $$
-///     \left[\theta\_{i} := \text{mle}\[\hat{f}\_i\](\vec{r}_o) \right]\_{i=1}^{2k},
+///     \left[\theta\_{i} := \text{mle}\[\hat{f}\_i\](\vec{r}_o) \right]\_{i=1}^{nk},
 ///     $$
 /// - `cm_i_s: &[LCCCS<C, NTT>]`
 ///
@@ -450,9 +451,9 @@ pub(super) fn compute_sumcheck_claim_expected_value<NTT: Ring, P: DecompositionP
 ///   - `v0: Vec<NTT>`  
 ///     Evaluation of linearized folded witness at $\vec{r}\_o$
 ///   - `u_0: Commitment<C, NTT>`
-///      A linear combination of $\left[ eta_s[i] \right]\_{i=1}^{2k}$
+///      A linear combination of $\left[ eta_s[i] \right]\_{i=1}^{nk}$
 ///   - `x0: Vec<NTT>`
-///     Folded CCS statement
+///     Folded CCS statement, i.e. the folded concatenation `x_w || h`
 ///   - `cm_0: Vec<NTT>`
 ///     Folded commitment
 pub(super) fn compute_v0_u0_x0_cm_0<const C: usize, NTT: SuitableRing>(
@@ -480,7 +481,7 @@ pub(super) fn compute_v0_u0_x0_cm_0<const C: usize, NTT: SuitableRing>(
                 .map(|etas_i_j| rho_i * etas_i_j)
                 .collect::<Vec<NTT>>()
         })
-        .fold(vec![NTT::zero(); ccs.l], |mut acc, rho_i_times_etas_i| {
+        .fold(vec![NTT::zero(); ccs.t], |mut acc, rho_i_times_etas_i| {
             acc.iter_mut()
                 .zip(rho_i_times_etas_i)
                 .for_each(|(acc_j, rho_i_times_etas_i_j)| {
@@ -496,18 +497,22 @@ pub(super) fn compute_v0_u0_x0_cm_0<const C: usize, NTT: SuitableRing>(
         .map(|(&rho_i, cm_i)| {
             cm_i.x_w
                 .iter()
+                .chain(Some(&cm_i.h))
                 .map(|x_w_i| rho_i * x_w_i)
                 .collect::<Vec<NTT>>()
         })
-        .fold(vec![NTT::zero(); ccs.n], |mut acc, rho_i_times_x_w_i| {
-            acc.iter_mut()
-                .zip(rho_i_times_x_w_i)
-                .for_each(|(acc_j, rho_i_times_x_w_i)| {
-                    *acc_j += rho_i_times_x_w_i;
-                });
-
-            acc
-        });
+        .fold(
+            vec![NTT::zero(); ccs.l + 1],
+            |mut acc, rho_i_times_x_w_i| {
+                acc.iter_mut()
+                    .zip(rho_i_times_x_w_i)
+                    .for_each(|(acc_j, rho_i_times_x_w_i)| {
+                        *acc_j += rho_i_times_x_w_i;
+                    });
+
+                acc
+            },
+        );
 
     (v_0, cm_0, u_0, x_0)
 }
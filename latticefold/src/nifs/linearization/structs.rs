@@ -1,8 +1,9 @@
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::marker::PhantomData;
-use stark_rings::OverField;
+use cyclotomic_rings::rings::SuitableRing;
+use stark_rings::{OverField, PolyRing};
 
-use crate::{ark_base::*, utils::sumcheck};
+use crate::{arith::CCS, ark_base::*, nifs::error::LinearizationError, utils::sumcheck};
 
 /// Non-interactive proof generated by the linearization prover
 ///
@@ -36,6 +37,22 @@ pub struct LinearizationProof<NTT: OverField> {
     pub u: Vec<NTT>,
 }
 
+impl<NTT: SuitableRing> LinearizationProof<NTT> {
+    /// Checks that the proof has the shape the linearization verifier expects for `ccs`,
+    /// so that a malformed proof is rejected before it is used.
+    pub fn validate(&self, ccs: &CCS<NTT>) -> Result<(), LinearizationError<NTT>> {
+        self.linearization_sumcheck.check_shape(ccs.s, ccs.d + 1)?;
+
+        if self.v.len() != NTT::CoefficientRepresentation::dimension() / NTT::dimension()
+            || self.u.len() != ccs.t
+        {
+            return Err(LinearizationError::IncorrectLength);
+        }
+
+        Ok(())
+    }
+}
+
 /// The LatticeFold prover
 ///
 /// The implementation of the `LinearizationProver` trait is defined in the main linearization file.
@@ -44,7 +44,7 @@ fn setup_test_environment<
 
     let wit = Witness::from_w_ccs::<DP>(w_ccs);
     let cm_i = CCCS {
-        cm: wit.commit::<C, W, DP>(&scheme).unwrap(),
+        cm: wit.commit::<C>(&scheme).unwrap(),
         x_ccs,
     };
 
@@ -65,7 +65,7 @@ fn test_compute_z_ccs() {
     assert_eq!(z_ccs[cm_i.x_ccs.len()], RqNTT::one());
 
     // Check commitment
-    assert_eq!(cm_i.cm, wit.commit::<C, W, StarkDP>(&scheme).unwrap());
+    assert_eq!(cm_i.cm, wit.commit::<C>(&scheme).unwrap());
 }
 
 #[test]
@@ -1,4 +1,3 @@
-use ark_ff::{Field, PrimeField};
 use cyclotomic_rings::rings::SuitableRing;
 use stark_rings::OverField;
 use stark_rings_poly::mle::DenseMultilinearExtension;
@@ -112,9 +111,7 @@ pub(crate) trait SqueezeBeta<NTT: SuitableRing> {
 
 impl<R: SuitableRing, T: Transcript<R>> SqueezeBeta<R> for T {
     fn squeeze_beta_challenges(&mut self, n: usize) -> Vec<R> {
-        self.absorb_field_element(&<R::BaseRing as Field>::from_base_prime_field(
-            <R::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(b"beta_s"),
-        ));
+        self.absorb_label(b"beta_s");
 
         self.get_challenges(n)
             .into_iter()
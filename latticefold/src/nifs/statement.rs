@@ -0,0 +1,236 @@
+//! Binding of the Fiat-Shamir transcript to the statement being proven.
+//!
+//! The instances folded by [`NIFSProver`](super::NIFSProver) are only meaningful relative to the CCS,
+//! the Ajtai commitment key and the decomposition parameters. Unless the transcript is bound to all of them
+//! before the first challenge is drawn, a proof produced for one circuit or key could be replayed against another.
+//!
+//! [`statement_digest`] hashes the public setting into 32 bytes. The digest is a required argument of
+//! [`NIFSProver::prove`](super::NIFSProver::prove) and its variants, which absorb it with [`bind_statement`]
+//! before the public input. The verifier does not take a digest, it takes a [`VerifierKey`],
+//! which computes the digest itself from the setting it is built for.
+
+use ark_serialize::{CanonicalSerialize, Write};
+use cyclotomic_rings::rings::SuitableRing;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    arith::{Arith, CCS},
+    ark_base::*,
+    commitment::AjtaiCommitmentKey,
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
+    transcript::Transcript,
+};
+
+/// The version of the protocol, included in every statement digest.
+pub const PROTOCOL_VERSION: &[u8] = b"LatticeFold/v1";
+
+/// Hashes everything that is written to it.
+struct DigestWriter(Sha256);
+
+impl DigestWriter {
+    fn update_u64(&mut self, x: usize) {
+        self.0.update((x as u64).to_le_bytes());
+    }
+}
+
+impl Write for DigestWriter {
+    fn write(&mut self, buf: &[u8]) -> ark_std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ark_std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the SHA-256 digest of the setting a proof is produced in:
+///  * the [`PROTOCOL_VERSION`],
+///  * the dimensions of `ccs`, i.e. [`Arith::params_to_le_bytes`], and its compressed canonical serialization,
+///    which includes the contents of the matrices, the multisets and the coefficients,
+///  * the commitment length `C`, the witness length of the commitment key `scheme`
+///    and its compressed canonical serialization,
+///  * the decomposition parameters `P`.
+///
+/// All integers are little-endian.
+pub fn statement_digest<const C: usize, NTT: SuitableRing, P: DecompositionParams>(
+    ccs: &CCS<NTT>,
+    scheme: &impl AjtaiCommitmentKey<C, NTT>,
+) -> [u8; 32] {
+    statement_digest_with_config(ccs, scheme, &P::CONFIG)
+}
+
+/// Returns the [`statement_digest`] for the decomposition parameters `dp` chosen at runtime.
+///
+/// The digest is the same as for a [`DecompositionParams`] whose [`DecompositionParams::CONFIG`] equals `dp`.
+pub fn statement_digest_with_config<const C: usize, NTT: SuitableRing>(
+    ccs: &CCS<NTT>,
+    scheme: &impl AjtaiCommitmentKey<C, NTT>,
+    dp: &DecompositionConfig,
+) -> [u8; 32] {
+    let mut writer = DigestWriter(Sha256::new());
+
+    writer.update_u64(PROTOCOL_VERSION.len());
+    writer.0.update(PROTOCOL_VERSION);
+
+    writer.0.update(ccs.params_to_le_bytes());
+    ccs.serialize_compressed(&mut writer)
+        .expect("writing to a hash does not fail");
+
+    writer.update_u64(C);
+    writer.update_u64(scheme.witness_len());
+    scheme
+        .serialize_compressed(&mut writer)
+        .expect("writing to a hash does not fail");
+
+    let DecompositionConfig { b, l, b_small, k } = *dp;
+    writer.0.update(b.to_le_bytes());
+    for param in [l, b_small, k] {
+        writer.update_u64(param);
+    }
+
+    writer.0.finalize().into()
+}
+
+/// The setting proofs are verified in, i.e. the CCS and the decomposition parameters,
+/// together with the [`statement_digest`] of the setting and the commitment key.
+///
+/// The digest is computed when the key is built, so it always matches the CCS and the parameters the verifier uses.
+#[derive(Clone, Debug)]
+pub struct VerifierKey<'a, const C: usize, NTT: SuitableRing> {
+    ccs: &'a CCS<NTT>,
+    dp: DecompositionConfig,
+    digest: [u8; 32],
+}
+
+impl<'a, const C: usize, NTT: SuitableRing> VerifierKey<'a, C, NTT> {
+    /// Builds the verifier key of `ccs`, the commitment key `scheme` and the decomposition parameters `P`.
+    pub fn new<P: DecompositionParams>(
+        ccs: &'a CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+    ) -> Self {
+        Self::with_config(ccs, scheme, P::CONFIG)
+    }
+
+    /// Builds the verifier key for the decomposition parameters `dp` chosen at runtime.
+    pub fn with_config(
+        ccs: &'a CCS<NTT>,
+        scheme: &impl AjtaiCommitmentKey<C, NTT>,
+        dp: DecompositionConfig,
+    ) -> Self {
+        Self {
+            ccs,
+            dp,
+            digest: statement_digest_with_config(ccs, scheme, &dp),
+        }
+    }
+
+    /// Returns the CCS the key was built for.
+    pub fn ccs(&self) -> &'a CCS<NTT> {
+        self.ccs
+    }
+
+    /// Returns the decomposition parameters the key was built for.
+    pub fn config(&self) -> &DecompositionConfig {
+        &self.dp
+    }
+
+    /// Returns the statement digest, the same as [`statement_digest`] returns for the setting of the key.
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+}
+
+/// Absorbs a statement `digest` returned by [`statement_digest`] into `transcript`.
+pub(crate) fn bind_statement<NTT: SuitableRing>(
+    transcript: &mut impl Transcript<NTT>,
+    digest: &[u8; 32],
+) {
+    transcript.absorb_label(b"statement");
+    transcript.absorb_bytes(digest);
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::test_rng;
+    use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT};
+
+    use super::*;
+    use crate::{
+        arith::tests::get_test_ccs,
+        commitment::{AjtaiCommitmentScheme, DynAjtaiCommitmentScheme},
+        decomposition_parameters::test_params::{GoldilocksDP, DP},
+        transcript::poseidon::PoseidonTranscript,
+    };
+
+    type RqNTT = GoldilocksRingNTT;
+    type T = PoseidonTranscript<RqNTT, GoldilocksChallengeSet>;
+
+    const C: usize = 4;
+    const W: usize = 4 * GoldilocksDP::L;
+
+    #[test]
+    fn test_digest_binds_statement() {
+        let mut rng = test_rng();
+        let ccs = get_test_ccs::<RqNTT>(W, GoldilocksDP::L);
+        let scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::rand(&mut rng);
+        let digest = statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &scheme);
+
+        assert_eq!(
+            digest,
+            statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &scheme)
+        );
+
+        let other_scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::rand(&mut rng);
+        assert_ne!(
+            digest,
+            statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &other_scheme)
+        );
+
+        let mut other_ccs = ccs.clone();
+        other_ccs.c[0] += RqNTT::from(1u64);
+        assert_ne!(
+            digest,
+            statement_digest::<C, RqNTT, GoldilocksDP>(&other_ccs, &scheme)
+        );
+
+        assert_ne!(digest, statement_digest::<C, RqNTT, DP>(&ccs, &scheme));
+
+        // A runtime-sized key with the same matrix is a different key,
+        // since its serialization includes the witness length.
+        let dyn_scheme = DynAjtaiCommitmentScheme::from(scheme.clone());
+        assert_ne!(
+            digest,
+            statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &dyn_scheme)
+        );
+    }
+
+    #[test]
+    fn test_verifier_key_digest() {
+        let mut rng = test_rng();
+        let ccs = get_test_ccs::<RqNTT>(W, GoldilocksDP::L);
+        let scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::rand(&mut rng);
+        let vk = VerifierKey::new::<GoldilocksDP>(&ccs, &scheme);
+
+        assert_eq!(
+            *vk.digest(),
+            statement_digest::<C, RqNTT, GoldilocksDP>(&ccs, &scheme)
+        );
+        assert_eq!(*vk.config(), GoldilocksDP::CONFIG);
+        assert_eq!(
+            VerifierKey::with_config(&ccs, &scheme, GoldilocksDP::CONFIG).digest(),
+            vk.digest()
+        );
+    }
+
+    #[test]
+    fn test_bind_statement() {
+        let mut a = T::default();
+        let mut b = T::default();
+
+        bind_statement(&mut a, &[0; 32]);
+        bind_statement(&mut b, &[1; 32]);
+
+        assert_ne!(a.get_challenge(), b.get_challenge());
+    }
+}
@@ -4,13 +4,20 @@ use rand::Rng;
 
 use crate::{
     arith::{r1cs::get_test_z_split, tests::get_test_ccs, Witness, CCCS, CCS, LCCCS},
-    commitment::AjtaiCommitmentScheme,
-    decomposition_parameters::DecompositionParams,
+    commitment::{AjtaiCommitmentScheme, DynAjtaiCommitmentScheme},
+    decomposition_parameters::{DecompositionConfig, DecompositionParams},
     nifs::{
+        error::{DecompositionError, FoldingError, LatticefoldError, LinearizationError},
         linearization::{LFLinearizationProver, LinearizationProver},
-        NIFSProver, NIFSVerifier,
+        statement::{statement_digest, statement_digest_with_config, VerifierKey},
+        LFProof, NIFSProver, NIFSProverWithConfig, NIFSVerifier, NIFSVerifierWithConfig,
     },
-    transcript::{poseidon::PoseidonTranscript, TranscriptWithShortChallenges},
+    transcript::{
+        poseidon::PoseidonTranscript,
+        recording::{first_divergence, RecordingTranscript, TranscriptEvent},
+        ProtocolStep, TranscriptWithShortChallenges,
+    },
+    utils::sumcheck::{self, SumCheckError},
 };
 
 fn setup_test_environment<
@@ -35,7 +42,7 @@ fn setup_test_environment<
 
     let wit_i = Witness::from_w_ccs::<DP>(w_ccs);
     let cm_i = CCCS {
-        cm: wit_i.commit::<C, W, DP>(&scheme).unwrap(),
+        cm: wit_i.commit::<C>(&scheme).unwrap(),
         x_ccs: x_ccs.clone(),
     };
 
@@ -61,14 +68,15 @@ fn test_nifs_prove<
     RqNTT: SuitableRing,
     CS: LatticefoldChallengeSet<RqNTT>,
     DP: DecompositionParams,
-    T: TranscriptWithShortChallenges<RqNTT>,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
 >() {
     let (acc, w_acc, cm_i, w_i, ccs, scheme) =
         setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
 
-    let mut transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let mut transcript = T::default();
 
-    let result = NIFSProver::<C, W, RqNTT, DP, T>::prove(
+    let result = NIFSProver::<C, RqNTT, DP, T>::prove(
         &acc,
         &w_acc,
         &cm_i,
@@ -76,6 +84,7 @@ fn test_nifs_prove<
         &mut transcript,
         &ccs,
         &scheme,
+        &statement,
     );
 
     assert!(result.is_ok());
@@ -88,15 +97,50 @@ fn test_nifs_verify<
     RqNTT: SuitableRing,
     CS: LatticefoldChallengeSet<RqNTT>,
     DP: DecompositionParams,
-    T: TranscriptWithShortChallenges<RqNTT>,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
 >() {
     let (acc, w_acc, cm_i, w_i, ccs, scheme) =
         setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
 
-    let mut prover_transcript = PoseidonTranscript::<RqNTT, CS>::default();
-    let mut verifier_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+    let mut prover_transcript = T::default();
+    let mut verifier_transcript = T::default();
+
+    let (_, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut prover_transcript,
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let result =
+        NIFSVerifier::<C, RqNTT, DP, T>::verify(&acc, &cm_i, &proof, &mut verifier_transcript, &vk);
+
+    assert!(result.is_ok());
+}
 
-    let (_, _, proof) = NIFSProver::<C, W, RqNTT, DP, T>::prove(
+fn test_nifs_rejects_other_statement<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
+>() {
+    let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let mut prover_transcript = T::default();
+
+    let (_, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
         &acc,
         &w_acc,
         &cm_i,
@@ -104,18 +148,530 @@ fn test_nifs_verify<
         &mut prover_transcript,
         &ccs,
         &scheme,
+        &statement,
     )
     .unwrap();
 
+    // The same instances and CCS, but a different commitment key.
+    let other_scheme = AjtaiCommitmentScheme::<C, W, RqNTT>::from_seed(&[1; 32]);
+    let other_vk = VerifierKey::new::<DP>(&ccs, &other_scheme);
+    let mut verifier_transcript = T::default();
+
     let result = NIFSVerifier::<C, RqNTT, DP, T>::verify(
         &acc,
         &cm_i,
         &proof,
         &mut verifier_transcript,
+        &other_vk,
+    );
+
+    assert!(result.is_err());
+}
+
+fn test_nifs_dyn_scheme<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
+>() {
+    let (mut acc, w_acc, mut cm_i, w_i, ccs, _) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+
+    // A key wider than the witnesses, which are committed as if they were zero-padded.
+    let dyn_scheme = DynAjtaiCommitmentScheme::<RqNTT>::from_seed(&[2; 32], C, 2 * W);
+    let scheme = dyn_scheme.to_static::<C, W>().unwrap();
+    acc.cm = w_acc.commit::<C>(&scheme).unwrap();
+    cm_i.cm = w_i.commit::<C>(&scheme).unwrap();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+
+    let (static_acc, _, _) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let (dyn_acc, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
         &ccs,
+        &dyn_scheme,
+        &statement,
+    )
+    .unwrap();
+
+    assert_eq!(dyn_acc, static_acc);
+
+    let result =
+        NIFSVerifier::<C, RqNTT, DP, T>::verify(&acc, &cm_i, &proof, &mut T::default(), &vk);
+    assert_eq!(result.unwrap(), dyn_acc);
+
+    // The number of rows of a runtime-sized key has to match the commitment length.
+    let taller_scheme = DynAjtaiCommitmentScheme::<RqNTT>::from_seed(&[2; 32], C + 1, W);
+    let result = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &taller_scheme,
+        &statement,
     );
+    assert!(result.is_err());
+}
 
-    assert!(result.is_ok());
+fn test_nifs_runtime_config<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
+>() {
+    let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+    let config = DecompositionConfig::of::<DP>();
+    let statement = statement_digest_with_config::<C, RqNTT>(&ccs, &scheme, &config);
+    let vk = VerifierKey::with_config(&ccs, &scheme, config);
+
+    let (static_acc, static_wit, static_proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let (config_acc, config_wit, config_proof) = NIFSProverWithConfig::<C, RqNTT, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &scheme,
+        &statement,
+        &config,
+    )
+    .unwrap();
+
+    assert_eq!(config_acc, static_acc);
+    assert_eq!(config_wit, static_wit);
+
+    // The proofs are interchangeable between the two verifiers.
+    let result = NIFSVerifierWithConfig::<C, RqNTT, T>::verify(
+        &acc,
+        &cm_i,
+        &static_proof,
+        &mut T::default(),
+        &vk,
+    );
+    assert_eq!(result.unwrap(), static_acc);
+
+    let result =
+        NIFSVerifier::<C, RqNTT, DP, T>::verify(&acc, &cm_i, &config_proof, &mut T::default(), &vk);
+    assert_eq!(result.unwrap(), static_acc);
+
+    // A config that does not pass validation is rejected before the proof is looked at.
+    let inconsistent = DecompositionConfig {
+        k: config.k - 1,
+        ..config
+    };
+    let inconsistent_vk = VerifierKey::with_config(&ccs, &scheme, inconsistent);
+    let result = NIFSVerifierWithConfig::<C, RqNTT, T>::verify(
+        &acc,
+        &cm_i,
+        &config_proof,
+        &mut T::default(),
+        &inconsistent_vk,
+    );
+    assert!(matches!(
+        result,
+        Err(LatticefoldError::DecompositionParamsError(_))
+    ));
+
+    // The verifier for DP does not accept a key built for other parameters.
+    let result = NIFSVerifier::<C, RqNTT, DP, T>::verify(
+        &acc,
+        &cm_i,
+        &config_proof,
+        &mut T::default(),
+        &inconsistent_vk,
+    );
+    assert!(matches!(
+        result,
+        Err(LatticefoldError::VerifierKeyMismatch(dp)) if dp == inconsistent
+    ));
+}
+
+fn test_nifs_recording_locates_divergence<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT> + Default,
+>() {
+    let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+
+    let mut prover_transcript = RecordingTranscript::<RqNTT, T>::default();
+    let (_, _, mut proof) = NIFSProver::<C, RqNTT, DP, RecordingTranscript<RqNTT, T>>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut prover_transcript,
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let mut verifier_transcript = RecordingTranscript::<RqNTT, T>::default();
+    NIFSVerifier::<C, RqNTT, DP, RecordingTranscript<RqNTT, T>>::verify(
+        &acc,
+        &cm_i,
+        &proof,
+        &mut verifier_transcript,
+        &vk,
+    )
+    .unwrap();
+    assert_eq!(
+        first_divergence(prover_transcript.log(), verifier_transcript.log()),
+        None
+    );
+
+    let tampered = proof.decomposition_proof_l.u_s[0][0] + RqNTT::ONE;
+    proof.decomposition_proof_l.u_s[0][0] = tampered;
+
+    let mut verifier_transcript = RecordingTranscript::<RqNTT, T>::default();
+    assert!(
+        NIFSVerifier::<C, RqNTT, DP, RecordingTranscript<RqNTT, T>>::verify(
+            &acc,
+            &cm_i,
+            &proof,
+            &mut verifier_transcript,
+            &vk
+        )
+        .is_err()
+    );
+
+    let divergence = first_divergence(prover_transcript.log(), verifier_transcript.log()).unwrap();
+    assert_eq!(divergence.step, Some(ProtocolStep::Decomposition));
+    assert_eq!(divergence.verifier, Some(TranscriptEvent::Absorb(tampered)));
+}
+
+fn setup_multi_test_environment<
+    const C: usize,
+    RqNTT: SuitableRing,
+    DP: DecompositionParams,
+    const W: usize,
+    CS: LatticefoldChallengeSet<RqNTT>,
+>(
+    num_acc: usize,
+    num_cm: usize,
+) -> (
+    Vec<LCCCS<C, RqNTT>>, // acc_s
+    Vec<Witness<RqNTT>>,  // w_acc_s
+    Vec<CCCS<C, RqNTT>>,  // cm_i_s
+    Vec<Witness<RqNTT>>,  // w_i_s
+    CCS<RqNTT>,
+    AjtaiCommitmentScheme<C, W, RqNTT>,
+) {
+    let ccs = get_test_ccs::<RqNTT>(W, DP::L);
+    let mut rng = test_rng();
+    let scheme = AjtaiCommitmentScheme::rand(&mut rng);
+
+    let mut new_cccs = || {
+        let (_, x_ccs, w_ccs) = get_test_z_split::<RqNTT>(rng.gen_range(0..64));
+        let wit = Witness::from_w_ccs::<DP>(w_ccs);
+        let cm = CCCS {
+            cm: wit.commit::<C>(&scheme).unwrap(),
+            x_ccs,
+        };
+        (cm, wit)
+    };
+
+    let (cm_i_s, w_i_s): (Vec<_>, Vec<_>) = (0..num_cm).map(|_| new_cccs()).unzip();
+
+    let mut transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let (acc_s, w_acc_s): (Vec<_>, Vec<_>) = (0..num_acc)
+        .map(|_| {
+            let (cm, wit) = new_cccs();
+            let (acc, _) = LFLinearizationProver::<_, PoseidonTranscript<RqNTT, CS>>::prove(
+                &cm,
+                &wit,
+                &mut transcript,
+                &ccs,
+            )
+            .unwrap();
+            (acc, wit)
+        })
+        .unzip();
+
+    (acc_s, w_acc_s, cm_i_s, w_i_s, ccs, scheme)
+}
+
+fn test_nifs_multi_verify<
+    const C: usize,
+    const W: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT>,
+>(
+    num_acc: usize,
+    num_cm: usize,
+) {
+    let (acc_s, w_acc_s, cm_i_s, w_i_s, ccs, scheme) =
+        setup_multi_test_environment::<C, RqNTT, DP, W, CS>(num_acc, num_cm);
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+
+    let mut prover_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let mut verifier_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+
+    let (folded_acc, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove_multi(
+        &acc_s,
+        &w_acc_s,
+        &cm_i_s,
+        &w_i_s,
+        &mut prover_transcript,
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    assert_eq!(proof.linearization_proofs.len(), num_cm);
+    assert_eq!(proof.decomposition_proofs_l.len(), num_acc);
+    assert_eq!(proof.decomposition_proofs_r.len(), num_cm);
+
+    let result = NIFSVerifier::<C, RqNTT, DP, T>::verify_multi(
+        &acc_s,
+        &cm_i_s,
+        &proof,
+        &mut verifier_transcript,
+        &vk,
+    );
+
+    assert_eq!(result.unwrap(), folded_acc);
+}
+
+fn test_nifs_multi_matches_single<
+    const C: usize,
+    const W: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT>,
+>() {
+    let (acc_s, w_acc_s, cm_i_s, w_i_s, ccs, scheme) =
+        setup_multi_test_environment::<C, RqNTT, DP, W, CS>(1, 1);
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+
+    let mut single_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+    let mut multi_transcript = PoseidonTranscript::<RqNTT, CS>::default();
+
+    let (single_acc, _, _) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc_s[0],
+        &w_acc_s[0],
+        &cm_i_s[0],
+        &w_i_s[0],
+        &mut single_transcript,
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let (multi_acc, _, _) = NIFSProver::<C, RqNTT, DP, T>::prove_multi(
+        &acc_s,
+        &w_acc_s,
+        &cm_i_s,
+        &w_i_s,
+        &mut multi_transcript,
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    assert_eq!(single_acc, multi_acc);
+}
+
+fn test_nifs_rejects_malformed_proof<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT>,
+>() {
+    let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+
+    let (_, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut PoseidonTranscript::<RqNTT, CS>::default(),
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+    proof.validate::<DP>(&ccs).unwrap();
+
+    let reject = |tamper: &dyn Fn(&mut LFProof<C, RqNTT>)| {
+        let mut proof = proof.clone();
+        tamper(&mut proof);
+
+        assert!(proof.validate::<DP>(&ccs).is_err());
+        NIFSVerifier::<C, RqNTT, DP, T>::verify(
+            &acc,
+            &cm_i,
+            &proof,
+            &mut PoseidonTranscript::<RqNTT, CS>::default(),
+            &vk,
+        )
+        .unwrap_err()
+    };
+    let drop_round = |sumcheck_proof: &mut sumcheck::Proof<RqNTT>| {
+        let mut rounds: Vec<Vec<RqNTT>> = sumcheck_proof
+            .round_evaluations()
+            .map(|evals| evals.to_vec())
+            .collect();
+        rounds.pop();
+        *sumcheck_proof = sumcheck::Proof::from_round_evaluations(rounds);
+    };
+
+    assert!(matches!(
+        reject(&|proof| drop_round(&mut proof.linearization_proof.linearization_sumcheck)),
+        LatticefoldError::LinearizationError(LinearizationError::SumCheckError(
+            SumCheckError::IncorrectNumberOfRounds(_, _)
+        ))
+    ));
+    assert!(matches!(
+        reject(&|proof| {
+            proof.linearization_proof.u.pop();
+        }),
+        LatticefoldError::LinearizationError(LinearizationError::IncorrectLength)
+    ));
+    assert!(matches!(
+        reject(&|proof| {
+            proof.decomposition_proof_l.x_s.pop();
+        }),
+        LatticefoldError::DecompositionError(DecompositionError::IncorrectLength)
+    ));
+    assert!(matches!(
+        reject(&|proof| {
+            proof.decomposition_proof_r.v_s[0].pop();
+        }),
+        LatticefoldError::DecompositionError(DecompositionError::IncorrectLength)
+    ));
+    assert!(matches!(
+        reject(&|proof| drop_round(&mut proof.folding_proof.pointshift_sumcheck_proof)),
+        LatticefoldError::FoldingError(FoldingError::SumCheckError(
+            SumCheckError::IncorrectNumberOfRounds(_, _)
+        ))
+    ));
+    assert!(matches!(
+        reject(&|proof| {
+            proof.folding_proof.theta_s.pop();
+        }),
+        LatticefoldError::FoldingError(FoldingError::IncorrectLength)
+    ));
+    assert!(matches!(
+        reject(&|proof| {
+            proof.folding_proof.eta_s[1].pop();
+        }),
+        LatticefoldError::FoldingError(FoldingError::IncorrectLength)
+    ));
+}
+
+fn test_nifs_verify_batch<
+    const C: usize,
+    const W: usize,
+    const WIT_LEN: usize,
+    RqNTT: SuitableRing,
+    CS: LatticefoldChallengeSet<RqNTT>,
+    DP: DecompositionParams,
+    T: TranscriptWithShortChallenges<RqNTT> + Default + Clone + Sync,
+>() {
+    let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+        setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+    let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+    let vk = VerifierKey::new::<DP>(&ccs, &scheme);
+
+    let (_, _, proof) = NIFSProver::<C, RqNTT, DP, T>::prove(
+        &acc,
+        &w_acc,
+        &cm_i,
+        &w_i,
+        &mut T::default(),
+        &ccs,
+        &scheme,
+        &statement,
+    )
+    .unwrap();
+
+    let mut instances = vec![(acc, cm_i, proof); 4];
+    instances[1].2.folding_proof.theta_s[0][0] += RqNTT::ONE;
+    instances[3].2.linearization_proof.u.pop();
+
+    let individual: Vec<_> = instances
+        .iter()
+        .map(|(acc, cm_i, proof)| {
+            NIFSVerifier::<C, RqNTT, DP, T>::verify(acc, cm_i, proof, &mut T::default(), &vk)
+        })
+        .collect();
+
+    let batch =
+        NIFSVerifier::<C, RqNTT, DP, T>::verify_batch(&instances, &T::default(), &vk).unwrap_err();
+
+    let rejected: Vec<usize> = (0..instances.len())
+        .filter(|&i| individual[i].is_err())
+        .collect();
+    assert_eq!(rejected, vec![1, 3]);
+    assert_eq!(batch.rejected_indices(instances.len()), rejected);
+
+    let accepted: Vec<_> = instances
+        .iter()
+        .zip(&individual)
+        .filter(|(_, result)| result.is_ok())
+        .map(|(instance, _)| instance.clone())
+        .collect();
+    let folded_accs =
+        NIFSVerifier::<C, RqNTT, DP, T>::verify_batch(&accepted, &T::default(), &vk).unwrap();
+    let expected: Vec<_> = individual.into_iter().filter_map(Result::ok).collect();
+    assert_eq!(folded_accs, expected);
 }
 
 mod e2e_tests {
@@ -155,7 +711,13 @@ mod e2e_tests {
         use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT};
 
         use super::*;
-        use crate::decomposition_parameters::test_params::GoldilocksDP;
+        use crate::{
+            decomposition_parameters::{test_params::GoldilocksDP, DecompositionParamsError},
+            transcript::{
+                hash::{Blake3Transcript, KeccakTranscript},
+                poseidon2::Poseidon2Transcript,
+            },
+        };
 
         type RqNTT = GoldilocksRingNTT;
         type CS = GoldilocksChallengeSet;
@@ -175,13 +737,110 @@ mod e2e_tests {
         fn test_verify() {
             test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
         }
+
+        #[test]
+        fn test_multi_verify() {
+            test_nifs_multi_verify::<C, W, RqNTT, CS, DP, T>(2, 3);
+        }
+
+        #[test]
+        fn test_multi_only_cccs() {
+            test_nifs_multi_verify::<C, W, RqNTT, CS, DP, T>(0, 2);
+        }
+
+        #[test]
+        fn test_multi_matches_single() {
+            test_nifs_multi_matches_single::<C, W, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_rejects_malformed_proof() {
+            test_nifs_rejects_malformed_proof::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_verify_batch() {
+            test_nifs_verify_batch::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_recording_locates_divergence() {
+            test_nifs_recording_locates_divergence::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_dyn_scheme() {
+            test_nifs_dyn_scheme::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_runtime_config() {
+            test_nifs_runtime_config::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_rejects_other_statement() {
+            test_nifs_rejects_other_statement::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
+        }
+
+        #[test]
+        fn test_verify_poseidon2() {
+            test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, Poseidon2Transcript<RqNTT, CS>>();
+        }
+
+        #[test]
+        fn test_verify_keccak() {
+            test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, KeccakTranscript<RqNTT, CS>>();
+        }
+
+        #[test]
+        fn test_verify_blake3() {
+            test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, Blake3Transcript<RqNTT, CS>>();
+        }
+
+        #[test]
+        fn test_rejects_inconsistent_params() {
+            #[derive(Clone)]
+            struct InconsistentDP;
+            impl DecompositionParams for InconsistentDP {
+                const B: u128 = DP::B;
+                const L: usize = DP::L;
+                const B_SMALL: usize = DP::B_SMALL;
+                const K: usize = DP::K - 1;
+            }
+
+            let (acc, w_acc, cm_i, w_i, ccs, scheme) =
+                setup_test_environment::<C, RqNTT, DP, W, WIT_LEN, CS>();
+            let statement = statement_digest::<C, RqNTT, DP>(&ccs, &scheme);
+
+            let result = NIFSProver::<C, RqNTT, InconsistentDP, T>::prove(
+                &acc,
+                &w_acc,
+                &cm_i,
+                &w_i,
+                &mut T::default(),
+                &ccs,
+                &scheme,
+                &statement,
+            );
+
+            assert!(matches!(
+                result,
+                Err(LatticefoldError::DecompositionParamsError(
+                    DecompositionParamsError::InconsistentK(2, 14, 32768)
+                ))
+            ));
+        }
     }
 
     mod babybear {
         use cyclotomic_rings::rings::{BabyBearChallengeSet, BabyBearRingNTT};
 
         use super::*;
-        use crate::decomposition_parameters::test_params::BabyBearDP;
+        use crate::{
+            decomposition_parameters::test_params::BabyBearDP,
+            transcript::poseidon2::Poseidon2Transcript,
+        };
 
         type RqNTT = BabyBearRingNTT;
         type CS = BabyBearChallengeSet;
@@ -201,5 +860,15 @@ mod e2e_tests {
         fn test_verify() {
             test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, T>();
         }
+
+        #[test]
+        fn test_multi_verify() {
+            test_nifs_multi_verify::<C, W, RqNTT, CS, DP, T>(3, 2);
+        }
+
+        #[test]
+        fn test_verify_poseidon2() {
+            test_nifs_verify::<C, W, WIT_LEN, RqNTT, CS, DP, Poseidon2Transcript<RqNTT, CS>>();
+        }
     }
 }
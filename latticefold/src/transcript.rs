@@ -2,13 +2,39 @@
 //!
 //! Transcripts allow provers and verifiers to independently draw the same challenges.
 
+use ark_ff::{Field, PrimeField};
 use ark_std::fmt::Debug;
 use cyclotomic_rings::{challenge_set::LatticefoldChallengeSet, rings::SuitableRing};
 use stark_rings::OverField;
 
 use crate::ark_base::*;
 
+pub mod hash;
 pub mod poseidon;
+pub mod poseidon2;
+pub mod recording;
+
+/// A step of the folding protocol, see [`Transcript::begin_step`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProtocolStep {
+    /// Absorbing the accumulators and the CCCS instances.
+    PublicInput,
+    Linearization,
+    Decomposition,
+    Folding,
+}
+
+impl ark_std::fmt::Display for ProtocolStep {
+    fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+        let name = match self {
+            Self::PublicInput => "public input",
+            Self::Linearization => "linearization",
+            Self::Decomposition => "decomposition",
+            Self::Folding => "folding",
+        };
+        f.write_str(name)
+    }
+}
 
 pub trait Transcript<R: OverField> {
     type TranscriptConfig: Debug;
@@ -27,6 +53,44 @@ pub trait Transcript<R: OverField> {
         }
     }
 
+    /// Absorbs a short constant `label` separating the messages of the protocol.
+    ///
+    /// The label is absorbed as a single field element, i.e. as a big-endian integer reduced modulo the characteristic.
+    /// Use [`Transcript::absorb_bytes`] for arbitrary data.
+    fn absorb_label(&mut self, label: &[u8]) {
+        self.absorb_field_element(&<R::BaseRing as Field>::from_base_prime_field(
+            <R::BaseRing as Field>::BasePrimeField::from_be_bytes_mod_order(label),
+        ));
+    }
+
+    /// Absorbs `label` followed by `v`.
+    fn absorb_labelled(&mut self, label: &[u8], v: &[R]) {
+        self.absorb_label(label);
+        self.absorb_slice(v);
+    }
+
+    /// Absorbs arbitrary bytes.
+    ///
+    /// The length of `bytes` is absorbed first, followed by `bytes` split into little-endian chunks
+    /// that fit into an element of the base prime field, so distinct byte strings are absorbed as distinct messages.
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let chunk_len =
+            ((<R::BaseRing as Field>::BasePrimeField::MODULUS_BIT_SIZE - 1) / 8) as usize;
+
+        self.absorb_field_element(&R::BaseRing::from(bytes.len() as u64));
+        for chunk in bytes.chunks(chunk_len) {
+            self.absorb_field_element(&<R::BaseRing as Field>::from_base_prime_field(
+                <R::BaseRing as Field>::BasePrimeField::from_le_bytes_mod_order(chunk),
+            ));
+        }
+    }
+
+    /// Marks the beginning of a step of the protocol.
+    ///
+    /// Does not affect the transcript, the default implementation does nothing.
+    /// [`recording::RecordingTranscript`] uses the marks to attribute operations to protocol steps.
+    fn begin_step(&mut self, _step: ProtocolStep) {}
+
     fn get_challenge(&mut self) -> R::BaseRing;
 
     fn get_challenges(&mut self, n: usize) -> Vec<R::BaseRing> {
@@ -0,0 +1,377 @@
+//! Transcripts over byte-oriented hash functions.
+//!
+//! Unlike the sponge transcripts, a [`HashTranscript`] can be reproduced by external verifiers,
+//! e.g. a smart contract with the `KECCAK256` opcode, without implementing arithmetic over the base prime field.
+//!
+//! The transcript keeps a 32-byte state, initially $H(\mathrm{domain})$, and a buffer of pending bytes:
+//!   * absorbing appends the canonical encoding of the ring element to the buffer: its coefficients
+//!     over the base prime field in order, each as a little-endian integer of $\lceil \log_2 p / 8 \rceil$ bytes,
+//!     labels and byte strings are appended as is, preceded by their length as a little-endian `u64`,
+//!   * squeezing replaces the state by $H(\mathrm{state} \Vert \mathrm{pending})$, clears the buffer and outputs the new state,
+//!     as many times as needed.
+//!
+//! A challenge in the base prime field is derived from $\lceil (\log_2 p + 128) / 8 \rceil$ squeezed bytes
+//! interpreted as a little-endian integer and reduced modulo $p$, so its distance from the uniform distribution is below $2^{-128}$.
+//! An extension field challenge consists of `extension_degree` such elements.
+
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::marker::PhantomData;
+use cyclotomic_rings::{challenge_set::LatticefoldChallengeSet, rings::SuitableRing};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256, Sha3_256};
+use stark_rings::OverField;
+
+use super::{Transcript, TranscriptWithShortChallenges};
+use crate::ark_base::*;
+
+/// The domain separator of the default transcripts.
+pub const DEFAULT_DOMAIN: &[u8] = b"LatticeFold";
+
+/// The statistical security of the reduction of squeezed bytes to field elements.
+const REDUCTION_SECURITY_BITS: usize = 128;
+
+/// A hash function with a 32-byte output.
+pub trait TranscriptHash: Clone + Default {
+    fn update(&mut self, bytes: &[u8]);
+
+    fn finalize(self) -> [u8; 32];
+}
+
+macro_rules! impl_transcript_hash_for_digest {
+    ($($hash:ty),*) => {
+        $(
+            impl TranscriptHash for $hash {
+                fn update(&mut self, bytes: &[u8]) {
+                    Digest::update(self, bytes);
+                }
+
+                fn finalize(self) -> [u8; 32] {
+                    Digest::finalize(self).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_transcript_hash_for_digest!(Sha256, Sha3_256, Keccak256);
+
+impl TranscriptHash for blake3::Hasher {
+    fn update(&mut self, bytes: &[u8]) {
+        blake3::Hasher::update(self, bytes);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        blake3::Hasher::finalize(&self).into()
+    }
+}
+
+/// HashTranscript implements the Transcript trait using the byte-oriented hash `H`
+#[derive(Clone)]
+pub struct HashTranscript<R: OverField, CS, H> {
+    _marker: PhantomData<(R, CS, H)>,
+    state: [u8; 32],
+    pending: Vec<u8>,
+}
+
+/// [`HashTranscript`] over SHA3-256.
+pub type Sha3Transcript<R, CS> = HashTranscript<R, CS, Sha3_256>;
+
+/// [`HashTranscript`] over Keccak-256, as used by the EVM.
+pub type KeccakTranscript<R, CS> = HashTranscript<R, CS, Keccak256>;
+
+/// [`HashTranscript`] over BLAKE3.
+pub type Blake3Transcript<R, CS> = HashTranscript<R, CS, blake3::Hasher>;
+
+impl<R: OverField, CS, H: TranscriptHash> HashTranscript<R, CS, H> {
+    /// Squeezes `num_bytes` bytes.
+    pub fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(num_bytes.next_multiple_of(32));
+        while bytes.len() < num_bytes {
+            let mut hash = H::default();
+            hash.update(&self.state);
+            hash.update(&self.pending);
+            self.state = hash.finalize();
+            self.pending.clear();
+
+            bytes.extend_from_slice(&self.state);
+        }
+        bytes.truncate(num_bytes);
+
+        bytes
+    }
+}
+
+impl<R: OverField, CS, H: TranscriptHash> Default for HashTranscript<R, CS, H> {
+    fn default() -> Self {
+        Self::new(&DEFAULT_DOMAIN.to_vec())
+    }
+}
+
+impl<R: OverField, CS, H: TranscriptHash> Transcript<R> for HashTranscript<R, CS, H> {
+    /// The domain separator.
+    type TranscriptConfig = Vec<u8>;
+
+    fn new(config: &Self::TranscriptConfig) -> Self {
+        let mut hash = H::default();
+        hash.update(config);
+
+        Self {
+            _marker: PhantomData,
+            state: hash.finalize(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn absorb(&mut self, v: &R) {
+        let len = byte_len::<<R::BaseRing as Field>::BasePrimeField>();
+
+        for x in v
+            .coeffs()
+            .iter()
+            .flat_map(|x| x.to_base_prime_field_elements())
+        {
+            self.pending
+                .extend_from_slice(&x.into_bigint().to_bytes_le()[..len]);
+        }
+    }
+
+    /// Appends the length of `label` as a little-endian `u64` and `label` to the pending bytes.
+    fn absorb_label(&mut self, label: &[u8]) {
+        self.absorb_bytes(label);
+    }
+
+    /// Appends the length of `bytes` as a little-endian `u64` and `bytes` to the pending bytes.
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.pending
+            .extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        self.pending.extend_from_slice(bytes);
+    }
+
+    fn get_challenge(&mut self) -> R::BaseRing {
+        let extension_degree = R::BaseRing::extension_degree() as usize;
+        let len =
+            byte_len::<<R::BaseRing as Field>::BasePrimeField>() + REDUCTION_SECURITY_BITS / 8;
+
+        let bytes = self.squeeze_bytes(extension_degree * len);
+        let c: Vec<_> = bytes
+            .chunks(len)
+            .map(<<R::BaseRing as Field>::BasePrimeField as PrimeField>::from_le_bytes_mod_order)
+            .collect();
+
+        <R::BaseRing as Field>::from_base_prime_field_elems(&c)
+            .expect("something went wrong: c does not contain extension_degree elements")
+    }
+}
+
+impl<R: SuitableRing, CS: LatticefoldChallengeSet<R>, H: TranscriptHash>
+    TranscriptWithShortChallenges<R> for HashTranscript<R, CS, H>
+{
+    type ChallengeSet = CS;
+
+    fn get_short_challenge(&mut self) -> R::CoefficientRepresentation {
+        let random_bytes = self.squeeze_bytes(Self::ChallengeSet::BYTES_NEEDED);
+
+        Self::ChallengeSet::short_challenge_from_random_bytes(&random_bytes)
+            .expect("not enough bytes to get a small challenge")
+    }
+}
+
+/// Returns the length of the canonical encoding of an element of `F`.
+fn byte_len<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::BigInt;
+    use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT, GoldilocksRingPoly};
+    use stark_rings::cyclotomic_ring::models::goldilocks::{Fq, Fq3};
+
+    use super::*;
+
+    type CS = GoldilocksChallengeSet;
+
+    fn fresh_transcript<H: TranscriptHash>() -> HashTranscript<GoldilocksRingNTT, CS, H> {
+        let mut transcript = HashTranscript::default();
+        transcript.pending.push(0xFF);
+        transcript
+    }
+
+    fn expected_challenge(c: [u64; 3]) -> Fq3 {
+        Fq3::new(
+            Fq::new(BigInt([c[0]])),
+            Fq::new(BigInt([c[1]])),
+            Fq::new(BigInt([c[2]])),
+        )
+    }
+
+    #[test]
+    fn test_get_big_challenge_sha3() {
+        let mut transcript = fresh_transcript::<Sha3_256>();
+
+        let expected = expected_challenge([
+            9541525229236786360,
+            17510650467938389151,
+            15482273497343717839,
+        ]);
+
+        assert_eq!(expected, transcript.get_challenge())
+    }
+
+    #[test]
+    fn test_get_small_challenge_sha3() {
+        let mut transcript = fresh_transcript::<Sha3_256>();
+
+        let expected_coeffs: Vec<Fq> = vec![
+            Fq::new(BigInt([18446744069414584292])),
+            Fq::new(BigInt([27])),
+            Fq::new(BigInt([16])),
+            Fq::new(BigInt([18446744069414584313])),
+            Fq::new(BigInt([23])),
+            Fq::new(BigInt([27])),
+            Fq::new(BigInt([18446744069414584315])),
+            Fq::new(BigInt([18446744069414584313])),
+            Fq::new(BigInt([18446744069414584311])),
+            Fq::new(BigInt([3])),
+            Fq::new(BigInt([15])),
+            Fq::new(BigInt([0])),
+            Fq::new(BigInt([18446744069414584303])),
+            Fq::new(BigInt([18446744069414584301])),
+            Fq::new(BigInt([18446744069414584293])),
+            Fq::new(BigInt([18446744069414584307])),
+            Fq::new(BigInt([18446744069414584305])),
+            Fq::new(BigInt([18446744069414584308])),
+            Fq::new(BigInt([18446744069414584317])),
+            Fq::new(BigInt([29])),
+            Fq::new(BigInt([18446744069414584302])),
+            Fq::new(BigInt([18446744069414584312])),
+            Fq::new(BigInt([18446744069414584293])),
+            Fq::new(BigInt([18446744069414584307])),
+        ];
+
+        let expected = GoldilocksRingPoly::from(expected_coeffs);
+
+        assert_eq!(expected, transcript.get_short_challenge())
+    }
+
+    #[test]
+    fn test_get_big_challenge_keccak() {
+        let mut transcript = fresh_transcript::<Keccak256>();
+
+        let expected = expected_challenge([
+            17143641210107019911,
+            12847053823707910077,
+            5651589432064749510,
+        ]);
+
+        assert_eq!(expected, transcript.get_challenge())
+    }
+
+    #[test]
+    fn test_get_small_challenge_keccak() {
+        let mut transcript = fresh_transcript::<Keccak256>();
+
+        let expected_coeffs: Vec<Fq> = vec![
+            Fq::new(BigInt([30])),
+            Fq::new(BigInt([18446744069414584314])),
+            Fq::new(BigInt([7])),
+            Fq::new(BigInt([18446744069414584296])),
+            Fq::new(BigInt([19])),
+            Fq::new(BigInt([12])),
+            Fq::new(BigInt([18446744069414584296])),
+            Fq::new(BigInt([18446744069414584305])),
+            Fq::new(BigInt([18446744069414584296])),
+            Fq::new(BigInt([18446744069414584304])),
+            Fq::new(BigInt([20])),
+            Fq::new(BigInt([27])),
+            Fq::new(BigInt([1])),
+            Fq::new(BigInt([20])),
+            Fq::new(BigInt([24])),
+            Fq::new(BigInt([18446744069414584312])),
+            Fq::new(BigInt([5])),
+            Fq::new(BigInt([10])),
+            Fq::new(BigInt([11])),
+            Fq::new(BigInt([6])),
+            Fq::new(BigInt([18])),
+            Fq::new(BigInt([18446744069414584304])),
+            Fq::new(BigInt([15])),
+            Fq::new(BigInt([18446744069414584306])),
+        ];
+
+        let expected = GoldilocksRingPoly::from(expected_coeffs);
+
+        assert_eq!(expected, transcript.get_short_challenge())
+    }
+
+    #[test]
+    fn test_get_big_challenge_blake3() {
+        let mut transcript = fresh_transcript::<blake3::Hasher>();
+
+        let expected = expected_challenge([
+            10109802812962726325,
+            2447829939009269483,
+            1372973844828164818,
+        ]);
+
+        assert_eq!(expected, transcript.get_challenge())
+    }
+
+    #[test]
+    fn test_get_small_challenge_blake3() {
+        let mut transcript = fresh_transcript::<blake3::Hasher>();
+
+        let expected_coeffs: Vec<Fq> = vec![
+            Fq::new(BigInt([28])),
+            Fq::new(BigInt([18])),
+            Fq::new(BigInt([30])),
+            Fq::new(BigInt([18446744069414584317])),
+            Fq::new(BigInt([18446744069414584295])),
+            Fq::new(BigInt([18])),
+            Fq::new(BigInt([18446744069414584317])),
+            Fq::new(BigInt([5])),
+            Fq::new(BigInt([29])),
+            Fq::new(BigInt([18446744069414584300])),
+            Fq::new(BigInt([9])),
+            Fq::new(BigInt([18446744069414584308])),
+            Fq::new(BigInt([18446744069414584296])),
+            Fq::new(BigInt([2])),
+            Fq::new(BigInt([24])),
+            Fq::new(BigInt([18446744069414584318])),
+            Fq::new(BigInt([18446744069414584300])),
+            Fq::new(BigInt([14])),
+            Fq::new(BigInt([18446744069414584314])),
+            Fq::new(BigInt([18446744069414584301])),
+            Fq::new(BigInt([10])),
+            Fq::new(BigInt([18446744069414584289])),
+            Fq::new(BigInt([18446744069414584308])),
+            Fq::new(BigInt([18446744069414584307])),
+        ];
+
+        let expected = GoldilocksRingPoly::from(expected_coeffs);
+
+        assert_eq!(expected, transcript.get_short_challenge())
+    }
+
+    #[test]
+    fn test_absorb_canonical_encoding() {
+        let mut transcript = Sha3Transcript::<GoldilocksRingNTT, CS>::default();
+        let x = GoldilocksRingNTT::from(vec![Fq3::from(3u64); 24]);
+
+        transcript.absorb(&x);
+
+        // 24 coefficients over Fq3, 8 bytes per element of Fq.
+        assert_eq!(transcript.pending.len(), 24 * 3 * 8);
+        assert_eq!(
+            &transcript.pending[..16],
+            &[3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let mut a = Blake3Transcript::<GoldilocksRingNTT, CS>::new(&b"a".to_vec());
+        let mut b = Blake3Transcript::<GoldilocksRingNTT, CS>::new(&b"b".to_vec());
+
+        assert_ne!(a.get_challenge(), b.get_challenge());
+    }
+}
@@ -0,0 +1,323 @@
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::marker::PhantomData;
+use cyclotomic_rings::{
+    challenge_set::LatticefoldChallengeSet,
+    rings::{poseidon2::Poseidon2Config, GetPoseidon2Params, SuitableRing},
+};
+use stark_rings::OverField;
+
+use super::{Transcript, TranscriptWithShortChallenges};
+use crate::ark_base::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpongeMode {
+    Absorbing { next_absorb_index: usize },
+    Squeezing { next_squeeze_index: usize },
+}
+
+/// A duplex sponge over the Poseidon2 permutation.
+///
+/// Absorbs and squeezes exactly like `ark_crypto_primitives`' `PoseidonSponge`,
+/// only the permutation differs.
+#[derive(Clone, Debug)]
+pub struct Poseidon2Sponge<F: PrimeField> {
+    config: Poseidon2Config<F>,
+    state: Vec<F>,
+    mode: SpongeMode,
+}
+
+impl<F: PrimeField> Poseidon2Sponge<F> {
+    pub fn new(config: &Poseidon2Config<F>) -> Self {
+        Self {
+            config: config.clone(),
+            state: vec![F::zero(); config.width()],
+            mode: SpongeMode::Absorbing {
+                next_absorb_index: 0,
+            },
+        }
+    }
+
+    pub fn absorb(&mut self, elems: &[F]) {
+        if elems.is_empty() {
+            return;
+        }
+
+        match self.mode {
+            SpongeMode::Absorbing { next_absorb_index } => {
+                let mut absorb_index = next_absorb_index;
+                if absorb_index == self.config.rate {
+                    self.permute();
+                    absorb_index = 0;
+                }
+                self.absorb_internal(absorb_index, elems);
+            }
+            SpongeMode::Squeezing { .. } => {
+                self.permute();
+                self.absorb_internal(0, elems);
+            }
+        }
+    }
+
+    pub fn squeeze_field_elements(&mut self, num_elements: usize) -> Vec<F> {
+        let mut squeezed = vec![F::zero(); num_elements];
+
+        match self.mode {
+            SpongeMode::Absorbing { .. } => {
+                self.permute();
+                self.squeeze_internal(0, &mut squeezed);
+            }
+            SpongeMode::Squeezing { next_squeeze_index } => {
+                let mut squeeze_index = next_squeeze_index;
+                if squeeze_index == self.config.rate {
+                    self.permute();
+                    squeeze_index = 0;
+                }
+                self.squeeze_internal(squeeze_index, &mut squeezed);
+            }
+        }
+
+        squeezed
+    }
+
+    /// Squeezes `num_bytes` bytes, taking the lowest `(F::MODULUS_BIT_SIZE - 1) / 8` bytes of every squeezed element.
+    pub fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let usable_bytes = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+        let num_elements = num_bytes.div_ceil(usable_bytes);
+
+        let mut bytes = Vec::with_capacity(usable_bytes * num_elements);
+        for elem in self.squeeze_field_elements(num_elements) {
+            bytes.extend_from_slice(&elem.into_bigint().to_bytes_le()[..usable_bytes]);
+        }
+        bytes.truncate(num_bytes);
+
+        bytes
+    }
+
+    fn permute(&mut self) {
+        self.config.permute(&mut self.state);
+    }
+
+    fn absorb_internal(&mut self, mut rate_start_index: usize, mut elems: &[F]) {
+        let (rate, capacity) = (self.config.rate, self.config.capacity);
+
+        loop {
+            if rate_start_index + elems.len() <= rate {
+                for (i, elem) in elems.iter().enumerate() {
+                    self.state[capacity + rate_start_index + i] += elem;
+                }
+                self.mode = SpongeMode::Absorbing {
+                    next_absorb_index: rate_start_index + elems.len(),
+                };
+                return;
+            }
+
+            let num_absorbed = rate - rate_start_index;
+            for (i, elem) in elems[..num_absorbed].iter().enumerate() {
+                self.state[capacity + rate_start_index + i] += elem;
+            }
+            self.permute();
+
+            elems = &elems[num_absorbed..];
+            rate_start_index = 0;
+        }
+    }
+
+    fn squeeze_internal(&mut self, mut rate_start_index: usize, mut output: &mut [F]) {
+        let (rate, capacity) = (self.config.rate, self.config.capacity);
+
+        loop {
+            let start = capacity + rate_start_index;
+            if rate_start_index + output.len() <= rate {
+                output.copy_from_slice(&self.state[start..start + output.len()]);
+                self.mode = SpongeMode::Squeezing {
+                    next_squeeze_index: rate_start_index + output.len(),
+                };
+                return;
+            }
+
+            let num_squeezed = rate - rate_start_index;
+            output[..num_squeezed].copy_from_slice(&self.state[start..start + num_squeezed]);
+
+            output = &mut output[num_squeezed..];
+            if !output.is_empty() {
+                self.permute();
+            }
+            rate_start_index = 0;
+        }
+    }
+}
+
+/// Poseidon2Transcript implements the Transcript trait using the Poseidon2 hash
+#[derive(Clone)]
+pub struct Poseidon2Transcript<R: OverField, CS> {
+    _marker: PhantomData<CS>,
+    sponge: Poseidon2Sponge<<R::BaseRing as Field>::BasePrimeField>,
+}
+
+impl<R: SuitableRing, CS: LatticefoldChallengeSet<R>> Default for Poseidon2Transcript<R, CS> {
+    fn default() -> Self {
+        Self::new(&R::Poseidon2Params::get_poseidon2_config())
+    }
+}
+
+impl<R: OverField, CS> Transcript<R> for Poseidon2Transcript<R, CS> {
+    type TranscriptConfig = Poseidon2Config<<R::BaseRing as Field>::BasePrimeField>;
+
+    fn new(config: &Self::TranscriptConfig) -> Self {
+        Self {
+            sponge: Poseidon2Sponge::new(config),
+            _marker: PhantomData,
+        }
+    }
+
+    fn absorb(&mut self, v: &R) {
+        self.sponge.absorb(
+            &v.coeffs()
+                .iter()
+                .flat_map(|x| x.to_base_prime_field_elements())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn get_challenge(&mut self) -> R::BaseRing {
+        let extension_degree = R::BaseRing::extension_degree();
+        let c = self
+            .sponge
+            .squeeze_field_elements(extension_degree as usize);
+        self.sponge.absorb(&c);
+        <R::BaseRing as Field>::from_base_prime_field_elems(&c)
+            .expect("something went wrong: c does not contain extension_degree elements")
+    }
+}
+
+impl<R: SuitableRing, CS: LatticefoldChallengeSet<R>> TranscriptWithShortChallenges<R>
+    for Poseidon2Transcript<R, CS>
+{
+    type ChallengeSet = CS;
+
+    fn get_short_challenge(&mut self) -> R::CoefficientRepresentation {
+        let random_bytes = self.sponge.squeeze_bytes(Self::ChallengeSet::BYTES_NEEDED);
+
+        Self::ChallengeSet::short_challenge_from_random_bytes(&random_bytes)
+            .expect("not enough bytes to get a small challenge")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{
+        fields::{Fp256, MontBackend, MontConfig},
+        BigInt, MontFp,
+    };
+    use cyclotomic_rings::rings::{
+        poseidon::PoseidonSpec, GoldilocksChallengeSet, GoldilocksRingNTT, GoldilocksRingPoly,
+    };
+    use stark_rings::cyclotomic_ring::models::goldilocks::{Fq, Fq3};
+
+    use super::*;
+
+    #[test]
+    fn test_get_big_challenge() {
+        let mut transcript =
+            Poseidon2Transcript::<GoldilocksRingNTT, GoldilocksChallengeSet>::default();
+
+        transcript
+            .sponge
+            .absorb(&[Fq::from(BigInt::<1>::from(0xFFu32))]);
+
+        let expected: Fq3 = Fq3::new(
+            Fq::new(BigInt([10458498977613103519])),
+            Fq::new(BigInt([14420800888817747808])),
+            Fq::new(BigInt([11439987822891583540])),
+        );
+
+        assert_eq!(expected, transcript.get_challenge())
+    }
+
+    #[test]
+    fn test_get_small_challenge() {
+        let mut transcript =
+            Poseidon2Transcript::<GoldilocksRingNTT, GoldilocksChallengeSet>::default();
+
+        transcript
+            .sponge
+            .absorb(&[Fq::from(BigInt::<1>::from(0xFFu32))]);
+
+        let expected_coeffs: Vec<Fq> = vec![
+            Fq::new(BigInt([18446744069414584320])),
+            Fq::new(BigInt([18446744069414584295])),
+            Fq::new(BigInt([4])),
+            Fq::new(BigInt([18446744069414584315])),
+            Fq::new(BigInt([18446744069414584317])),
+            Fq::new(BigInt([2])),
+            Fq::new(BigInt([18446744069414584311])),
+            Fq::new(BigInt([18446744069414584292])),
+            Fq::new(BigInt([4])),
+            Fq::new(BigInt([18446744069414584289])),
+            Fq::new(BigInt([22])),
+            Fq::new(BigInt([1])),
+            Fq::new(BigInt([18446744069414584319])),
+            Fq::new(BigInt([18446744069414584299])),
+            Fq::new(BigInt([18446744069414584304])),
+            Fq::new(BigInt([18446744069414584289])),
+            Fq::new(BigInt([26])),
+            Fq::new(BigInt([18446744069414584292])),
+            Fq::new(BigInt([18446744069414584291])),
+            Fq::new(BigInt([18446744069414584302])),
+            Fq::new(BigInt([18446744069414584297])),
+            Fq::new(BigInt([29])),
+            Fq::new(BigInt([3])),
+            Fq::new(BigInt([18446744069414584301])),
+        ];
+
+        let expected = GoldilocksRingPoly::from(expected_coeffs);
+
+        assert_eq!(expected, transcript.get_short_challenge())
+    }
+
+    /// The scalar field of BN254, the field of the known-answer tests of the reference implementation.
+    #[derive(MontConfig)]
+    #[modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+    #[generator = "5"]
+    struct Bn254FrConfig;
+    type Bn254Fr = Fp256<MontBackend<Bn254FrConfig, 4>>;
+
+    /// The permutation of $(0, 1, 2)$ by Poseidon2 over BN254 with $t = 3$, $\alpha = 5$, $R_F = 8$ and $R_P = 56$,
+    /// as in the tests of the reference implementation <https://github.com/HorizenLabs/poseidon2>.
+    #[test]
+    fn test_reference_vector_bn254() {
+        let spec = PoseidonSpec {
+            rate: 2,
+            capacity: 1,
+            alpha: 5,
+            full_rounds: 8,
+            partial_rounds: 56,
+        };
+        let config = Poseidon2Config::<Bn254Fr>::generate(&spec).unwrap();
+
+        assert_eq!(
+            config.external_rc[0][0],
+            MontFp!(
+                "13128406282895484157369354038809433636203389051939936481821261911791933663254"
+            )
+        );
+
+        let mut state = vec![
+            Bn254Fr::from(0u64),
+            Bn254Fr::from(1u64),
+            Bn254Fr::from(2u64),
+        ];
+        config.permute(&mut state);
+
+        let expected: Vec<Bn254Fr> = vec![
+            MontFp!("5297208644449048816064511434384511824916970985131888684874823260532015509555"),
+            MontFp!(
+                "21816030159894113985964609355246484851575571273661473159848781012394295965040"
+            ),
+            MontFp!(
+                "13940986381491601233448981668101586453321811870310341844570924906201623195336"
+            ),
+        ];
+        assert_eq!(state, expected);
+    }
+}
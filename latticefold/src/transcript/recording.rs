@@ -0,0 +1,251 @@
+//! A transcript wrapper for debugging diverging transcripts.
+//!
+//! When the verifier rejects an honestly generated proof, the prover and the verifier usually
+//! absorbed different values at some point, and all the challenges drawn afterwards differ.
+//! Running both with a [`RecordingTranscript`] and passing the logs to [`first_divergence`]
+//! points at the first operation that differs, along with the protocol step and the last label absorbed before it.
+
+use ark_std::fmt;
+use cyclotomic_rings::rings::SuitableRing;
+
+use super::{ProtocolStep, Transcript, TranscriptWithShortChallenges};
+use crate::ark_base::*;
+
+/// An operation performed on a transcript.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TranscriptEvent<R: SuitableRing> {
+    /// [`Transcript::begin_step`].
+    Step(ProtocolStep),
+    /// [`Transcript::absorb_label`].
+    Label(Vec<u8>),
+    /// [`Transcript::absorb_bytes`].
+    Bytes(Vec<u8>),
+    /// [`Transcript::absorb`].
+    Absorb(R),
+    /// [`Transcript::absorb_field_element`].
+    AbsorbFieldElement(R::BaseRing),
+    /// [`Transcript::get_challenge`].
+    Challenge(R::BaseRing),
+    /// [`TranscriptWithShortChallenges::get_short_challenge`].
+    ShortChallenge(R::CoefficientRepresentation),
+}
+
+/// Wraps the transcript `T` and logs every operation performed on it.
+///
+/// The challenges are the ones of `T`, i.e. a protocol run with a `RecordingTranscript<T>`
+/// produces the same proof as a run with `T`.
+#[derive(Clone)]
+pub struct RecordingTranscript<R: SuitableRing, T> {
+    inner: T,
+    log: Vec<TranscriptEvent<R>>,
+}
+
+impl<R: SuitableRing, T> RecordingTranscript<R, T> {
+    /// Starts recording the operations performed on `inner`.
+    pub fn wrap(inner: T) -> Self {
+        Self {
+            inner,
+            log: Vec::new(),
+        }
+    }
+
+    /// Returns the operations performed so far.
+    pub fn log(&self) -> &[TranscriptEvent<R>] {
+        &self.log
+    }
+
+    /// Returns the wrapped transcript and the log.
+    pub fn into_parts(self) -> (T, Vec<TranscriptEvent<R>>) {
+        (self.inner, self.log)
+    }
+}
+
+impl<R: SuitableRing, T: Default> Default for RecordingTranscript<R, T> {
+    fn default() -> Self {
+        Self::wrap(T::default())
+    }
+}
+
+impl<R: SuitableRing, T: Transcript<R>> Transcript<R> for RecordingTranscript<R, T> {
+    type TranscriptConfig = T::TranscriptConfig;
+
+    fn new(config: &Self::TranscriptConfig) -> Self {
+        Self::wrap(T::new(config))
+    }
+
+    fn absorb(&mut self, v: &R) {
+        self.log.push(TranscriptEvent::Absorb(*v));
+        self.inner.absorb(v);
+    }
+
+    fn absorb_field_element(&mut self, v: &R::BaseRing) {
+        self.log.push(TranscriptEvent::AbsorbFieldElement(*v));
+        self.inner.absorb_field_element(v);
+    }
+
+    fn absorb_label(&mut self, label: &[u8]) {
+        self.log.push(TranscriptEvent::Label(label.to_vec()));
+        self.inner.absorb_label(label);
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.log.push(TranscriptEvent::Bytes(bytes.to_vec()));
+        self.inner.absorb_bytes(bytes);
+    }
+
+    fn begin_step(&mut self, step: ProtocolStep) {
+        self.log.push(TranscriptEvent::Step(step));
+        self.inner.begin_step(step);
+    }
+
+    fn get_challenge(&mut self) -> R::BaseRing {
+        let c = self.inner.get_challenge();
+        self.log.push(TranscriptEvent::Challenge(c));
+        c
+    }
+}
+
+impl<R: SuitableRing, T: TranscriptWithShortChallenges<R>> TranscriptWithShortChallenges<R>
+    for RecordingTranscript<R, T>
+{
+    type ChallengeSet = T::ChallengeSet;
+
+    fn get_short_challenge(&mut self) -> R::CoefficientRepresentation {
+        let c = self.inner.get_short_challenge();
+        self.log.push(TranscriptEvent::ShortChallenge(c));
+        c
+    }
+}
+
+/// The first operation at which two transcript logs differ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence<R: SuitableRing> {
+    /// The index of the operation in both logs.
+    pub index: usize,
+    /// The protocol step the operation belongs to, if any step has begun.
+    pub step: Option<ProtocolStep>,
+    /// The last label absorbed before the operation, if any.
+    pub label: Option<Vec<u8>>,
+    /// The operation in the prover's log, or `None` if the log ended.
+    pub prover: Option<TranscriptEvent<R>>,
+    /// The operation in the verifier's log, or `None` if the log ended.
+    pub verifier: Option<TranscriptEvent<R>>,
+}
+
+impl<R: SuitableRing> fmt::Display for Divergence<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "transcripts diverge at operation {}", self.index)?;
+        if let Some(step) = self.step {
+            write!(f, " in the {step} step")?;
+        }
+        if let Some(label) = &self.label {
+            write!(f, " after label \"{}\"", String::from_utf8_lossy(label))?;
+        }
+        write!(
+            f,
+            ": prover {:?}, verifier {:?}",
+            self.prover, self.verifier
+        )
+    }
+}
+
+/// Compares the logs of a prover and a verifier transcript and returns the first operation that differs.
+///
+/// Returns `None` if the logs are equal. If one log is a prefix of the other,
+/// e.g. because the verifier stopped early, the divergence is the first operation missing from the shorter log.
+pub fn first_divergence<R: SuitableRing>(
+    prover: &[TranscriptEvent<R>],
+    verifier: &[TranscriptEvent<R>],
+) -> Option<Divergence<R>> {
+    let mut step = None;
+    let mut label = None;
+
+    for index in 0..prover.len().max(verifier.len()) {
+        let (p, v) = (prover.get(index), verifier.get(index));
+        if p != v {
+            return Some(Divergence {
+                index,
+                step,
+                label,
+                prover: p.cloned(),
+                verifier: v.cloned(),
+            });
+        }
+
+        match p {
+            Some(TranscriptEvent::Step(s)) => step = Some(*s),
+            Some(TranscriptEvent::Label(l)) => label = Some(l.clone()),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use cyclotomic_rings::rings::{GoldilocksChallengeSet, GoldilocksRingNTT};
+
+    use super::*;
+    use crate::transcript::poseidon::PoseidonTranscript;
+
+    type R = GoldilocksRingNTT;
+    type T = RecordingTranscript<R, PoseidonTranscript<R, GoldilocksChallengeSet>>;
+
+    fn run(transcript: &mut T, x: u64) {
+        transcript.begin_step(ProtocolStep::Linearization);
+        transcript.absorb_label(b"x");
+        transcript.absorb(&R::from(x));
+        transcript.get_challenge();
+        transcript.get_short_challenge();
+    }
+
+    #[test]
+    fn test_recording_preserves_challenges() {
+        let mut recording = T::default();
+        let mut plain = PoseidonTranscript::<R, GoldilocksChallengeSet>::default();
+
+        recording.absorb_slice(&[R::from(1u64), R::from(2u64)]);
+        plain.absorb_slice(&[R::from(1u64), R::from(2u64)]);
+
+        assert_eq!(recording.get_challenge(), plain.get_challenge());
+        assert_eq!(recording.get_short_challenge(), plain.get_short_challenge());
+        assert_eq!(recording.log().len(), 4);
+    }
+
+    #[test]
+    fn test_first_divergence() {
+        let (mut prover, mut verifier) = (T::default(), T::default());
+        run(&mut prover, 1);
+        run(&mut verifier, 1);
+
+        assert_eq!(first_divergence(prover.log(), verifier.log()), None);
+
+        let mut verifier = T::default();
+        run(&mut verifier, 2);
+
+        let divergence = first_divergence(prover.log(), verifier.log()).unwrap();
+        assert_eq!(divergence.index, 2);
+        assert_eq!(divergence.step, Some(ProtocolStep::Linearization));
+        assert_eq!(divergence.label, Some(b"x".to_vec()));
+        assert_eq!(
+            divergence.prover,
+            Some(TranscriptEvent::Absorb(R::from(1u64)))
+        );
+        assert_eq!(
+            divergence.verifier,
+            Some(TranscriptEvent::Absorb(R::from(2u64)))
+        );
+    }
+
+    #[test]
+    fn test_first_divergence_truncated() {
+        let mut prover = T::default();
+        run(&mut prover, 1);
+
+        let divergence = first_divergence(prover.log(), &prover.log()[..3]).unwrap();
+        assert_eq!(divergence.index, 3);
+        assert!(divergence.prover.is_some());
+        assert_eq!(divergence.verifier, None);
+    }
+}
@@ -2,5 +2,7 @@
 
 pub(crate) mod mle_helpers;
 #[cfg(feature = "std")]
+pub mod param_search;
+#[cfg(feature = "std")]
 pub mod security_check;
 pub mod sumcheck;
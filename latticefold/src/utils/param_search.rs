@@ -0,0 +1,392 @@
+//! Search for Ajtai and decomposition parameters.
+//!
+//! Given a ring and a witness length, [`search`] enumerates the parameter sets $(\kappa, B, L, b, k)$
+//! with $B = b^k$ that [`estimate_security`] deems secure at the target [`SecurityLevel`], and ranks them by
+//! a rough estimate of the proof size and of the prover cost.
+//! The results can be printed as [`DecompositionParams`](crate::decomposition_parameters::DecompositionParams)
+//! definitions or as entries of `benches/config.toml`.
+
+use ark_ff::{Field, PrimeField};
+use cyclotomic_rings::rings::SuitableRing;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use stark_rings::PolyRing;
+use thiserror::Error;
+
+use super::security_check::{estimate_security, CostModel, MsisParams, SecurityLevel};
+use crate::{ark_base::*, decomposition_parameters::DecompositionConfig};
+
+/// The number of matrices of an R1CS, assumed by the proof size estimate.
+const R1CS_T: usize = 3;
+
+/// Errors that can occur in the parameter search.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParamSearchError {
+    /// The witness length must be positive.
+    #[error("witness length must be positive")]
+    EmptyWitness,
+}
+
+/// The modulus and the degree of a ring.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingInfo {
+    /// The modulus $q$ of the coefficients.
+    pub modulus: BigUint,
+    /// The degree of the ring in the coefficient form.
+    pub degree: usize,
+}
+
+impl RingInfo {
+    /// Returns the modulus and the degree of `R`.
+    pub fn of<R: SuitableRing>() -> Self {
+        Self {
+            modulus: <R::BaseRing as Field>::BasePrimeField::MODULUS.into(),
+            degree: R::CoefficientRepresentation::dimension(),
+        }
+    }
+
+    /// Returns the size of a ring element in bytes.
+    fn element_size(&self) -> usize {
+        self.degree * (self.modulus.bits() as usize).div_ceil(8)
+    }
+}
+
+/// The order of the parameter sets returned by [`search`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankBy {
+    /// Cheapest prover first, ties are broken by the proof size.
+    ProverCost,
+    /// Shortest proof first, ties are broken by the prover cost.
+    ProofSize,
+}
+
+/// Options of the parameter search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// The length of the CCS witness, i.e. `WIT_LEN` in the examples and `w` in `benches/config.toml`.
+    pub witness_len: usize,
+    /// The target security level.
+    pub level: SecurityLevel,
+    /// The cost model of the security estimate.
+    pub model: CostModel,
+    /// The largest number of rows of the Ajtai matrix to try.
+    pub max_kappa: usize,
+    /// The largest length of the radix-$B$ decomposition to accept.
+    pub max_l: usize,
+    /// The small bases $b$ to try.
+    pub b_small: Vec<usize>,
+    /// The order of the results.
+    pub rank_by: RankBy,
+}
+
+impl SearchOptions {
+    /// Returns the default options for the CCS witness length `witness_len`.
+    pub fn new(witness_len: usize) -> Self {
+        Self {
+            witness_len,
+            level: SecurityLevel::Bits128,
+            model: CostModel::ClassicalCoreSvp,
+            max_kappa: 32,
+            max_l: 8,
+            b_small: vec![2, 3, 4, 8, 16],
+            rank_by: RankBy::ProverCost,
+        }
+    }
+}
+
+/// A parameter set found by [`search`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParamSet {
+    /// The number of rows of the Ajtai matrix, i.e. `C`.
+    pub kappa: usize,
+    /// The length of the CCS witness.
+    pub witness_len: usize,
+    /// The MSIS bound.
+    pub b: u128,
+    /// The length of the radix-$B$ decomposition.
+    pub l: usize,
+    /// The small b from the decomposition step of LF.
+    pub b_small: usize,
+    /// K = log_b B.
+    pub k: usize,
+    /// The estimated proof size in bytes.
+    pub proof_size: usize,
+    /// The estimated prover cost in ring multiplications.
+    pub prover_cost: u128,
+}
+
+impl ParamSet {
+    /// Returns the decomposition parameters of the set.
+    pub fn config(&self) -> DecompositionConfig {
+        DecompositionConfig {
+            b: self.b,
+            l: self.l,
+            b_small: self.b_small,
+            k: self.k,
+        }
+    }
+
+    /// Returns the number of columns of the Ajtai matrix, i.e. `W`.
+    pub fn ajtai_witness_len(&self) -> usize {
+        self.witness_len * self.l
+    }
+
+    /// Returns the parameters as a `DecompositionParams` implementation for the struct `name`.
+    pub fn to_decomposition_params(&self, name: &str) -> String {
+        format!(
+            "#[derive(Clone)]\n\
+             pub struct {name};\n\
+             \n\
+             // C = {}, WIT_LEN = {}, W = {}\n\
+             impl DecompositionParams for {name} {{\n    \
+                 const B: u128 = {};\n    \
+                 const L: usize = {};\n    \
+                 const B_SMALL: usize = {};\n    \
+                 const K: usize = {};\n\
+             }}\n",
+            self.kappa,
+            self.witness_len,
+            self.ajtai_witness_len(),
+            self.b,
+            self.l,
+            self.b_small,
+            self.k,
+        )
+    }
+
+    /// Returns the parameters as an entry of `benches/config.toml`.
+    pub fn to_toml(&self) -> String {
+        format!(
+            "{{ x_len = 1, c = {}, w = {}, b = \"{}\", l = {}, b_small = {}, k = {} }}",
+            self.kappa, self.witness_len, self.b, self.l, self.b_small, self.k,
+        )
+    }
+}
+
+/// Enumerates all parameter sets for `ring` within the bounds of `options`.
+///
+/// A set is accepted if $B = b^k$, $L$ is the smallest length with $B^L > q / 2$
+/// and [`estimate_security`] deems the Ajtai matrix with $\kappa$ rows and $W = L \cdot$ `witness_len` columns
+/// secure at `options.level` in `options.model`.
+///
+/// The estimates assume an R1CS and only count the terms depending on the parameters:
+///  * the proof size consists of the $2k$ decomposed instances, i.e. their commitments and evaluation claims,
+///    and of the folding sumcheck of degree $2b$ over $\log W$ variables,
+///  * the prover cost consists of committing to the $2k$ decomposed witnesses and of the folding sumcheck.
+pub fn search(ring: &RingInfo, options: &SearchOptions) -> Result<Vec<ParamSet>, ParamSearchError> {
+    if options.witness_len == 0 {
+        return Err(ParamSearchError::EmptyWitness);
+    }
+
+    let half_modulus = &ring.modulus >> 1;
+    let mut sets = Vec::new();
+
+    for kappa in 1..=options.max_kappa {
+        for &b_small in options.b_small.iter().filter(|&&b_small| b_small >= 2) {
+            let mut b = BigUint::from(b_small);
+            let mut k = 1;
+
+            while b <= ring.modulus {
+                let Some(b_u128) = b.to_u128() else {
+                    break;
+                };
+
+                if let Some(l) = smallest_l(&b, &half_modulus, options.max_l) {
+                    let params = MsisParams {
+                        degree: ring.degree,
+                        kappa,
+                        modulus: ring.modulus.clone(),
+                        witness_len: options.witness_len * l,
+                        b: b_u128,
+                        l,
+                    };
+
+                    if estimate_security(&params, options.level, options.model).is_secure() {
+                        sets.push(estimate(
+                            ring,
+                            kappa,
+                            options.witness_len,
+                            b_u128,
+                            l,
+                            b_small,
+                            k,
+                        ));
+                    }
+                }
+
+                b *= b_small;
+                k += 1;
+            }
+        }
+    }
+
+    let key = |set: &ParamSet| {
+        let (primary, secondary) = match options.rank_by {
+            RankBy::ProverCost => (set.prover_cost, set.proof_size as u128),
+            RankBy::ProofSize => (set.proof_size as u128, set.prover_cost),
+        };
+        (primary, secondary, set.kappa, set.b, set.b_small)
+    };
+    sets.sort_by_key(key);
+
+    Ok(sets)
+}
+
+/// Returns the smallest `l <= max_l` such that `b^l > half_modulus`.
+fn smallest_l(b: &BigUint, half_modulus: &BigUint, max_l: usize) -> Option<usize> {
+    let mut b_pow_l = b.clone();
+
+    for l in 1..=max_l {
+        if b_pow_l > *half_modulus {
+            return Some(l);
+        }
+        b_pow_l *= b;
+    }
+
+    None
+}
+
+fn estimate(
+    ring: &RingInfo,
+    kappa: usize,
+    witness_len: usize,
+    b: u128,
+    l: usize,
+    b_small: usize,
+    k: usize,
+) -> ParamSet {
+    let w = (witness_len * l).next_power_of_two();
+    let log_w = w.trailing_zeros() as usize;
+
+    // Every decomposed instance has a commitment, an evaluation of f and the evaluations of the Mz MLEs,
+    // which are sent in the decomposition and again in the folding.
+    let instance_elements = kappa + 2 * (1 + R1CS_T);
+    let sumcheck_elements = log_w * (2 * b_small + 1);
+    let proof_size = (2 * k * instance_elements + sumcheck_elements) * ring.element_size();
+
+    let commit_cost = (2 * k * kappa * witness_len * l) as u128;
+    let sumcheck_cost = (2 * k * 2 * b_small * w) as u128;
+
+    ParamSet {
+        kappa,
+        witness_len,
+        b,
+        l,
+        b_small,
+        k,
+        proof_size,
+        prover_cost: commit_cost + sumcheck_cost,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cyclotomic_rings::rings::{BabyBearRingNTT, GoldilocksRingNTT};
+
+    use super::*;
+
+    #[test]
+    fn test_search_goldilocks() {
+        let ring = RingInfo::of::<GoldilocksRingNTT>();
+        let options = SearchOptions::new(1 << 10);
+        let sets = search(&ring, &options).unwrap();
+
+        assert!(!sets.is_empty());
+        for set in &sets {
+            set.config().validate::<GoldilocksRingNTT>().unwrap();
+            assert!(set.l <= options.max_l && set.kappa <= options.max_kappa);
+            let params = MsisParams {
+                degree: ring.degree,
+                kappa: set.kappa,
+                modulus: ring.modulus.clone(),
+                witness_len: set.ajtai_witness_len(),
+                b: set.b,
+                l: set.l,
+            };
+            assert!(estimate_security(&params, options.level, options.model).is_secure());
+        }
+        assert!(sets
+            .windows(2)
+            .all(|w| (w[0].prover_cost, w[0].proof_size) <= (w[1].prover_cost, w[1].proof_size)));
+    }
+
+    #[test]
+    fn test_search_rank_by_proof_size() {
+        let ring = RingInfo::of::<BabyBearRingNTT>();
+        let options = SearchOptions {
+            rank_by: RankBy::ProofSize,
+            ..SearchOptions::new(1 << 9)
+        };
+        let sets = search(&ring, &options).unwrap();
+
+        assert!(!sets.is_empty());
+        assert!(sets
+            .windows(2)
+            .all(|w| (w[0].proof_size, w[0].prover_cost) <= (w[1].proof_size, w[1].prover_cost)));
+    }
+
+    #[test]
+    fn test_search_levels() {
+        let ring = RingInfo::of::<GoldilocksRingNTT>();
+        let search_at = |level| {
+            search(
+                &ring,
+                &SearchOptions {
+                    level,
+                    ..SearchOptions::new(1 << 10)
+                },
+            )
+            .unwrap()
+        };
+
+        // Every set secure at a higher level is secure at a lower one.
+        let sets_80 = search_at(SecurityLevel::Bits80);
+        let sets_128 = search_at(SecurityLevel::Bits128);
+        let sets_192 = search_at(SecurityLevel::Bits192);
+        assert!(sets_128.len() < sets_80.len());
+        assert!(sets_128.iter().all(|set| sets_80.contains(set)));
+        assert!(sets_192.iter().all(|set| sets_128.contains(set)));
+    }
+
+    #[test]
+    fn test_search_errors() {
+        let ring = RingInfo::of::<GoldilocksRingNTT>();
+
+        assert_eq!(
+            search(&ring, &SearchOptions::new(0)),
+            Err(ParamSearchError::EmptyWitness)
+        );
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let set = ParamSet {
+            kappa: 4,
+            witness_len: 512,
+            b: 1 << 15,
+            l: 5,
+            b_small: 2,
+            k: 15,
+            proof_size: 0,
+            prover_cost: 0,
+        };
+
+        assert_eq!(
+            set.to_toml(),
+            "{ x_len = 1, c = 4, w = 512, b = \"32768\", l = 5, b_small = 2, k = 15 }"
+        );
+        assert_eq!(
+            set.to_decomposition_params("GoldilocksDP"),
+            "#[derive(Clone)]\n\
+             pub struct GoldilocksDP;\n\
+             \n\
+             // C = 4, WIT_LEN = 512, W = 2560\n\
+             impl DecompositionParams for GoldilocksDP {\n    \
+                 const B: u128 = 32768;\n    \
+                 const L: usize = 5;\n    \
+                 const B_SMALL: usize = 2;\n    \
+                 const K: usize = 15;\n\
+             }\n"
+        );
+    }
+}
@@ -1,6 +1,19 @@
-use ark_std::f64;
+//! Security estimates of the Ajtai commitment scheme, i.e. of the underlying Module-SIS problem.
+//!
+//! [`check_ring_modulus_128_bits_security`] is a quick check using the root Hermite factor $\delta = 1.0045$.
+//! [`estimate_security`] computes the concrete bit-security in the core-SVP model:
+//! it finds the largest root Hermite factor $\delta$ for which the lattice reduction attack
+//! finds a short enough MSIS solution, the smallest BKZ block size $\beta$ achieving $\delta$,
+//! and prices the SVP calls in dimension $\beta$.
+
+use ark_std::{
+    f64::consts::{E, PI},
+    fmt,
+};
 use num_bigint::BigUint;
-use num_traits::ToPrimitive;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::ark_base::*;
 
 fn calculate_bound_l2(degree: usize, kappa: usize, ring_modulus_log2: f64) -> BigUint {
     // The current security parameter use log2(delta)
@@ -43,3 +56,464 @@ pub fn check_ring_modulus_128_bits_security(
     // Return the result of the condition
     bound_l2_check && b_check && b_pow_l_check
 }
+
+/// The cost of solving SVP in dimension $\beta$, i.e. the cost of a BKZ tour of block size $\beta$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CostModel {
+    /// Classical sieving, $2^{0.292 \beta}$.
+    ClassicalCoreSvp,
+    /// Quantum sieving, $2^{0.265 \beta}$.
+    QuantumCoreSvp,
+}
+
+impl CostModel {
+    /// Returns the bit-security per dimension of the SVP oracle.
+    pub fn bits_per_dimension(&self) -> f64 {
+        match self {
+            Self::ClassicalCoreSvp => 0.292,
+            Self::QuantumCoreSvp => 0.265,
+        }
+    }
+}
+
+/// Target security levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityLevel {
+    Bits80,
+    Bits100,
+    Bits128,
+    Bits192,
+}
+
+impl SecurityLevel {
+    /// Returns the security level in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Bits80 => 80,
+            Self::Bits100 => 100,
+            Self::Bits128 => 128,
+            Self::Bits192 => 192,
+        }
+    }
+
+    /// Returns the level of `bits` bits, if supported.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            80 => Some(Self::Bits80),
+            100 => Some(Self::Bits100),
+            128 => Some(Self::Bits128),
+            192 => Some(Self::Bits192),
+            _ => None,
+        }
+    }
+}
+
+/// The parameters of an Ajtai commitment scheme over a cyclotomic ring.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsisParams {
+    /// The degree of the ring.
+    pub degree: usize,
+    /// The number of rows of the Ajtai matrix, i.e. `C`.
+    pub kappa: usize,
+    /// The modulus of the ring.
+    pub modulus: BigUint,
+    /// The number of columns of the Ajtai matrix, i.e. `W`.
+    pub witness_len: usize,
+    /// The bound on the $\ell_\infty$-norm of the openings, i.e. `B`.
+    pub b: u128,
+    /// The length of the radix-`B` decomposition, i.e. `L`.
+    pub l: usize,
+}
+
+impl MsisParams {
+    fn log2_modulus(&self) -> f64 {
+        self.modulus.to_f64().unwrap().log2()
+    }
+
+    /// Returns $\log_2$ of the $\ell_2$-norm bound implied by the $\ell_\infty$-norm bound `B`.
+    fn log2_bound_l2(&self) -> f64 {
+        (self.b as f64).log2() + 0.5 * ((self.degree * self.witness_len) as f64).log2()
+    }
+}
+
+/// The conditions that the parameters have to satisfy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    /// The largest $\ell_2$-norm bound secure at the target level is below $q / 2$.
+    L2Bound,
+    /// `B` is below the largest $\ell_\infty$-norm bound secure at the target level.
+    LInfBound,
+    /// $B^L > q / 2$, i.e. every ring element has a radix-`B` decomposition of length `L`.
+    Decomposition,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::L2Bound => write!(f, "l2 bound < q/2"),
+            Self::LInfBound => write!(f, "B < l_inf bound"),
+            Self::Decomposition => write!(f, "B^L > q/2"),
+        }
+    }
+}
+
+/// A constraint along with its margin in bits, that is negative if the constraint is violated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConstraintCheck {
+    /// The checked constraint.
+    pub constraint: Constraint,
+    /// $\log_2$ of the ratio of the two sides of the constraint.
+    pub margin_bits: f64,
+}
+
+impl ConstraintCheck {
+    /// Returns `true` if the constraint is satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        self.margin_bits > 0.0
+    }
+}
+
+/// The cheapest attack against binding found by [`estimate_security`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Attack {
+    /// The bound $\beta$ on the $\ell_2$-norm is at least $q$, so $q$ times a unit vector is a solution.
+    Trivial,
+    /// Lattice reduction with BKZ of the given block size.
+    LatticeReduction { block_size: usize },
+    /// No lattice reduction finds a short enough solution.
+    None,
+}
+
+/// The result of [`estimate_security`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecurityReport {
+    /// The target security level.
+    pub level: SecurityLevel,
+    /// The estimated bit-security, zero for [`Attack::Trivial`] and infinite for [`Attack::None`].
+    pub bits: f64,
+    /// The cheapest attack.
+    pub attack: Attack,
+    /// The constraints for the target level.
+    pub constraints: [ConstraintCheck; 3],
+}
+
+impl SecurityReport {
+    /// Returns `true` if the estimated security reaches the target level and all constraints are satisfied.
+    pub fn is_secure(&self) -> bool {
+        self.bits >= self.level.bits() as f64 && self.constraints.iter().all(|c| c.is_satisfied())
+    }
+
+    /// Returns the binding constraint, i.e. the one with the smallest margin.
+    pub fn binding_constraint(&self) -> &ConstraintCheck {
+        self.constraints
+            .iter()
+            .min_by(|a, b| a.margin_bits.total_cmp(&b.margin_bits))
+            .expect("there are three constraints")
+    }
+}
+
+impl fmt::Display for SecurityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.attack {
+            Attack::Trivial => writeln!(f, "estimated security: trivially broken (β ≥ q)")?,
+            Attack::LatticeReduction { block_size } => writeln!(
+                f,
+                "estimated security: {:.1} bits (BKZ block size {block_size})",
+                self.bits
+            )?,
+            Attack::None => writeln!(f, "estimated security: no lattice reduction attack")?,
+        }
+        writeln!(f, "target: {} bits", self.level.bits())?;
+        for check in &self.constraints {
+            writeln!(
+                f,
+                "  {}: {} with margin {:.1} bits",
+                check.constraint,
+                if check.is_satisfied() {
+                    "satisfied"
+                } else {
+                    "violated"
+                },
+                check.margin_bits
+            )?;
+        }
+        write!(
+            f,
+            "binding constraint: {}",
+            self.binding_constraint().constraint
+        )
+    }
+}
+
+/// Returns $\log_2 \delta$ of the root Hermite factor achieved by BKZ with block size `block_size`,
+/// i.e. $\delta = ((\pi \beta)^{1 / \beta} \beta / (2 \pi e))^{1 / (2 (\beta - 1))}$.
+fn log2_root_hermite_factor(block_size: usize) -> f64 {
+    let beta = block_size as f64;
+    ((PI * beta).powf(1.0 / beta) * beta / (2.0 * PI * E)).log2() / (2.0 * (beta - 1.0))
+}
+
+/// The smallest block size for which the root Hermite factor estimate is meaningful.
+const MIN_BLOCK_SIZE: usize = 50;
+/// The largest block size considered.
+const MAX_BLOCK_SIZE: usize = 10_000;
+
+/// Returns the smallest block size achieving $\log_2 \delta \leq$ `log2_delta`.
+fn block_size_for(log2_delta: f64) -> Option<usize> {
+    (MIN_BLOCK_SIZE..=MAX_BLOCK_SIZE)
+        .find(|&block_size| log2_root_hermite_factor(block_size) <= log2_delta)
+}
+
+/// Returns $\log_2$ of the largest $\ell_2$- and $\ell_\infty$-norms of MSIS solutions that are hard to find
+/// at the target `level` for an $n \times m$ matrix over $\mathbb{Z}_q$.
+///
+/// Lattice reduction with root Hermite factor $\delta$ finds solutions of length $2^{2 \sqrt{n \log q \log \delta}}$
+/// at best, and the $\ell_\infty$-norm of a vector of length $\beta$ is at least $\beta / \sqrt{m}$.
+fn log2_max_secure_norms(
+    n: f64,
+    log2_q: f64,
+    m: f64,
+    level: SecurityLevel,
+    model: CostModel,
+) -> (f64, f64) {
+    let target_block_size = (level.bits() as f64 / model.bits_per_dimension()).ceil() as usize;
+    let target_log2_delta = log2_root_hermite_factor(target_block_size.max(MIN_BLOCK_SIZE));
+    let log2_bound_l2 = 2.0 * (n * log2_q * target_log2_delta).sqrt();
+
+    (log2_bound_l2, log2_bound_l2 - 0.5 * m.log2())
+}
+
+/// Returns the largest $\ell_\infty$-norm, rounded down to a power of two, of MSIS solutions that are hard to find
+/// at the target `level` for the `kappa` $\times$ `witness_len` Ajtai matrix over the ring of degree `degree`
+/// and modulus `modulus`.
+///
+/// Two distinct openings of a commitment differ by such a solution, so the commitment is binding
+/// for openings of $\ell_\infty$-norm at most half of the returned norm.
+pub fn max_secure_norm_inf(
+    degree: usize,
+    kappa: usize,
+    modulus: &BigUint,
+    witness_len: usize,
+    level: SecurityLevel,
+    model: CostModel,
+) -> BigUint {
+    let (_, log2_bound_inf) = log2_max_secure_norms(
+        (degree * kappa) as f64,
+        modulus.to_f64().unwrap().log2(),
+        (degree * witness_len) as f64,
+        level,
+        model,
+    );
+
+    if log2_bound_inf < 0.0 {
+        BigUint::zero()
+    } else {
+        BigUint::one() << (log2_bound_inf.floor() as u64)
+    }
+}
+
+/// Estimates the bit-security of the Ajtai commitment scheme with the parameters `params`
+/// in the cost model `model`, and checks the constraints for the target level `level`.
+///
+/// An attacker against binding looks for a nonzero $x$ with $A x = 0$ and $\|x\|_2 \leq \beta = B \sqrt{d W}$.
+/// Lattice reduction with root Hermite factor $\delta$ on $m \leq d W$ columns of the $d \kappa \times d W$
+/// matrix finds solutions of length $\delta^m q^{d \kappa / m}$, that is minimal for
+/// $m = 2 d \kappa \log q / \log \beta$, and the attack succeeds if it is below $\beta$.
+pub fn estimate_security(
+    params: &MsisParams,
+    level: SecurityLevel,
+    model: CostModel,
+) -> SecurityReport {
+    let n = (params.degree * params.kappa) as f64;
+    let max_m = (params.degree * params.witness_len) as f64;
+    let log2_q = params.log2_modulus();
+    let log2_half_q = log2_q - 1.0;
+    let log2_beta = params.log2_bound_l2();
+
+    let (bits, attack) = if log2_beta >= log2_q {
+        // q times a unit vector is a solution.
+        (0.0, Attack::Trivial)
+    } else {
+        let m = f64::min(2.0 * n * log2_q / log2_beta, max_m);
+        let log2_delta = (log2_beta - n * log2_q / m) / m;
+
+        if log2_delta <= 0.0 {
+            (f64::INFINITY, Attack::None)
+        } else {
+            match block_size_for(log2_delta) {
+                Some(block_size) => (
+                    model.bits_per_dimension() * block_size as f64,
+                    Attack::LatticeReduction { block_size },
+                ),
+                None => (f64::INFINITY, Attack::None),
+            }
+        }
+    };
+
+    let (log2_bound_l2, log2_bound_inf) = log2_max_secure_norms(n, log2_q, max_m, level, model);
+
+    let constraints = [
+        ConstraintCheck {
+            constraint: Constraint::L2Bound,
+            margin_bits: log2_half_q - log2_bound_l2,
+        },
+        ConstraintCheck {
+            constraint: Constraint::LInfBound,
+            margin_bits: log2_bound_inf - (params.b as f64).log2(),
+        },
+        ConstraintCheck {
+            constraint: Constraint::Decomposition,
+            margin_bits: params.l as f64 * (params.b as f64).log2() - log2_half_q,
+        },
+    ];
+
+    SecurityReport {
+        level,
+        bits,
+        attack,
+        constraints,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goldilocks_params(kappa: usize, b: u128, l: usize) -> MsisParams {
+        MsisParams {
+            degree: 24,
+            kappa,
+            modulus: BigUint::from(18446744069414584321u64),
+            witness_len: 1 << 12,
+            b,
+            l,
+        }
+    }
+
+    #[test]
+    fn test_root_hermite_factor() {
+        // The root Hermite factor decreases with the block size, e.g. BKZ-380 achieves about 1.0041.
+        assert!(log2_root_hermite_factor(100) > log2_root_hermite_factor(200));
+        let delta = 2f64.powf(log2_root_hermite_factor(380));
+        assert!((delta - 1.0041).abs() < 0.0001);
+        assert_eq!(block_size_for(log2_root_hermite_factor(380)), Some(380));
+    }
+
+    #[test]
+    fn test_security_grows_with_kappa() {
+        let bits: Vec<f64> = (10..20)
+            .map(|kappa| {
+                estimate_security(
+                    &goldilocks_params(kappa, 1 << 8, 8),
+                    SecurityLevel::Bits128,
+                    CostModel::ClassicalCoreSvp,
+                )
+                .bits
+            })
+            .collect();
+
+        assert!(bits.windows(2).all(|w| w[0] <= w[1]));
+        assert!(bits[0] < bits[bits.len() - 1]);
+    }
+
+    #[test]
+    fn test_quantum_is_cheaper() {
+        let params = goldilocks_params(16, 1 << 8, 8);
+        let classical =
+            estimate_security(&params, SecurityLevel::Bits128, CostModel::ClassicalCoreSvp);
+        let quantum = estimate_security(&params, SecurityLevel::Bits128, CostModel::QuantumCoreSvp);
+
+        assert_eq!(classical.attack, quantum.attack);
+        assert!(quantum.bits < classical.bits);
+    }
+
+    #[test]
+    fn test_binding_constraint() {
+        // B^L = 2^32 does not cover the Goldilocks modulus.
+        let report = estimate_security(
+            &goldilocks_params(16, 1 << 8, 4),
+            SecurityLevel::Bits128,
+            CostModel::ClassicalCoreSvp,
+        );
+        assert!(!report.is_secure());
+        assert_eq!(
+            report.binding_constraint().constraint,
+            Constraint::Decomposition
+        );
+
+        // A huge B is insecure.
+        let report = estimate_security(
+            &goldilocks_params(1, 1 << 40, 2),
+            SecurityLevel::Bits128,
+            CostModel::ClassicalCoreSvp,
+        );
+        assert!(!report.is_secure());
+        assert_eq!(
+            report.binding_constraint().constraint,
+            Constraint::LInfBound
+        );
+    }
+
+    #[test]
+    fn test_trivially_broken() {
+        // The l2-norm bound B * sqrt(d * W) exceeds the modulus.
+        let report = estimate_security(
+            &goldilocks_params(16, 1 << 60, 2),
+            SecurityLevel::Bits128,
+            CostModel::ClassicalCoreSvp,
+        );
+        assert_eq!(report.attack, Attack::Trivial);
+        assert_eq!(report.bits, 0.0);
+        assert!(!report.is_secure());
+        assert!(report
+            .to_string()
+            .starts_with("estimated security: trivially broken (β ≥ q)\n"));
+    }
+
+    #[test]
+    fn test_max_secure_norm_inf() {
+        let modulus = BigUint::from(18446744069414584321u64);
+        let norm = |kappa| {
+            max_secure_norm_inf(
+                24,
+                kappa,
+                &modulus,
+                1 << 12,
+                SecurityLevel::Bits128,
+                CostModel::ClassicalCoreSvp,
+            )
+        };
+
+        assert!(norm(8) < norm(16));
+        assert!(norm(16).count_ones() == 1);
+
+        // Consistent with the LInfBound constraint of the estimate.
+        let log2_norm = norm(16).bits() as f64 - 1.0;
+        let report = estimate_security(
+            &goldilocks_params(16, 1 << 8, 8),
+            SecurityLevel::Bits128,
+            CostModel::ClassicalCoreSvp,
+        );
+        let log2_bound_inf = report.constraints[1].margin_bits + 8.0;
+        assert!(log2_norm <= log2_bound_inf && log2_bound_inf < log2_norm + 1.0);
+    }
+
+    #[test]
+    fn test_target_levels() {
+        let params = goldilocks_params(16, 1 << 8, 8);
+        let reports: Vec<_> = [
+            SecurityLevel::Bits80,
+            SecurityLevel::Bits100,
+            SecurityLevel::Bits128,
+            SecurityLevel::Bits192,
+        ]
+        .into_iter()
+        .map(|level| estimate_security(&params, level, CostModel::ClassicalCoreSvp))
+        .collect();
+
+        // The estimate does not depend on the target, the margins shrink as the target grows.
+        assert!(reports.windows(2).all(|w| w[0].bits == w[1].bits
+            && w[0].constraints[1].margin_bits > w[1].constraints[1].margin_bits));
+        assert_eq!(SecurityLevel::from_bits(100), Some(SecurityLevel::Bits100));
+        assert_eq!(SecurityLevel::from_bits(64), None);
+    }
+}
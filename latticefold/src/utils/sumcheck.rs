@@ -26,6 +26,14 @@ pub enum SumCheckError<R: Ring + Display> {
     SumCheckFailed(R, R),
     #[error("max degree exceeded")]
     MaxDegreeExceeded,
+    #[error("incorrect number of sumcheck rounds. Expected `{0}`. Received `{1}`")]
+    IncorrectNumberOfRounds(usize, usize),
+    #[error(
+        "incorrect number of evaluations in sumcheck round `{0}`. Expected `{1}`. Received `{2}`"
+    )]
+    IncorrectNumberOfEvaluations(usize, usize, usize),
+    #[error("sumcheck verifier has not finished")]
+    VerifierNotFinished,
 }
 
 impl<R: Ring> From<ArithErrors> for SumCheckError<R> {
@@ -41,6 +49,44 @@ pub struct MLSumcheck<R, T>(#[doc(hidden)] PhantomData<(R, T)>);
 #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<R1: Ring>(Vec<ProverMsg<R1>>);
 
+impl<R: Ring> Proof<R> {
+    /// Returns the evaluations of the round polynomials sent by the prover.
+    #[cfg(test)]
+    pub(crate) fn round_evaluations(&self) -> impl Iterator<Item = &[R]> {
+        self.0.iter().map(|msg| msg.evaluations.as_slice())
+    }
+
+    /// Checks that the proof consists of `nvars` rounds of `degree + 1` evaluations each.
+    pub fn check_shape(&self, nvars: usize, degree: usize) -> Result<(), SumCheckError<R>> {
+        if self.0.len() != nvars {
+            return Err(SumCheckError::IncorrectNumberOfRounds(nvars, self.0.len()));
+        }
+
+        for (round, msg) in self.0.iter().enumerate() {
+            if msg.evaluations.len() != degree + 1 {
+                return Err(SumCheckError::IncorrectNumberOfEvaluations(
+                    round,
+                    degree + 1,
+                    msg.evaluations.len(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds a proof out of the evaluations of the round polynomials.
+    #[cfg(test)]
+    pub(crate) fn from_round_evaluations(evaluations: Vec<Vec<R>>) -> Self {
+        Self(
+            evaluations
+                .into_iter()
+                .map(|evaluations| ProverMsg { evaluations })
+                .collect(),
+        )
+    }
+}
+
 impl<R: OverField, T: Transcript<R>> MLSumcheck<R, T> {
     /// extract sum from the proof
     pub fn extract_sum(proof: &Proof<R>) -> R {
@@ -88,12 +134,13 @@ impl<R: OverField, T: Transcript<R>> MLSumcheck<R, T> {
         claimed_sum: R,
         proof: &Proof<R>,
     ) -> Result<SubClaim<R>, SumCheckError<R>> {
+        proof.check_shape(nvars, degree)?;
+
         transcript.absorb(&R::from(nvars as u128));
         transcript.absorb(&R::from(degree as u128));
 
         let mut verifier_state = IPForMLSumcheck::<R, T>::verifier_init(nvars, degree);
-        for i in 0..nvars {
-            let prover_msg = proof.0.get(i).expect("proof is incomplete");
+        for prover_msg in &proof.0 {
             transcript.absorb_slice(&prover_msg.evaluations);
             let verifier_msg =
                 IPForMLSumcheck::verify_round(prover_msg.clone(), &mut verifier_state, transcript);
@@ -116,7 +163,7 @@ mod tests {
         transcript::poseidon::PoseidonTranscript,
         utils::sumcheck::{
             utils::{rand_poly, rand_poly_comb_fn},
-            MLSumcheck, Proof,
+            MLSumcheck, Proof, SumCheckError,
         },
     };
 
@@ -223,6 +270,40 @@ mod tests {
         }
     }
 
+    fn test_malformed_sumcheck<R, CS>()
+    where
+        R: SuitableRing,
+        CS: LatticefoldChallengeSet<R>,
+    {
+        let mut rng = ark_std::test_rng();
+        let nvars = 5;
+
+        let (poly_degree, sum, proof) = generate_sumcheck_proof::<R, CS>(nvars, &mut rng);
+        let mut rounds: Vec<Vec<R>> = proof.round_evaluations().map(|e| e.to_vec()).collect();
+
+        let verify = |rounds: Vec<Vec<R>>| {
+            MLSumcheck::verify_as_subprotocol(
+                &mut PoseidonTranscript::<R, CS>::default(),
+                nvars,
+                poly_degree,
+                sum,
+                &Proof::from_round_evaluations(rounds),
+            )
+        };
+
+        rounds[2].pop();
+        assert!(matches!(
+            verify(rounds.clone()),
+            Err(SumCheckError::IncorrectNumberOfEvaluations(2, _, _))
+        ));
+
+        rounds.truncate(2);
+        assert!(matches!(
+            verify(rounds),
+            Err(SumCheckError::IncorrectNumberOfRounds(5, 2))
+        ));
+    }
+
     mod stark {
         use cyclotomic_rings::rings::StarkChallengeSet;
         use stark_rings::cyclotomic_ring::models::stark_prime::RqNTT;
@@ -243,6 +324,11 @@ mod tests {
         fn test_failing_sumcheck() {
             super::test_failing_sumcheck::<RqNTT, CS>();
         }
+
+        #[test]
+        fn test_malformed_sumcheck() {
+            super::test_malformed_sumcheck::<RqNTT, CS>();
+        }
     }
 
     mod frog {
@@ -265,6 +351,11 @@ mod tests {
         fn test_failing_sumcheck() {
             super::test_failing_sumcheck::<RqNTT, CS>();
         }
+
+        #[test]
+        fn test_malformed_sumcheck() {
+            super::test_malformed_sumcheck::<RqNTT, CS>();
+        }
     }
 
     mod goldilocks {
@@ -287,6 +378,11 @@ mod tests {
         fn test_failing_sumcheck() {
             super::test_failing_sumcheck::<RqNTT, CS>();
         }
+
+        #[test]
+        fn test_malformed_sumcheck() {
+            super::test_malformed_sumcheck::<RqNTT, CS>();
+        }
     }
 
     mod babybear {
@@ -309,5 +405,10 @@ mod tests {
         fn test_failing_sumcheck() {
             super::test_failing_sumcheck::<RqNTT, CS>();
         }
+
+        #[test]
+        fn test_malformed_sumcheck() {
+            super::test_malformed_sumcheck::<RqNTT, CS>();
+        }
     }
 }
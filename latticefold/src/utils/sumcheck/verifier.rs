@@ -94,17 +94,24 @@ impl<R: OverField, T: Transcript<R>> IPForMLSumcheck<R, T> {
         asserted_sum: R,
     ) -> Result<SubClaim<R>, SumCheckError<R>> {
         if !verifier_state.finished {
-            panic!("Verifier has not finished.");
+            return Err(SumCheckError::VerifierNotFinished);
         }
 
         let mut expected = asserted_sum;
         if verifier_state.polynomials_received.len() != verifier_state.nv {
-            panic!("insufficient rounds");
+            return Err(SumCheckError::IncorrectNumberOfRounds(
+                verifier_state.nv,
+                verifier_state.polynomials_received.len(),
+            ));
         }
         for i in 0..verifier_state.nv {
             let evaluations = &verifier_state.polynomials_received[i];
             if evaluations.len() != verifier_state.max_multiplicands + 1 {
-                panic!("incorrect number of evaluations");
+                return Err(SumCheckError::IncorrectNumberOfEvaluations(
+                    i,
+                    verifier_state.max_multiplicands + 1,
+                    evaluations.len(),
+                ));
             }
             let p0 = evaluations[0];
             let p1 = evaluations[1];